@@ -0,0 +1,90 @@
+use std::fmt::Display;
+
+use proc_macro2::{Span, TokenStream as TokenStream2};
+
+// Every error reported by the macros carries one of these codes, the long form explanations
+// live in `cold_moka::diagnostics` (moka-cached/src/diagnostics.rs) so they can be looked up
+// programmatically. Codes are stable: never reuse or renumber them, only append new ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Diagnostic {
+    UnsupportedReceiver,
+    ConflictingArguments,
+    MissingArgument,
+    InvalidArgument,
+    UnsupportedReturnType,
+    UnsupportedPattern,
+    UnnameableKey,
+    UnsupportedFunction,
+}
+
+impl Diagnostic {
+    #[cfg(test)]
+    pub(crate) const ALL: [Diagnostic; 8] = [
+        Diagnostic::UnsupportedReceiver,
+        Diagnostic::ConflictingArguments,
+        Diagnostic::MissingArgument,
+        Diagnostic::InvalidArgument,
+        Diagnostic::UnsupportedReturnType,
+        Diagnostic::UnsupportedPattern,
+        Diagnostic::UnnameableKey,
+        Diagnostic::UnsupportedFunction,
+    ];
+
+    pub(crate) fn code(self) -> &'static str {
+        match self {
+            Diagnostic::UnsupportedReceiver => "CM0001",
+            Diagnostic::ConflictingArguments => "CM0002",
+            Diagnostic::MissingArgument => "CM0003",
+            Diagnostic::InvalidArgument => "CM0004",
+            Diagnostic::UnsupportedReturnType => "CM0005",
+            Diagnostic::UnsupportedPattern => "CM0006",
+            Diagnostic::UnnameableKey => "CM0007",
+            Diagnostic::UnsupportedFunction => "CM0008",
+        }
+    }
+
+    pub(crate) fn message(self, details: impl Display) -> String {
+        format!("[{}] {}", self.code(), details)
+    }
+
+    pub(crate) fn error(self, span: Span, details: impl Display) -> syn::Error {
+        syn::Error::new(span, self.message(details))
+    }
+
+    pub(crate) fn compile_error(self, span: Span, details: impl Display) -> TokenStream2 {
+        self.error(span, details).to_compile_error()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Diagnostic;
+
+    #[test]
+    fn codes_are_unique() {
+        let mut codes: Vec<_> = Diagnostic::ALL.iter().map(|d| d.code()).collect();
+        codes.sort_unstable();
+        codes.dedup();
+        assert_eq!(codes.len(), Diagnostic::ALL.len());
+    }
+
+    #[test]
+    fn codes_are_explained() {
+        let table = include_str!("../../moka-cached/src/diagnostics.rs");
+        for diagnostic in Diagnostic::ALL {
+            assert!(
+                table.contains(&format!("(\n        \"{}\",", diagnostic.code())),
+                "{:?} has no explanation in cold_moka::diagnostics",
+                diagnostic
+            );
+        }
+    }
+
+    #[test]
+    fn messages_carry_the_code() {
+        for diagnostic in Diagnostic::ALL {
+            let message = diagnostic.message("details");
+            assert!(message.starts_with(&format!("[{}] ", diagnostic.code())));
+        }
+    }
+}
@@ -1,13 +1,18 @@
-use proc_macro2::{Ident, TokenStream as TokenStream2};
+use crate::diagnostics::Diagnostic;
+use darling::ast::NestedMeta;
+use proc_macro2::{Ident, Span, TokenStream as TokenStream2, TokenTree};
 use quote::{quote, ToTokens};
-use std::collections::HashSet;
 use std::iter;
 use std::ops::Deref;
+use std::time::Duration;
 use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
 use syn::token::Comma;
 use syn::{
-    parse_str, Block, FieldPat, FnArg, GenericArgument, Pat, PatIdent, PatReference, PatStruct,
-    PatTuple, PatTupleStruct, PatType, PathArguments, ReturnType, Signature, Type,
+    parse_quote, parse_str, Block, Expr, ExprField, ExprPath, FieldPat, FnArg, GenericArgument,
+    Lifetime, Pat, PatIdent, PatReference, PatStruct, PatTuple, PatTupleStruct, PatType, Path,
+    PathArguments, PathSegment, ReturnType, Signature, TraitBound, Type, TypeGroup, TypeImplTrait,
+    TypeParamBound, TypeParen, TypePath, TypeTraitObject,
 };
 
 // if you define arguments as mutable, e.g.
@@ -41,6 +46,47 @@ pub(super) fn get_mut_signature(signature: Signature) -> Signature {
     signature_no_muts
 }
 
+// signature of a function generated next to the cached one, e.g. `foo_pin` for `foo`.
+// It takes the same arguments as the wrapper, so it has to be built from the signature
+// with the `mut`s already stripped.
+pub(super) fn companion_signature(signature: &Signature, suffix: &str) -> Signature {
+    let mut companion = signature.clone();
+    companion.ident = companion_ident(&signature.ident, suffix);
+    companion
+}
+
+// Every name generated by the macros comes from the helpers below. Names are derived from the
+// function name and the suffix of the item alone: no counters, hashes of spans or positions and
+// no randomness, so moving unrelated code around doesn't change the expansion. Otherwise rustc's
+// incremental caches get invalidated and the expansion snapshots churn. New generated items
+// must be named through these helpers too.
+pub(super) fn companion_ident(fn_ident: &Ident, suffix: &str) -> Ident {
+    Ident::new(&format!("{}_{}", fn_ident, suffix), fn_ident.span())
+}
+
+// the statics holding the cache and its state, `__COLD_MOKA_FOO`, `__COLD_MOKA_FOO_PINNED`...
+pub(super) fn static_ident(fn_ident: &Ident, suffix: Option<&str>) -> Ident {
+    let name = format!("__COLD_MOKA_{}", fn_ident.to_string().to_uppercase());
+    let name = match suffix {
+        Some(suffix) => format!("{}_{}", name, suffix),
+        None => name,
+    };
+    Ident::new(&name, fn_ident.span())
+}
+
+// private functions next to the cached one, e.g. `__cold_moka_key_foo`
+pub(super) fn private_ident(fn_ident: &Ident, prefix: &str) -> Ident {
+    Ident::new(
+        &format!("__cold_moka_{}_{}", prefix, fn_ident),
+        fn_ident.span(),
+    )
+}
+
+// locals of the generated code, `Span::mixed_site` keeps inputs named like them from clashing
+pub(super) fn local_ident(name: &str) -> Ident {
+    Ident::new(name, Span::mixed_site())
+}
+
 pub(super) fn strip_mut_from_pat(pat_type: &PatType) -> Box<Pat> {
     match &pat_type.pat.deref() {
         Pat::Ident(pat_ident) => {
@@ -92,93 +138,618 @@ pub fn param_names(pat: Pat, depth: u8) -> Box<dyn Iterator<Item = (Ident, u8)>>
 pub enum RetTurnTy {
     Result,
     Option,
+    // `Result<Option<T>, E>`, only `Ok(Some(_))` is cached
+    ResultOption,
     Bare,
 }
 
-// works with Result<T>, ::std::result::Result<T>, Option<T>, ::std::option::Option<T> and type Result<T> = ::std::result::Result<T, E>;
+// works with Result<T>, ::std::result::Result<T>, Option<T>, ::core::option::Option<T> and
+// anything else whose last path segment is `Result` or `Option`,
+// e.g. type Result<T> = ::std::result::Result<T, E>;
 pub fn return_fallible_type(output: &ReturnType) -> RetTurnTy {
-    let return_ty = match &output {
-        ReturnType::Default => quote! { () },
-        ReturnType::Type(_, ty) => quote! { #ty },
+    let ty = match output {
+        ReturnType::Default => return RetTurnTy::Bare,
+        ReturnType::Type(_, ty) => ty,
+    };
+    let last_segment = match ty.deref() {
+        Type::Path(TypePath { qself: None, path }) => path.segments.last(),
+        _ => None,
+    };
+
+    match last_segment {
+        Some(_) if result_holds_option(output) => RetTurnTy::ResultOption,
+        Some(segment) if segment.ident == "Result" => RetTurnTy::Result,
+        Some(segment) if segment.ident == "Option" => RetTurnTy::Option,
+        _ => RetTurnTy::Bare,
+    }
+}
+
+// Whether the first generic argument of the returned `Result` is an `Option`, the caller makes
+// sure it's a `Result`
+pub(super) fn result_holds_option(output: &ReturnType) -> bool {
+    let ReturnType::Type(_, ty) = output else {
+        return false;
+    };
+    let Type::Path(TypePath { qself: None, path }) = ty.deref() else {
+        return false;
+    };
+    if path
+        .segments
+        .last()
+        .is_none_or(|segment| segment.ident != "Result")
+    {
+        return false;
+    }
+    match first_generic_type(path) {
+        Some(Type::Path(TypePath { qself: None, path })) => path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Option"),
+        _ => false,
+    }
+}
+
+// Strip the `Return<T>` of a `wrap_return` function, e.g. `Result<T, E>` for
+// `Result<Return<T>, E>`, along with where the `Return` was: bare, in a `Result` or an `Option`
+pub(super) fn unwrap_return(output: &ReturnType) -> syn::Result<(ReturnType, RetTurnTy)> {
+    fn return_value(ty: &Type) -> Option<Type> {
+        let Type::Path(TypePath { qself: None, path }) = ty else {
+            return None;
+        };
+        if path.segments.last()?.ident != "Return" {
+            return None;
+        }
+        first_generic_type(path).cloned()
+    }
+
+    let error = || {
+        Diagnostic::UnsupportedReturnType.error(
+            output.span(),
+            "wrap_return needs the function to return `cold_moka::Return<T>`, or a `Result` or an `Option` of it",
+        )
+    };
+    let ReturnType::Type(arrow, ty) = output else {
+        return Err(error());
+    };
+    if let Some(value) = return_value(ty) {
+        return Ok((ReturnType::Type(*arrow, Box::new(value)), RetTurnTy::Bare));
     }
-    .to_string()
-    .to_lowercase();
+    let mut ty = ty.clone();
+    let Type::Path(TypePath { qself: None, path }) = ty.as_mut() else {
+        return Err(error());
+    };
+    let Some(segment) = path.segments.last_mut() else {
+        return Err(error());
+    };
+    let shape = match segment.ident.to_string().as_str() {
+        "Result" => RetTurnTy::Result,
+        "Option" => RetTurnTy::Option,
+        _ => return Err(error()),
+    };
+    let PathArguments::AngleBracketed(brackets) = &mut segment.arguments else {
+        return Err(error());
+    };
+    match brackets.args.first_mut() {
+        Some(GenericArgument::Type(inner)) => {
+            *inner = return_value(inner).ok_or_else(error)?;
+            Ok((ReturnType::Type(*arrow, ty), shape))
+        }
+        _ => Err(error()),
+    }
+}
 
-    if return_ty.contains("result") {
-        RetTurnTy::Result
-    } else if return_ty.contains("option") {
-        RetTurnTy::Option
-    } else {
-        RetTurnTy::Bare
+fn first_generic_type(path: &Path) -> Option<&Type> {
+    path.segments
+        .last()
+        .and_then(|segment| match &segment.arguments {
+            PathArguments::AngleBracketed(brackets) => {
+                brackets.args.iter().find_map(|arg| match arg {
+                    GenericArgument::Type(ty) => Some(ty),
+                    _ => None,
+                })
+            }
+            _ => None,
+        })
+}
+
+// Whether the error of a `Result` can't be cloned out of the `Arc` moka shares it in, so it's
+// wrapped in a `SharedError` instead: boxed trait objects, e.g. `Box<dyn Error + Send + Sync>`,
+// `anyhow::Error` and `eyre::Report`, including their `Result<T>` shorthands.
+pub(super) fn has_shared_error(output: &ReturnType) -> bool {
+    let ReturnType::Type(_, ty) = output else {
+        return false;
+    };
+    let Type::Path(TypePath { qself: None, path }) = ty.deref() else {
+        return false;
+    };
+    let Some(PathArguments::AngleBracketed(result_args)) =
+        path.segments.last().map(|segment| &segment.arguments)
+    else {
+        return false;
+    };
+    let error = match result_args.args.iter().nth(1) {
+        Some(GenericArgument::Type(Type::Path(TypePath { qself: None, path }))) => path,
+        Some(_) => return false,
+        None => return is_report_path(path, true),
+    };
+    if is_report_path(error, false) {
+        return true;
+    }
+    match error.segments.last() {
+        Some(segment) if segment.ident == "Box" => match &segment.arguments {
+            PathArguments::AngleBracketed(box_args) => matches!(
+                box_args.args.first(),
+                Some(GenericArgument::Type(Type::TraitObject(_)))
+            ),
+            _ => false,
+        },
+        _ => false,
     }
 }
 
+// crates with a `Result<T>` shorthand and the error type it defaults to
+const REPORT_CRATES: [(&str, &str); 3] = [
+    ("anyhow", "Error"),
+    ("eyre", "Report"),
+    ("color_eyre", "Report"),
+];
+
+// `anyhow::Result` or `anyhow::Error` and their `eyre` counterparts, the crate has to be named
+// for the path to be recognized
+fn is_report_path(path: &Path, result: bool) -> bool {
+    let mut segments = path.segments.iter().rev();
+    let (Some(last), Some(krate)) = (segments.next(), segments.next()) else {
+        return false;
+    };
+    REPORT_CRATES.iter().any(|(name, error)| {
+        krate.ident == name && last.ident == if result { "Result" } else { error }
+    })
+}
+
 // Find the type of the value to store.
 // Normally it's the same as the return type of the functions, but
 // for Options and Results it's the (first) inner type. So for
 // Option<u32>, store u32, for Result<i32, String>, store i32, etc.
+// Tuples, references and the like are stored as they are, unless
+// `result = true` claims they are a `Result`. `Result<Option<T>, E>` stores `T`.
 pub(super) fn find_value_type(
     return_ty: RetTurnTy,
     output: &ReturnType,
     output_ty: TokenStream2,
-) -> TokenStream2 {
-    match return_ty {
-        RetTurnTy::Bare => output_ty,
-        _ => match output.clone() {
-            ReturnType::Default => {
-                panic!("function must return something for result or option attributes")
+    span: Span,
+) -> syn::Result<TokenStream2> {
+    if return_ty == RetTurnTy::Bare {
+        return Ok(output_ty);
+    }
+    let ty = match output {
+        ReturnType::Default => {
+            return Err(Diagnostic::UnsupportedReturnType.error(
+                span,
+                "function must return something for result or option attributes",
+            ))
+        }
+        ReturnType::Type(_, ty) => ty.deref(),
+    };
+    let mut inner = ty;
+    while let Type::Paren(TypeParen { elem, .. }) | Type::Group(TypeGroup { elem, .. }) = inner {
+        inner = elem;
+    }
+    let Type::Path(TypePath { path, .. }) = inner else {
+        return Err(Diagnostic::UnsupportedReturnType.error(
+            ty.span(),
+            "unable to find the value type, return a `Result` or `Option` path",
+        ));
+    };
+    let value_ty = first_generic_type(path);
+    let value_ty = match (return_ty, value_ty) {
+        (RetTurnTy::ResultOption, Some(Type::Path(TypePath { path, .. }))) => {
+            first_generic_type(path)
+        }
+        (_, value_ty) => value_ty,
+    };
+    match value_ty {
+        Some(value_ty) => Ok(quote! {#value_ty}),
+        None => Err(Diagnostic::UnsupportedReturnType
+            .error(ty.span(), "function return type has no inner type")),
+    }
+}
+
+// Whether the value the function returns is an `Arc` already, e.g. `Arc<T>`, `Result<Arc<T>, E>`
+// or `Option<Arc<T>>`. Such values are cached as they are, hits only clone the `Arc`
+pub(super) fn holds_arc(return_ty: RetTurnTy, output: &ReturnType) -> bool {
+    let ReturnType::Type(_, ty) = output else {
+        return false;
+    };
+    let depth = match return_ty {
+        RetTurnTy::Bare => 0,
+        RetTurnTy::Result | RetTurnTy::Option => 1,
+        RetTurnTy::ResultOption => 2,
+    };
+    let mut value_ty = Some(ty.deref());
+    for _ in 0..depth {
+        value_ty = match value_ty {
+            Some(Type::Path(TypePath { path, .. })) => first_generic_type(path),
+            _ => None,
+        };
+    }
+    match value_ty {
+        Some(Type::Path(TypePath { qself: None, path })) => path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Arc"),
+        _ => false,
+    }
+}
+
+// The output of a function with `arc = true`: the value it returns is wrapped in an `Arc`,
+// e.g. `Arc<T>` for `T` and `Result<Arc<T>, E>` for `Result<T, E>`.
+pub(super) fn arc_output(return_ty: RetTurnTy, output: &ReturnType) -> ReturnType {
+    let mut ty = match output {
+        ReturnType::Default => return parse_quote! { -> ::std::sync::Arc<()> },
+        ReturnType::Type(_, ty) => ty.deref().clone(),
+    };
+    if return_ty == RetTurnTy::Bare {
+        return parse_quote! { -> ::std::sync::Arc<#ty> };
+    }
+    // `find_value_type` already made sure there is a value type to wrap
+    let mut inner = &mut ty;
+    while let Type::Paren(TypeParen { elem, .. }) | Type::Group(TypeGroup { elem, .. }) = inner {
+        inner = elem;
+    }
+    if let Type::Path(TypePath { path, .. }) = inner {
+        if let Some(PathArguments::AngleBracketed(brackets)) = path
+            .segments
+            .last_mut()
+            .map(|segment| &mut segment.arguments)
+        {
+            if let Some(GenericArgument::Type(value_ty)) = brackets
+                .args
+                .iter_mut()
+                .find(|arg| matches!(arg, GenericArgument::Type(_)))
+            {
+                // `Result<Option<T>, E>` becomes `Result<Option<Arc<T>>, E>`
+                let option = (return_ty == RetTurnTy::ResultOption)
+                    .then(|| arc_output(RetTurnTy::Option, &parse_quote! { -> #value_ty }));
+                *value_ty = match option {
+                    Some(ReturnType::Type(_, option)) => *option,
+                    _ => parse_quote! { ::std::sync::Arc<#value_ty> },
+                };
             }
-            ReturnType::Type(_, ty) => {
-                if let Type::Path(typepath) = *ty {
-                    let segments = typepath.path.segments;
-                    if let PathArguments::AngleBracketed(brackets) =
-                        &segments.last().unwrap().arguments
-                    {
-                        let inner_ty = brackets.args.first().unwrap();
-                        quote! {#inner_ty}
-                    } else {
-                        panic!("function return type has no inner type")
-                    }
-                } else {
-                    panic!("function return type too complex")
+        }
+    }
+    parse_quote! { -> #ty }
+}
+
+// Find the output of a boxed future, e.g. `T` for `BoxFuture<'static, T>`,
+// `Pin<Box<dyn Future<Output = T> + Send>>` and `impl Future<Output = T>`
+pub(super) fn boxed_future_output(output: &ReturnType, span: Span) -> syn::Result<Type> {
+    fn generic_types(segment: &PathSegment) -> impl Iterator<Item = &Type> {
+        let args = match &segment.arguments {
+            PathArguments::AngleBracketed(brackets) => Some(&brackets.args),
+            _ => None,
+        };
+        args.into_iter().flatten().filter_map(|arg| match arg {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        })
+    }
+
+    fn future_output(ty: &Type) -> Option<Type> {
+        match ty {
+            Type::Path(TypePath { qself: None, path }) => {
+                let segment = path.segments.last()?;
+                match segment.ident.to_string().as_str() {
+                    // BoxFuture<'a, T>, LocalBoxFuture<'a, T>
+                    "BoxFuture" | "LocalBoxFuture" => generic_types(segment).next().cloned(),
+                    "Pin" | "Box" => future_output(generic_types(segment).next()?),
+                    _ => None,
                 }
             }
-        },
+            Type::TraitObject(TypeTraitObject { bounds, .. })
+            | Type::ImplTrait(TypeImplTrait { bounds, .. }) => {
+                bounds.iter().find_map(|bound| match bound {
+                    TypeParamBound::Trait(TraitBound { path, .. }) => {
+                        let segment = path.segments.last()?;
+                        if segment.ident != "Future" {
+                            return None;
+                        }
+                        match &segment.arguments {
+                            PathArguments::AngleBracketed(brackets) => {
+                                brackets.args.iter().find_map(|arg| match arg {
+                                    GenericArgument::AssocType(assoc)
+                                        if assoc.ident == "Output" =>
+                                    {
+                                        Some(assoc.ty.clone())
+                                    }
+                                    _ => None,
+                                })
+                            }
+                            _ => None,
+                        }
+                    }
+                    _ => None,
+                })
+            }
+            Type::Paren(paren) => future_output(&paren.elem),
+            _ => None,
+        }
+    }
+
+    match output {
+        ReturnType::Default => Err(Diagnostic::UnsupportedReturnType.error(
+            span,
+            "boxed_future functions must return a boxed future",
+        )),
+        ReturnType::Type(_, ty) => future_output(ty).ok_or_else(|| {
+            Diagnostic::UnsupportedReturnType.error(
+                ty.span(),
+                "boxed_future functions must return `BoxFuture<T>`, `Pin<Box<dyn Future<Output = T>>>` or `impl Future<Output = T>`",
+            )
+        }),
     }
 }
 
+// Borrowed key inputs can't be stored in a `'static` cache, the key holds them owned: `&str`
+// as `String`, `&[T]` as `Vec<T>` and `&T` as `T`, `'static` references are kept as they are.
+// Returns the owned type and the number of references it was behind.
+pub(super) fn owned_key_type(ty: &Type) -> (Type, usize) {
+    let reference = match ty {
+        Type::Reference(reference)
+            if reference
+                .lifetime
+                .as_ref()
+                .is_none_or(|l| l.ident != "static") =>
+        {
+            reference
+        }
+        _ => return (ty.clone(), 0),
+    };
+    let (elem, derefs) = owned_key_type(&reference.elem);
+    let owned = match &elem {
+        Type::Path(TypePath { qself: None, path }) if path.is_ident("str") => {
+            parse_quote! { ::std::string::String }
+        }
+        Type::Slice(slice) => {
+            let elem = &slice.elem;
+            parse_quote! { ::std::vec::Vec<#elem> }
+        }
+        _ => elem,
+    };
+    (owned, derefs + 1)
+}
+
+// what the key keeps of the key inputs, `key_hash` and `debug_key` replace them in the key
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) enum KeyEncoding {
+    Inputs,
+    Hash,
+    Debug,
+}
+
 // make the cache key type and block that converts the inputs into the key type
+#[allow(clippy::too_many_arguments)]
 pub(super) fn make_cache_key_type(
-    key_args_indexes: &HashSet<usize>,
+    attr_args: &[NestedMeta],
+    key_args_indexes: &[usize],
     convert: &Option<String>,
+    key_fn: &Option<String>,
     cache_type: &Option<String>,
+    encoding: KeyEncoding,
     input_tys: Vec<Type>,
     input_names: &[Ident],
-) -> (TokenStream2, TokenStream2) {
-    let input_tys: Vec<_> = input_tys
+) -> syn::Result<(TokenStream2, TokenStream2)> {
+    // the key follows the order of the indexes, not the one of the parameters
+    let input_tys: Vec<_> = key_args_indexes
+        .iter()
+        .map(|&idx| &input_tys[idx])
+        .collect();
+    let input_names: Vec<_> = key_args_indexes
         .iter()
-        .enumerate()
-        .filter_map(|(idx, ty)| key_args_indexes.contains(&idx).then_some(ty))
+        .map(|&idx| &input_names[idx])
         .collect();
     match (convert, cache_type) {
-        (Some(convert_str), _) => {
-            let key_convert_block =
-                parse_str::<Block>(convert_str).expect("unable to parse key convert block");
-            let cache_key_ty = quote! {(#(#input_tys),*)};
+        _ if encoding == KeyEncoding::Hash => Ok((
+            quote! {u64},
+            quote! {
+                let mut hasher = <::cold_moka::KeyHasher as ::std::default::Default>::default();
+                #(::std::hash::Hash::hash(#input_names, &mut hasher);)*
+                ::std::hash::Hasher::finish(&hasher)
+            },
+        )),
+        _ if encoding == KeyEncoding::Debug => Ok((
+            quote! {::std::string::String},
+            quote! { ::std::format!("{:?}", (#(#input_names),*)) },
+        )),
+        (None, Some(cache_type_str)) if key_fn.is_some() => {
+            let key_fn = parse_argument::<Path>(
+                key_fn.as_deref().unwrap_or_default(),
+                "key_fn",
+                crate::arg_span(attr_args, "key_fn"),
+            )?;
+            let cache_key_ty = parse_argument::<Type>(
+                cache_type_str,
+                "key_type",
+                crate::arg_span(attr_args, "key_type"),
+            )?;
 
-            (quote! {#cache_key_ty}, quote! {#key_convert_block})
+            Ok((quote! {#cache_key_ty}, quote! {#key_fn(#(#input_names),*)}))
+        }
+        (_, None) if key_fn.is_some() => Err(Diagnostic::MissingArgument.error(
+            crate::arg_span(attr_args, "key_fn"),
+            "key_fn requires key_type to be set",
+        )),
+        (Some(_), _) if key_fn.is_some() => Err(Diagnostic::ConflictingArguments.error(
+            crate::arg_span(attr_args, "convert"),
+            "key_fn and convert can't be used together",
+        )),
+        (Some(convert_str), cache_type) => {
+            let key_convert_block =
+                parse_convert(convert_str, crate::arg_span(attr_args, "convert"))?;
+            let cache_key_ty = match cache_type {
+                Some(cache_type) => parse_argument::<Type>(
+                    cache_type,
+                    "key_type",
+                    crate::arg_span(attr_args, "key_type"),
+                )?
+                .to_token_stream(),
+                None => quote! {(#(#input_tys),*)},
+            };
+            // the block is written against owned inputs, `key_expr` usually reads only some of them
+            Ok((
+                quote! {#cache_key_ty},
+                quote! {
+                    #(#[allow(unused_variables)] let #input_names = ::std::clone::Clone::clone(#input_names);)*
+                    #key_convert_block
+                },
+            ))
         }
         (None, _) => {
-            let input_names = input_names
+            let (owned_tys, owned_values): (Vec<_>, Vec<_>) = input_tys
                 .iter()
-                .enumerate()
-                .filter_map(|(idx, ty)| key_args_indexes.contains(&idx).then_some(ty));
-            (
-                quote! {(#(#input_tys),*)},
-                quote! {(#(#input_names.clone()),*)},
-            )
+                .zip(&input_names)
+                .map(|(ty, name)| match owned_key_type(ty) {
+                    (owned_ty, 0) => (owned_ty, quote! {#name.clone()}),
+                    (owned_ty, derefs) => {
+                        let derefs = iter::repeat_n(quote! {*}, derefs + 1);
+                        (
+                            owned_ty,
+                            quote! {::std::borrow::ToOwned::to_owned(&#(#derefs)*#name)},
+                        )
+                    }
+                })
+                .unzip();
+            Ok((quote! {(#(#owned_tys),*)}, quote! {(#(#owned_values),*)}))
+        }
+    }
+}
+
+// `key = "ctx.tenant_id, id"` keys on fields of the inputs. The type of the fields can't be
+// looked up, so the key is of type `key_type` unless it's hashed. The key follows the order of
+// `key`, the inputs the fields belong to are the key inputs.
+pub(super) fn parse_key_fields(
+    key: &str,
+    input_names: &[Ident],
+    span: Span,
+) -> syn::Result<Vec<Expr>> {
+    key.split(',')
+        .map(|field| {
+            let expr = parse_str::<Expr>(field.trim()).map_err(|_| {
+                Diagnostic::InvalidArgument.error(
+                    span,
+                    format!("unable to parse key field `{}`", field.trim()),
+                )
+            })?;
+            match field_root(&expr) {
+                Some(root) if input_names.contains(root) => Ok(expr),
+                Some(root) => {
+                    let available: Vec<_> = input_names.iter().map(ToString::to_string).collect();
+                    Err(Diagnostic::InvalidArgument.error(
+                        span,
+                        format!(
+                            "key field `{}` doesn't belong to an argument, expected one of: {}",
+                            root,
+                            available.join(", ")
+                        ),
+                    ))
+                }
+                None => Err(Diagnostic::InvalidArgument.error(
+                    span,
+                    format!("key field `{}` must be a path like `ctx.id`", field.trim()),
+                )),
+            }
+        })
+        .collect()
+}
+
+// the input a key field belongs to, `ctx` for `ctx.tenant.id`
+pub(super) fn field_root(expr: &Expr) -> Option<&Ident> {
+    match expr {
+        Expr::Field(ExprField { base, .. }) => field_root(base),
+        Expr::Path(ExprPath {
+            path, qself: None, ..
+        }) => path.get_ident(),
+        _ => None,
+    }
+}
+
+pub(super) fn make_field_key_type(
+    attr_args: &[NestedMeta],
+    fields: &[Expr],
+    cache_type: &Option<String>,
+    encoding: KeyEncoding,
+) -> syn::Result<(TokenStream2, TokenStream2)> {
+    match encoding {
+        KeyEncoding::Hash => {
+            return Ok((
+                quote! {u64},
+                quote! {
+                    let mut hasher = <::cold_moka::KeyHasher as ::std::default::Default>::default();
+                    #(::std::hash::Hash::hash(&#fields, &mut hasher);)*
+                    ::std::hash::Hasher::finish(&hasher)
+                },
+            ));
+        }
+        KeyEncoding::Debug => {
+            return Ok((
+                quote! {::std::string::String},
+                quote! { ::std::format!("{:?}", (#(&#fields),*)) },
+            ));
+        }
+        KeyEncoding::Inputs => {}
+    }
+    let Some(cache_type) = cache_type else {
+        return Err(Diagnostic::MissingArgument.error(
+            crate::arg_span(attr_args, "key"),
+            "key fields require key_type to be set",
+        ));
+    };
+    let cache_key_ty = parse_argument::<Type>(
+        cache_type,
+        "key_type",
+        crate::arg_span(attr_args, "key_type"),
+    )?;
+    Ok((
+        quote! {#cache_key_ty},
+        quote! {(#(::std::clone::Clone::clone(&#fields)),*)},
+    ))
+}
+
+// the arguments of the attribute are string literals, parsing errors point at the literal
+pub(super) fn parse_argument<T: syn::parse::Parse>(
+    value: &str,
+    name: &str,
+    span: Span,
+) -> syn::Result<T> {
+    parse_str::<T>(value).map_err(|e| {
+        Diagnostic::InvalidArgument
+            .error(span, format!("unable to parse {} `{}`: {}", name, value, e))
+    })
+}
+
+// `convert` is a block or an expression, which is put in a block so both expand the same
+fn parse_convert(convert: &str, span: Span) -> syn::Result<Block> {
+    parse_str::<Block>(convert).or_else(|_| {
+        let expr = parse_argument::<Expr>(convert, "convert", span)?;
+        Ok(parse_quote! {{ #expr }})
+    })
+}
+
+// a `convert` awaiting something is only valid in an `async fn`, its key function is async then
+pub(super) fn awaits(tokens: TokenStream2) -> bool {
+    let mut after_dot = false;
+    for tree in tokens {
+        match tree {
+            TokenTree::Group(group) if awaits(group.stream()) => return true,
+            TokenTree::Ident(ident) if after_dot && ident == "await" => return true,
+            TokenTree::Punct(punct) => {
+                after_dot = punct.as_char() == '.';
+                continue;
+            }
+            _ => {}
         }
+        after_dot = false;
     }
+    false
 }
 
 // if you define arguments as mutable, e.g.
@@ -194,46 +765,142 @@ pub(super) fn make_cache_key_type(
 pub(super) fn get_input_names(
     inputs: &Punctuated<FnArg, Comma>,
 ) -> impl Iterator<Item = (Ident, u8)> + '_ {
+    // the receiver is never a part of the key
     inputs.iter().flat_map(|input| match input {
-        FnArg::Receiver(_) => panic!("methods (functions taking 'self') are not supported"),
+        FnArg::Receiver(_) => Box::new(iter::empty()),
         FnArg::Typed(pat_type) => param_names(*pat_type.pat.clone(), 0),
     })
 }
 
-// get types for cache key
-pub(super) fn get_input_types(
-    inputs: &Punctuated<FnArg, Comma>,
-    ty_depths_info: &[u8],
-) -> Vec<Type> {
-    inputs
-        .iter()
-        .zip(ty_depths_info.iter())
-        .map(|(input, depth)| match input {
-            FnArg::Receiver(_) => panic!("methods (functions taking 'self') are not supported"),
-            FnArg::Typed(pat_type) => ty_from_depth_info(*depth, *pat_type.ty.clone()),
-        })
-        .collect()
+// get types for cache key, one for every name of `get_input_names` in the same order
+pub(super) fn get_input_types(inputs: &Punctuated<FnArg, Comma>) -> syn::Result<Vec<Type>> {
+    let mut tys = Vec::new();
+    for input in inputs {
+        if let FnArg::Typed(pat_type) = input {
+            binding_types(&pat_type.pat, &pat_type.ty, &mut tys)?;
+        }
+    }
+    Ok(tys)
 }
 
-pub(super) fn ty_from_depth_info(depth: u8, ty: Type) -> Type {
-    if depth == 0 {
-        return ty;
-    }
-    if let Type::Path(p) = ty {
-        let mut segments = p.path.segments;
-        let last = segments.last_mut().unwrap();
-        if let PathArguments::AngleBracketed(brackets) = &mut last.arguments {
-            let inner_ty = brackets.args.first_mut().unwrap();
-            let inner_ty = match inner_ty {
-                GenericArgument::Type(ty) => ty.clone(),
-                _ => panic!("GenericArgument not a type"),
-            };
-            ty_from_depth_info(depth - 1, inner_ty.clone())
-        } else {
-            panic!("PathArguments not AngleBracketed")
+// Walks a pattern along its type, e.g. `(a, b): (A, B)` binds `a: A` and `b: B`. The fields of
+// struct patterns can't be looked up, their bindings are assumed to hold the generic argument
+fn binding_types(pat: &Pat, ty: &Type, tys: &mut Vec<Type>) -> syn::Result<()> {
+    let mut ty = ty;
+    while let Type::Paren(TypeParen { elem, .. }) | Type::Group(TypeGroup { elem, .. }) = ty {
+        ty = elem;
+    }
+    match (pat, ty) {
+        (Pat::Ident(_), _) => tys.push(ty.clone()),
+        (Pat::Reference(PatReference { pat, .. }), Type::Reference(reference)) => {
+            binding_types(pat, &reference.elem, tys)?
+        }
+        (Pat::Tuple(PatTuple { elems, .. }), Type::Tuple(tuple))
+            if elems.len() == tuple.elems.len() =>
+        {
+            for (pat, ty) in elems.iter().zip(tuple.elems.iter()) {
+                binding_types(pat, ty, tys)?;
+            }
+        }
+        _ => {
+            for (_, depth) in param_names(pat.clone(), 0) {
+                tys.push(ty_from_depth_info(depth, ty.clone())?);
+            }
         }
-    } else {
-        ty
+    }
+    Ok(())
+}
+
+// Finds an `impl Trait` in the type of an input, such inputs can't be stored in the key
+// as the type of the key has to be named.
+pub(super) fn find_impl_trait(ty: &Type) -> Option<&Type> {
+    match ty {
+        Type::ImplTrait(_) => Some(ty),
+        Type::Reference(reference) => find_impl_trait(&reference.elem),
+        Type::Paren(paren) => find_impl_trait(&paren.elem),
+        Type::Group(group) => find_impl_trait(&group.elem),
+        Type::Slice(slice) => find_impl_trait(&slice.elem),
+        Type::Array(array) => find_impl_trait(&array.elem),
+        Type::Tuple(tuple) => tuple.elems.iter().find_map(find_impl_trait),
+        Type::Path(TypePath { path, .. }) => {
+            path.segments
+                .iter()
+                .find_map(|segment| match &segment.arguments {
+                    PathArguments::AngleBracketed(args) => {
+                        args.args.iter().find_map(|arg| match arg {
+                            GenericArgument::Type(ty) => find_impl_trait(ty),
+                            _ => None,
+                        })
+                    }
+                    _ => None,
+                })
+        }
+        _ => None,
+    }
+}
+
+// Names the lifetimes elided in the type of an input, the companions take the inputs through
+// an `impl Trait` which can't elide them. Tells whether there was any.
+pub(super) fn name_elided_lifetimes(ty: &mut Type, lifetime: &Lifetime) -> bool {
+    match ty {
+        Type::Reference(reference) => {
+            let elided = reference.lifetime.as_ref().is_none_or(|l| l.ident == "_");
+            if elided {
+                reference.lifetime = Some(lifetime.clone());
+            }
+            name_elided_lifetimes(&mut reference.elem, lifetime) || elided
+        }
+        Type::Paren(paren) => name_elided_lifetimes(&mut paren.elem, lifetime),
+        Type::Group(group) => name_elided_lifetimes(&mut group.elem, lifetime),
+        Type::Slice(slice) => name_elided_lifetimes(&mut slice.elem, lifetime),
+        Type::Array(array) => name_elided_lifetimes(&mut array.elem, lifetime),
+        Type::Tuple(tuple) => {
+            let mut named = false;
+            for ty in &mut tuple.elems {
+                named |= name_elided_lifetimes(ty, lifetime);
+            }
+            named
+        }
+        Type::Path(TypePath { path, .. }) => {
+            path.segments
+                .iter_mut()
+                .fold(false, |named, segment| match &mut segment.arguments {
+                    PathArguments::AngleBracketed(args) => {
+                        args.args.iter_mut().fold(named, |named, arg| match arg {
+                            GenericArgument::Lifetime(l) if l.ident == "_" => {
+                                *l = lifetime.clone();
+                                true
+                            }
+                            GenericArgument::Type(ty) => {
+                                name_elided_lifetimes(ty, lifetime) || named
+                            }
+                            _ => named,
+                        })
+                    }
+                    _ => named,
+                })
+        }
+        _ => false,
+    }
+}
+
+pub(super) fn ty_from_depth_info(depth: u8, ty: Type) -> syn::Result<Type> {
+    if depth == 0 {
+        return Ok(ty);
+    }
+    let Type::Path(p) = &ty else {
+        return Ok(ty);
+    };
+    let inner_ty = match p.path.segments.last().map(|last| &last.arguments) {
+        Some(PathArguments::AngleBracketed(brackets)) => brackets.args.first(),
+        _ => None,
+    };
+    match inner_ty {
+        Some(GenericArgument::Type(inner_ty)) => ty_from_depth_info(depth - 1, inner_ty.clone()),
+        _ => Err(Diagnostic::UnsupportedPattern.error(
+            ty.span(),
+            "the pattern doesn't match a type with a generic argument, bind the input to an identifier",
+        )),
     }
 }
 
@@ -245,19 +912,191 @@ pub(super) fn ty_from_depth_info(depth: u8, ty: Type) -> Type {
 // ```
 pub(super) fn get_wrapped_type_for_function_call(
     inputs: &Punctuated<FnArg, Comma>,
-) -> Vec<TokenStream2> {
+) -> syn::Result<Vec<TokenStream2>> {
     inputs
         .iter()
-        .map(|input| match input {
-            FnArg::Receiver(_) => panic!("methods (functions taking 'self') are not supported"),
-            FnArg::Typed(pat_type) => match *strip_mut_from_pat(pat_type) {
-                Pat::Ident(ident) => ident.to_token_stream(),
-                Pat::Tuple(tuple) => tuple.to_token_stream(),
-                Pat::TupleStruct(tuple_struct) => tuple_struct.to_token_stream(),
-                Pat::Struct(struct_pat) => struct_pat.to_token_stream(),
-                Pat::Reference(pat_ref) => pat_ref.to_token_stream(),
-                _ => panic!("unsupported pattern"),
-            },
+        .filter_map(|input| match input {
+            FnArg::Receiver(_) => None,
+            FnArg::Typed(pat_type) => Some(match *strip_mut_from_pat(pat_type) {
+                Pat::Ident(ident) => Ok(ident.to_token_stream()),
+                Pat::Tuple(tuple) => Ok(tuple.to_token_stream()),
+                Pat::TupleStruct(tuple_struct) => Ok(tuple_struct.to_token_stream()),
+                Pat::Struct(struct_pat) => Ok(struct_pat.to_token_stream()),
+                Pat::Reference(pat_ref) => Ok(pat_ref.to_token_stream()),
+                pat => Err(Diagnostic::UnsupportedPattern.error(
+                    pat.span(),
+                    "unsupported pattern, bind the input to an identifier",
+                )),
+            }),
         })
         .collect()
 }
+
+// parses durations like "500ms", "30s", "5m" or "1h"
+pub(super) fn parse_duration(duration: &str) -> Option<Duration> {
+    let duration = duration.trim();
+    let split = duration
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(duration.len());
+    let (value, unit) = duration.split_at(split);
+    let value: u64 = value.parse().ok()?;
+    match unit.trim() {
+        "ms" => Some(Duration::from_millis(value)),
+        "s" => Some(Duration::from_secs(value)),
+        "m" => Some(Duration::from_secs(value * 60)),
+        "h" => Some(Duration::from_secs(value * 60 * 60)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use proc_macro2::Span;
+    use quote::quote;
+
+    use syn::{parse_quote, parse_str, Ident, ItemFn, ReturnType, Type};
+
+    use super::{
+        awaits, boxed_future_output, companion_ident, get_input_names, get_input_types,
+        get_wrapped_type_for_function_call, make_cache_key_type, name_elided_lifetimes,
+        parse_convert, private_ident, static_ident, ty_from_depth_info, KeyEncoding,
+    };
+
+    fn names(fn_ident: &Ident) -> Vec<String> {
+        vec![
+            static_ident(fn_ident, None).to_string(),
+            static_ident(fn_ident, Some("PINNED")).to_string(),
+            private_ident(fn_ident, "key").to_string(),
+            companion_ident(fn_ident, "cache_clear").to_string(),
+        ]
+    }
+
+    #[test]
+    fn generated_names() {
+        let fn_ident = Ident::new("fetch", Span::call_site());
+        assert_eq!(
+            names(&fn_ident),
+            [
+                "__COLD_MOKA_FETCH",
+                "__COLD_MOKA_FETCH_PINNED",
+                "__cold_moka_key_fetch",
+                "fetch_cache_clear",
+            ]
+        );
+    }
+
+    #[test]
+    fn names_dont_depend_on_spans() {
+        let moved = parse_str::<ItemFn>("\n\n    fn fetch() {}")
+            .unwrap()
+            .sig
+            .ident;
+        let original = parse_str::<ItemFn>("fn fetch() {}").unwrap().sig.ident;
+        let mixed = Ident::new("fetch", Span::mixed_site());
+        assert_eq!(names(&moved), names(&original));
+        assert_eq!(names(&mixed), names(&original));
+    }
+
+    #[test]
+    fn errors_carry_the_code() {
+        let key_fn = Some("make_key".to_owned());
+        let err = make_cache_key_type(
+            &[],
+            &[],
+            &None,
+            &key_fn,
+            &None,
+            KeyEncoding::Inputs,
+            vec![],
+            &[],
+        )
+        .unwrap_err();
+        assert!(err.to_string().starts_with("[CM0003] "));
+
+        let convert = Some("{ not a block".to_owned());
+        let err = make_cache_key_type(
+            &[],
+            &[],
+            &convert,
+            &None,
+            &None,
+            KeyEncoding::Inputs,
+            vec![],
+            &[],
+        )
+        .unwrap_err();
+        assert!(err.to_string().starts_with("[CM0004] "));
+
+        let output: ReturnType = parse_quote! { -> u64 };
+        let err = boxed_future_output(&output, Span::call_site()).unwrap_err();
+        assert!(err.to_string().starts_with("[CM0005] "));
+
+        let ty: Type = parse_quote! { u64 };
+        assert!(ty_from_depth_info(0, ty.clone()).is_ok());
+        let ty: Type = parse_quote! { Wrapper };
+        let err = ty_from_depth_info(1, ty).unwrap_err();
+        assert!(err.to_string().starts_with("[CM0006] "));
+
+        let item: ItemFn = parse_quote! { fn f(_: u64) {} };
+        let err = get_wrapped_type_for_function_call(&item.sig.inputs).unwrap_err();
+        assert!(err.to_string().starts_with("[CM0006] "));
+    }
+
+    #[test]
+    fn destructured_inputs_get_their_types() {
+        let item: ItemFn = parse_quote! {
+            fn f(id: u64, (a, &b): (String, &u8), Wrapper(c): Wrapper<bool>) {}
+        };
+        let names: Vec<_> = get_input_names(&item.sig.inputs)
+            .map(|(name, _)| name.to_string())
+            .collect();
+        let tys: Vec<Type> = get_input_types(&item.sig.inputs).unwrap();
+        let expected: Vec<Type> = vec![
+            parse_quote! { u64 },
+            parse_quote! { String },
+            parse_quote! { u8 },
+            parse_quote! { bool },
+        ];
+        assert_eq!(names, ["id", "a", "b", "c"]);
+        assert_eq!(
+            tys.iter()
+                .map(|ty| quote!(#ty).to_string())
+                .collect::<Vec<_>>(),
+            expected
+                .iter()
+                .map(|ty| quote!(#ty).to_string())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn elided_lifetimes_get_named() {
+        let lifetime = parse_quote! { 'a };
+        let mut ty: Type = parse_quote! { (&Context, Cow<'_, str>, &'static str) };
+        assert!(name_elided_lifetimes(&mut ty, &lifetime));
+        assert_eq!(
+            quote!(#ty).to_string(),
+            quote!((&'a Context, Cow<'a, str>, &'static str)).to_string()
+        );
+
+        let mut ty: Type = parse_quote! { Vec<&'static str> };
+        assert!(!name_elided_lifetimes(&mut ty, &lifetime));
+    }
+
+    #[test]
+    fn convert_expression_expands_like_a_block() {
+        let block = parse_convert("{ (a.abs(), b) }", Span::call_site()).unwrap();
+        let expr = parse_convert("(a.abs(), b)", Span::call_site()).unwrap();
+        assert_eq!(quote!(#block).to_string(), quote!(#expr).to_string());
+        assert!(parse_convert("{ (a, ", Span::call_site()).is_err());
+    }
+
+    #[test]
+    fn awaits_are_found_in_nested_groups() {
+        assert!(awaits(quote! { digest(&a).await }));
+        assert!(awaits(quote! { { (hash(b.as_bytes()).await, a) } }));
+        assert!(!awaits(quote! { (a.abs(), b) }));
+        assert!(!awaits(quote! { a.awaited }));
+        assert!(!awaits(quote! { r#"await"# }));
+    }
+}
@@ -1,8 +1,9 @@
+//! Procedural macros of the `cold-moka` crate, use them through `cold_moka::cached`.
 #![warn(
     clippy::all,
     clippy::dbg_macro,
     clippy::todo,
-    clippy::empty_enum,
+    clippy::empty_enums,
     clippy::enum_glob_use,
     clippy::mem_forget,
     clippy::unused_self,
@@ -11,9 +12,7 @@
     clippy::needless_borrow,
     clippy::match_wildcard_for_single_variants,
     clippy::if_let_mutex,
-    clippy::mismatched_target_os,
     clippy::await_holding_lock,
-    clippy::match_on_vec_items,
     clippy::imprecise_flops,
     clippy::suboptimal_flops,
     clippy::lossy_float_literal,
@@ -36,289 +35,3636 @@
 
 use proc_macro::TokenStream;
 use std::collections::HashSet;
+use std::ops::Deref;
 
 use darling::ast::NestedMeta;
 use darling::FromMeta;
-use proc_macro2::TokenStream as TokenStream2;
-use quote::quote;
-use syn::{parse_macro_input, Ident, ItemFn, ReturnType};
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{quote, quote_spanned, ToTokens};
+use syn::spanned::Spanned;
+use syn::{
+    parse_macro_input, parse_quote, parse_str, Expr, ExprLit, FnArg, Ident, ItemFn, Lifetime, Lit,
+    Meta, Pat, Path, ReturnType, Type,
+};
 
+use crate::diagnostics::Diagnostic;
 use crate::helpers::*;
+use crate::mutation::Mutation;
 
+mod diagnostics;
 mod helpers;
+mod mutation;
+mod once;
 
 #[derive(FromMeta)]
 struct MacroArgs {
     #[darling(default)]
     size: Option<usize>,
     ttl: Option<u64>,
+
+    #[darling(default)]
+    // total weight of the entries, replaces `size`
+    max_weight: Option<u64>,
+    #[darling(default)]
+    // closure `|&K, &V| -> u32` weighing the entries against `max_weight`
+    weigher: Option<String>,
+    #[darling(default)]
+    // "bypass" or "dedicated", what happens to values weighing more than a fraction of `max_weight`
+    oversize: Option<String>,
+    #[darling(default)]
+    // the fraction of `max_weight` above which a value is oversized, 0.5 by default
+    oversize_fraction: Option<f64>,
+    #[darling(default)]
+    // path of a function called with the weight of each oversized value
+    on_oversize: Option<String>,
     #[darling(default)]
     // list of input names to use for the cache key
     key: Option<String>,
+    #[darling(default)]
+    // list of input names to leave out of the cache key, the inverse of `key`
+    ignore: Option<String>,
+    #[darling(default)]
+    // store a `u64` hash of the key inputs instead of the inputs themselves
+    key_hash: bool,
+    #[darling(default)]
+    // store the `Debug` output of the key inputs instead of the inputs themselves
+    debug_key: bool,
+    #[darling(default)]
+    // move the key inputs into the key, the function gets copies of them on a miss
+    key_borrow: bool,
+
+    #[darling(default)]
+    convert: Option<String>,
+    #[darling(default)]
+    // the key as a single expression of `key_type`, a shorthand for `convert`
+    key_expr: Option<String>,
+
+    #[darling(default)]
+    // path of a function building the key (of `key_type`) from borrows of the key inputs
+    key_fn: Option<String>,
+
+    // `type` is a keyword and can't be used as an attribute argument
+    #[darling(default, rename = "key_type")]
+    cache_type: Option<String>,
+    #[darling(default, rename = "create")]
+    cache_create: Option<String>,
+    #[darling(default)]
+    // path of a static holding a cache shared with other functions, declared by the user
+    group: Option<String>,
+    #[darling(default)]
+    // the cached value type, for returns the macro can't take apart, e.g. aliases
+    value_type: Option<String>,
+
+    #[darling(default)]
+    // generate `<fn>_pin` which keeps the entry from expiring
+    pin: bool,
+
+    #[darling(default)]
+    // treat the return type as a `Result` (or a bare value) whatever its name
+    result: Option<bool>,
+
+    #[darling(default)]
+    // treat the return type as an `Option` (or a bare value) whatever its name
+    option: Option<bool>,
+
+    #[darling(default)]
+    // cache the whole `Result`, errors included
+    cache_errors: bool,
+    #[darling(default)]
+    // time to live of the cached errors, in seconds
+    negative_ttl: Option<u64>,
+    #[darling(default)]
+    // cache the whole `Option`, `None` included
+    cache_none: bool,
+    #[darling(default)]
+    // the value is a `(T, Duration)`, the duration is the time to live of the entry
+    value_ttl: bool,
+
+    #[darling(default)]
+    // how the errors moka shares in an `Arc` are returned: "clone", "shared" or "arc"
+    error: Option<String>,
+    #[darling(default)]
+    // closure turning the shared `Arc<E>` back into `E` for `error = "arc"`
+    from_arc: Option<String>,
+
+    #[darling(default)]
+    // emit `tracing` events for the hits and misses
+    trace: bool,
+    #[darling(default)]
+    // return the last good value when computing it again fails
+    result_fallback: bool,
+    #[darling(default)]
+    // closure deciding whether a computed value is kept in the cache
+    cache_if: Option<String>,
+    #[darling(default)]
+    // paths of functions called with the key on every hit and every miss
+    on_hit: Option<String>,
+    #[darling(default)]
+    on_miss: Option<String>,
+    #[darling(default)]
+    // the function returns `cold_moka::Return<T>` telling whether the value came from the cache
+    wrap_return: bool,
+
+    #[darling(default)]
+    // closure measuring computed values for the `metrics` histogram
+    record_size: Option<String>,
+
+    #[darling(default)]
+    // cache and return `Arc<T>`, so hits don't clone the value itself
+    arc: bool,
+
+    #[darling(default)]
+    // use a sync cache for an `async fn`, looked up without awaiting
+    sync_cache: bool,
+
+    #[darling(default)]
+    // run the body of an `async fn` on tokio's blocking threads
+    blocking: bool,
+
+    #[darling(default)]
+    // enable moka's invalidation closures, generate `<fn>_cache_invalidate_if`
+    invalidation: bool,
+
+    #[darling(default)]
+    // generate `<fn>_many` looking up a batch of inputs
+    many: bool,
+
+    #[darling(default)]
+    // generate `<fn>_cache_dump` listing the cached entries
+    dump: bool,
+
+    #[darling(default)]
+    // count hits and misses, generate `<fn>_cache_stats` returning them
+    stats: bool,
+
+    #[darling(default)]
+    // e.g. "30s", metrics are not recorded for that long after the cache is created
+    warmup: Option<String>,
+
+    #[darling(default)]
+    // keep the cache behind an `ArcSwap` and generate `<fn>_cache_swap` replacing it at once
+    swap: bool,
+    #[darling(default)]
+    // path of a static `CacheBackend` used instead of a moka cache, e.g. a fake in tests
+    backend: Option<String>,
+    #[darling(default)]
+    // generate `<fn>_uncached` running the body without the cache
+    bypass: bool,
+    #[darling(default)]
+    // name of the function generated by `bypass`, instead of `<fn>_uncached`
+    bypass_name: Option<String>,
+
+    #[darling(default)]
+    // inject `cold_moka::<fn>::*` failpoints, they only do something with the `chaos` feature
+    failpoints: bool,
+    #[darling(default)]
+    // path of an `AtomicBool`, the cache is skipped while it's `false`
+    enabled: Option<String>,
+
+    #[darling(default)]
+    // an associated function of an `impl` without a receiver, cached like a method
+    associated: bool,
+    #[darling(default)]
+    // non-async function returning a future, set on its own for `BoxFuture<T>`,
+    // `Pin<Box<dyn Future<Output = T>>>` and `impl Future<Output = T>`
+    boxed_future: bool,
+}
+
+/// ```ignore
+/// use cold_moka::moka::sync::Cache;
+/// use cold_moka::cached;
+/// use cold_moka::once_cell::sync::Lazy;
+///
+///
+/// #[cached(ttl = 100, size = 100)]
+/// fn foo(bar: i32) -> i32 {
+///     bar + 1
+/// }
+///
+/// static __COLD_MOKA_FOO: Lazy<Cache<i32, i32>> = Lazy::new(|| {
+///     Cache::builder()
+///         .max_capacity(100)
+///         .time_to_live(std::time::Duration::from_secs(100))
+///         .build()
+/// });
+///
+/// fn foo(bar: i32) -> i32 {
+///     fn __cold_moka_inner_foo(bar: i32) -> i32 {
+///         bar + 1
+///     }
+///
+///     __COLD_MOKA_FOO.get_with_by_ref(&bar, || __cold_moka_inner_foo(bar))
+/// }
+/// ```
+///
+/// async functions will use `moka::future::Cache` instead of `moka::sync::Cache`
+///
+/// ```ignore
+/// use cold_moka::moka::future::Cache;
+/// use cold_moka::cached;
+/// use cold_moka::once_cell::sync::Lazy;
+///
+/// #[cached(ttl = 100, size = 100)]
+/// async fn bar(arg1: i32) ->String{
+///   arg1.to_string()
+/// }
+///
+/// // becomes
+/// static __COLD_MOKA_BAR: Lazy<Cache<i32, String>> = Lazy::new(|| {
+///     Cache::builder()
+///         .max_capacity(100)
+///         .time_to_live(std::time::Duration::from_secs(100))
+///         .build()
+/// });
+///
+/// async fn bar(arg1: i32) ->String{
+///  __COLD_MOKA_BAR.get_with_by_ref(&arg1, || arg1.to_string()).await
+/// }
+/// ```
+///
+/// Functions returning nothing cache `()`, so with a `ttl` they run at most once per key within
+/// that window, e.g. to debounce side effects. Concurrent calls for the same key wait for the
+/// running one instead of running the body again.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[cached(ttl = 600)]
+/// async fn notify_ops(alert_key: String) {
+///     // fire the webhook, at most once per alert key every 10 minutes
+/// }
+/// ```
+///
+/// The body is moved into a function nested in the generated one, so a recursive call resolves
+/// to the caching wrapper and is memoized as well. A call can't depend on its own key though:
+/// looking up the key which is being computed waits for that computation and deadlocks. Async
+/// recursion has to be boxed as usual.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[cached]
+/// fn fib(n: u64) -> u64 {
+///     if n < 2 { n } else { fib(n - 1) + fib(n - 2) }
+/// }
+///
+/// assert_eq!(fib(80), 23416728348467685);
+/// ```
+///
+/// for functions with multiple arguments, you can specify which arguments to use for the cache key
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// struct Context;
+///
+/// #[cached(key = "arg1, arg2")]
+/// async fn frobnicate(ctx: Context,arg1:i32, arg2: i32) -> Result<i32, String> {
+///     Ok(arg1 + arg2)
+/// }
+/// ```
+/// The key tuple and the parameters of the companions follow the order of the names in `key`,
+/// `key = "b, a"` stores `(b, a)` whatever the order of the parameters is. Without `key` they
+/// follow the parameters.
+///
+/// `ignore` is the inverse, every argument except the listed ones makes up the key. It can't be
+/// combined with `key`.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// struct Context;
+///
+/// #[cached(ignore = "ctx")]
+/// fn sum(ctx: Context, arg1: i32, arg2: i32) -> i32 {
+///     arg1 + arg2
+/// }
+///
+/// assert_eq!(sum(Context, 1, 2), 3);
+/// assert!(sum_cache_contains(1, 2));
+/// ```
+///
+/// Borrowed key inputs can't be kept in the cache, so the key holds them owned: `&str` becomes a
+/// `String`, `&[T]` a `Vec<T>` and any other `&T` a `T`, which has to be `Clone`. They are copied
+/// on every call, like owned inputs are. `&'static` references are kept as they are.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[cached]
+/// fn lookup(name: &str, scale: &u64) -> u64 {
+///     name.len() as u64 * scale
+/// }
+///
+/// assert_eq!(lookup("abc", &2), 6);
+/// assert!(lookup_cache_contains("abc", &2));
+/// ```
+///
+/// `key_hash = true` keeps a `u64` hash of the key inputs instead of the inputs themselves,
+/// which saves memory when they are large, e.g. long strings. Two inputs hashing to the same
+/// value share an entry, with 64 bits that's unlikely but not impossible, so only use it when
+/// an occasional wrong value is acceptable. The hash is computed by `cold_moka::KeyHasher`,
+/// which gives the same hash in every build and every run.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[cached(key_hash = true)]
+/// fn word_count(text: String) -> usize {
+///     text.split_whitespace().count()
+/// }
+///
+/// assert_eq!(word_count("a rather long text".to_owned()), 4);
+/// assert!(word_count_cache_contains("a rather long text".to_owned()));
+/// ```
+///
+/// `debug_key = true` keys on the `Debug` output of the key inputs, formatted as a tuple into a
+/// `String`, for inputs which are `Debug` but not `Hash` or `Eq`, e.g. types of another crate.
+/// It's meant for prototyping: two inputs printing alike share an entry even when they differ,
+/// e.g. through a field the `Debug` implementation skips, and the output isn't stable across
+/// versions of the types or of the standard library. `key` picks the inputs formatted.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[derive(Debug)]
+/// struct Point {
+///     x: f64,
+///     y: f64,
+/// }
+///
+/// #[cached(debug_key = true)]
+/// fn norm(point: Point) -> f64 {
+///     point.x.hypot(point.y)
+/// }
+///
+/// assert_eq!(norm(Point { x: 3.0, y: 4.0 }), 5.0);
+/// assert!(norm_cache_contains(Point { x: 3.0, y: 4.0 }));
+/// ```
+///
+/// `key_borrow = true` avoids copying the key inputs on a hit. They are moved into the key, which
+/// is only borrowed by the lookup: moka takes a `&Q` where the stored key type `K: Borrow<Q>` and
+/// only turns it into a `K` with `ToOwned` when it inserts a value, here `Q` is `K` itself. On a
+/// miss the function gets copies of the inputs, so a miss copies them twice instead of once. It
+/// pays off for large keys which are mostly hits, and needs the key inputs to be plain
+/// identifiers.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[cached(key_borrow = true)]
+/// fn lines(path: String) -> usize {
+///     path.len()
+/// }
+///
+/// assert_eq!(lines("/var/log/syslog".to_owned()), 15);
+/// ```
+///
+/// functions returning `Result` or `Option` will use `try_get_with_by_ref` and `optional_get_with_by_ref` respectively.
+/// Errors are not cached, moka shares them between the concurrent callers as `Arc<E>`,
+/// so the error type has to be `Clone` to be returned to each of them, the last one holding the
+/// `Arc` gets the error without a clone. Errors which aren't `Clone` can implement
+/// `From<Arc<E>>` instead. The exception are errors
+/// written as `Box<dyn Error + Send + Sync>` (not behind an alias) and, with the `anyhow` or
+/// `eyre` feature, `anyhow::Error`, `eyre::Report` and their `Result<T>` shorthands, also
+/// through `color_eyre`. The crate has to be named in the path. They come back wrapping a
+/// [`SharedError`](../cold_moka/struct.SharedError.html) which shares the original one.
+///
+/// ```rust
+/// use cold_moka::cached;
+/// struct Context;
+///
+/// struct Wrapper<T>(T);
+///
+/// #[cached(key = "arg1,str")]
+/// async fn frobnicate(
+///     _ctx: Context,
+///     Wrapper(arg1): Wrapper<i32> ,
+///     str: String
+/// ) -> Result<String, String> {
+///     Ok(format!("{}{}", arg1, str))
+/// }
+/// ```
+///
+/// `error` picks the conversion explicitly: `"clone"` (the above), `"shared"` (e.g. for an alias of
+/// `Box<dyn Error + Send + Sync>`) or `"arc"`, which hands the `Arc<E>` to the `from_arc` closure
+/// and lets functions with errors neither `Clone` nor shareable be cached. Without `from_arc`
+/// it's the same as `"clone"`. The function keeps its signature in every case.
+///
+/// ```rust
+/// use std::sync::Arc;
+///
+/// use cold_moka::cached;
+///
+/// #[derive(Debug)]
+/// struct DbError(String);
+///
+/// #[cached(error = "arc", from_arc = "|e: Arc<DbError>| DbError(e.0.clone())")]
+/// fn load(id: u64) -> Result<String, DbError> {
+///     Err(DbError(format!("{} is gone", id)))
+/// }
+///
+/// assert_eq!(load(1).unwrap_err().0, "1 is gone");
+/// ```
+///
+/// `cache_errors = true` caches the whole `Result`, so an error is returned from the cache until
+/// it expires like any other value. `negative_ttl` gives the errors a time to live of their
+/// own, in seconds, while `ttl` keeps applying to the `Ok` values. The error type has to be
+/// `Clone` in this mode.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[cached(cache_errors = true, ttl = 3600, negative_ttl = 60)]
+/// fn lookup(id: u64) -> Result<String, u16> {
+///     Err(404)
+/// }
+///
+/// assert_eq!(lookup(1), Err(404));
+/// assert_eq!(lookup_if_cached(1), Some(Err(404)));
+/// ```
+///
+/// `value_ttl = true` lets the function decide how long each value lives: it returns a
+/// `(T, Duration)` (`Result<(T, Duration), E>`, `Option<(T, Duration)>`) and the entry expires
+/// after that duration, e.g. the `max-age` of an HTTP response. The whole tuple is cached, so
+/// hits return the duration the value was computed with. It replaces `ttl`.
+///
+/// ```rust
+/// use std::time::Duration;
+/// use cold_moka::cached;
+///
+/// #[cached(value_ttl = true)]
+/// fn fetch(url: String) -> Result<(String, Duration), String> {
+///     Ok((format!("body of {}", url), Duration::from_secs(300)))
+/// }
+///
+/// let (body, max_age) = fetch("/".to_owned()).unwrap();
+/// assert_eq!(max_age, Duration::from_secs(300));
+/// ```
+///
+/// `cache_none = true` does the same for `Option`s, a `None` stays cached until it expires or
+/// is evicted.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[cached(cache_none = true)]
+/// fn find(name: String) -> Option<u64> {
+///     name.parse().ok()
+/// }
+///
+/// assert_eq!(find("x".to_owned()), None);
+/// assert_eq!(find_if_cached("x".to_owned()), Some(None));
+/// ```
+///
+/// A `Result<Option<T>, E>` caches only `Ok(Some(_))`, both `Ok(None)` and errors are computed
+/// again on the next call. `cache_none = true` caches `Ok(None)` as well, `cache_errors = true`
+/// caches every outcome.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[cached]
+/// fn user(id: u64) -> Result<Option<String>, String> {
+///     Ok((id != 0).then(|| format!("user {}", id)))
+/// }
+///
+/// assert_eq!(user(0), Ok(None));
+/// assert_eq!(user_if_cached(0), None);
+/// assert_eq!(user(1), Ok(Some("user 1".to_owned())));
+/// assert_eq!(user_if_cached(1), Some("user 1".to_owned()));
+/// ```
+///
+/// `cache_if` takes a closure getting a reference to a computed value, which is only kept in the
+/// cache when it returns `true`. The value is returned either way, e.g. to avoid caching
+/// transient empty results. Concurrent callers waiting for the computation still get the
+/// rejected value.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[cached(cache_if = "|v: &Vec<u64>| !v.is_empty()")]
+/// fn orders(user: u64) -> Vec<u64> {
+///     Vec::new()
+/// }
+///
+/// assert!(orders(1).is_empty());
+/// assert_eq!(orders_if_cached(1), None);
+/// ```
+///
+/// `result_fallback = true` keeps the values which expired or were evicted from the cache
+/// aside, and returns the last good value of a key when computing it again fails. The error is
+/// only returned when there is no such value, e.g. for a new key or one which was invalidated.
+/// The key has to be `Clone`. Up to `size` such values are kept, the least recently used is
+/// dropped first, and a value is kept once moka's maintenance hands it to the eviction listener.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[cached(ttl = 60, result_fallback = true)]
+/// fn exchange_rate(currency: String) -> Result<f64, String> {
+///     Err(format!("no rate for {}", currency))
+/// }
+///
+/// assert!(exchange_rate("EUR".to_owned()).is_err());
+/// ```
+///
+/// `value_type` names the cached value type when it can't be taken from the return type, e.g.
+/// for an alias of a `Result` without generic arguments. It is the `T` of a `Result<T, E>` or an
+/// `Option<T>`, not the whole return type.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// type Lookup = Result<u32, String>;
+///
+/// #[cached(result = true, value_type = "u32")]
+/// fn port(service: String) -> Lookup {
+///     service.parse().map_err(|_| format!("unknown service {}", service))
+/// }
+///
+/// assert_eq!(port("80".to_owned()), Ok(80));
+/// ```
+///
+/// `impl Trait` inputs can't be stored in the key, as its type has to be named. Leave them out
+/// of it with `key`, or pass them to a `key_fn`.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[cached(key = "id")]
+/// fn describe(id: u64, log: impl Fn(&str)) -> String {
+///     log("computing");
+///     format!("item {}", id)
+/// }
+/// ```
+///
+/// When building the key takes more than a tuple, `key_fn` names a function which is called
+/// with references to the key inputs and returns the key of type `key_type`
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[derive(Clone, Hash, PartialEq, Eq)]
+/// struct CompositeId(String);
+///
+/// impl CompositeId {
+///     fn from_parts(tenant: &str, id: &u64) -> Self {
+///         CompositeId(format!("{}/{}", tenant.to_lowercase(), id))
+///     }
+/// }
+///
+/// #[cached(key = "tenant, id", key_fn = "CompositeId::from_parts", key_type = "CompositeId")]
+/// fn lookup(tenant: String, id: u64) -> String {
+///     format!("{}:{}", tenant, id)
+/// }
+/// ```
+///
+/// `convert` builds the key in place instead, from copies of the key inputs. It's a block or an
+/// expression, and the key keeps the type of the key inputs unless `key_type` names another one.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[cached(convert = r#"format!("{}/{}", tenant.to_lowercase(), id)"#, key_type = "String")]
+/// fn find(tenant: String, id: u64) -> String {
+///     format!("{}:{}", tenant, id)
+/// }
+///
+/// find("Acme".to_owned(), 1);
+/// assert!(find_cache_contains("ACME".to_owned(), 1));
+/// ```
+///
+/// In an `async fn` the `convert` can `.await`, e.g. to hash the inputs with an async provider.
+/// It still sees copies of the inputs listed in `key`, and `key_type` names the type of the
+/// awaited key. The companions building a key are async then, `_cache_contains` included.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// async fn digest(data: &[u8]) -> u64 {
+///     data.iter().map(|&byte| u64::from(byte)).sum()
+/// }
+///
+/// #[cached(key = "data", convert = "digest(&data).await", key_type = "u64")]
+/// async fn size(data: Vec<u8>, verbose: bool) -> usize {
+///     let _ = verbose;
+///     data.len()
+/// }
+/// ```
+///
+/// `key_expr` is a shorthand for a `convert` expression of `key_type`, it can't be combined with
+/// `key`, `convert` or `key_fn`. Like `convert` it reads copies of the inputs.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[derive(Clone)]
+/// struct Request {
+///     tenant_id: u64,
+///     path: String,
+/// }
+///
+/// #[cached(key_expr = "req.tenant_id", key_type = "u64")]
+/// fn tenant_name(req: Request) -> String {
+///     format!("tenant {} ({})", req.tenant_id, req.path)
+/// }
+/// ```
+///
+/// `key` can also name fields of the inputs, e.g. `key = "ctx.tenant_id"`. Their types can't be
+/// looked up, so `key_type` has to name the type of the key, a tuple for several fields.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// struct Context {
+///     tenant_id: u64,
+///     verbose: bool,
+/// }
+///
+/// #[cached(key = "ctx.tenant_id, id", key_type = "(u64, u64)")]
+/// fn invoice(ctx: Context, id: u64) -> String {
+///     format!("invoice {} of {} ({})", id, ctx.tenant_id, ctx.verbose)
+/// }
+/// ```
+///
+/// Every cached function gets a `<fn>_cache_clear` companion with the same visibility,
+/// which drops all the cached entries.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[cached]
+/// fn answer() -> i32 {
+///     42
+/// }
+///
+/// answer();
+/// answer_cache_clear(); // next call computes the value again
+/// ```
+///
+/// The companions are declared next to the function, so their names are taken in its module.
+/// Every cached function gets `<fn>_cache_clear`, `<fn>_cache_invalidate`, `<fn>_cache_insert`,
+/// `<fn>_if_cached`, `<fn>_cache_contains`, `<fn>_cache_entry_count`,
+/// `<fn>_cache_weighted_size`, `<fn>_cache_sync` and `<fn>_warm`. `pin`, `invalidation`,
+/// `many`, `swap`, `stats`, `dump` and `bypass` add `<fn>_pin`, `<fn>_cache_invalidate_if`,
+/// `<fn>_many`, `<fn>_cache_swap`, `<fn>_cache_stats`, `<fn>_cache_dump` and `<fn>_uncached`.
+/// The statics and private functions are named `__COLD_MOKA_<FN>*` and `__cold_moka_*_<fn>`.
+/// Declaring any of these names in the module fails with a duplicate definition. Functions with
+/// a `backend` get fewer companions, methods and functions marked `associated` get none.
+///
+/// The companions and the cache are generated only if the function is compiled: its `#[cfg]`s
+/// are evaluated before the macro runs, so a `#[cfg(test)]` function leaves nothing behind in
+/// other builds.
+///
+/// Methods taking `self`, `&self`, `&mut self` or a typed receiver like `self: Arc<Self>` are
+/// cached as well. The receiver isn't a part of the key, so the instances share a cache keyed
+/// on the other inputs. The cache lives inside the method, so its key and value types can't
+/// mention `Self` or the generics of the `impl`, and methods get no companion functions.
+///
+/// The macro can't tell an associated function without a receiver from a free function, so
+/// `associated = true` marks it. It's then cached like a method, without this the statics and
+/// companions generated next to it fail to compile inside the `impl`.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// struct Parser;
+///
+/// impl Parser {
+///     #[cached(associated = true)]
+///     fn parse(raw: String) -> Result<u64, String> {
+///         raw.parse().map_err(|_| format!("bad id {}", raw))
+///     }
+/// }
+///
+/// assert_eq!(Parser::parse("7".to_owned()), Ok(7));
+/// ```
+///
+/// Functions with const generic parameters can't be cached, the single cache would be shared by
+/// every value of the parameter. An array of any length is keyed by taking a slice instead, the
+/// key keeps a `Vec` of its items.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[cached]
+/// fn checksum(bytes: &[u8]) -> u64 {
+///     bytes.iter().map(|&byte| u64::from(byte)).sum()
+/// }
+///
+/// assert_eq!(checksum(&[1, 2, 3]), 6);
+/// assert_eq!(checksum(&[1; 4]), 4);
+/// ```
+///
+/// `group = "PATH"` makes functions share one cache, a static declared by the user with the
+/// cache type the functions would declare themselves: a `moka::sync::Cache` for functions which
+/// aren't `async` or use `sync_cache`, a `moka::future::Cache` otherwise, of the key and value
+/// types of the members. A member of another type doesn't compile. The members key their
+/// entries with `convert` or `key_fn` into a common key type, e.g. an enum, so they can't
+/// clash. The size and the expiry are the ones of the shared cache, so the arguments
+/// configuring the cache can't be used by a member, and `<fn>_cache_clear` clears the whole
+/// group.
+///
+/// ```rust
+/// use cold_moka::cached;
+/// use cold_moka::moka::sync::Cache;
+/// use cold_moka::once_cell::sync::Lazy;
+///
+/// #[derive(Clone, Hash, PartialEq, Eq)]
+/// enum UserKey {
+///     Id(u64),
+///     Email(String),
+/// }
+///
+/// static USERS: Lazy<Cache<UserKey, String>> = Lazy::new(|| Cache::new(1000));
+///
+/// #[cached(group = "USERS", convert = "UserKey::Id(id)", key_type = "UserKey")]
+/// fn user_by_id(id: u64) -> String {
+///     format!("user {}", id)
+/// }
+///
+/// #[cached(group = "USERS", convert = "UserKey::Email(email)", key_type = "UserKey")]
+/// fn user_by_email(email: String) -> String {
+///     email
+/// }
+///
+/// user_by_id(1);
+/// user_by_email("a@example.com".to_owned());
+/// assert!(USERS.contains_key(&UserKey::Id(1)));
+/// assert!(USERS.contains_key(&UserKey::Email("a@example.com".to_owned())));
+/// ```
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// struct Users {
+///     prefix: String,
+/// }
+///
+/// impl Users {
+///     #[cached]
+///     fn name(&self, id: u64) -> String {
+///         format!("{}{}", self.prefix, id)
+///     }
+/// }
+///
+/// let users = Users { prefix: "user ".to_owned() };
+/// assert_eq!(users.name(1), "user 1");
+/// ```
+///
+/// Functions building their future by hand, e.g. to keep a trait object safe or from
+/// combinators, are cached like `async` ones when they return `BoxFuture<T>`,
+/// `Pin<Box<dyn Future<Output = T>>>` or `impl Future<Output = T>`. `boxed_future = true` does
+/// the same for an alias named differently. The value the future resolves to is cached in a
+/// future cache and the function returns a new future awaiting it. The body runs on every call
+/// to build the future, which is only awaited on a miss. Methods get a cache shared between all
+/// instances and no companion functions.
+///
+/// ```rust
+/// use std::future::Future;
+/// use std::pin::Pin;
+///
+/// use cold_moka::cached;
+///
+/// trait Repo {
+///     fn fetch(&self, id: u64) -> Pin<Box<dyn Future<Output = String> + Send>>;
+/// }
+///
+/// struct Db;
+///
+/// impl Repo for Db {
+///     #[cached]
+///     fn fetch(&self, id: u64) -> Pin<Box<dyn Future<Output = String> + Send>> {
+///         Box::pin(async move { format!("row {}", id) })
+///     }
+/// }
+/// ```
+///
+/// `<fn>_cache_insert` puts a precomputed value into the cache. It takes the inputs the key is
+/// built from (the ones listed in `key`, if set) followed by the value, and is `async` for
+/// async functions.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// struct Context;
+///
+/// #[cached(key = "id")]
+/// fn render(_ctx: Context, id: u64) -> String {
+///     format!("page {}", id)
+/// }
+///
+/// render_cache_insert(1, "prerendered".to_owned());
+/// assert_eq!(render(Context, 1), "prerendered");
+/// ```
+///
+/// Fallible functions are recognized by the name of their return type, aliases named
+/// differently, like `type ApiResult<T> = Result<T, ApiError>`, need `result = true`
+/// (`option = true` for an `Option`). The first generic argument of the alias is taken as the
+/// cached value type, so the alias must keep the `Ok` type first. `result = false` and
+/// `option = false` cache a type named `Result` or `Option` as a whole value, errors included.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// type ApiResult<T> = Result<T, String>;
+///
+/// #[cached(result = true)]
+/// fn user_name(id: u64) -> ApiResult<String> {
+///     Ok(format!("user {}", id))
+/// }
+/// ```
+///
+/// `<fn>_if_cached` takes the same inputs and returns the cached value without ever computing it.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[cached]
+/// fn expensive(x: u64) -> u64 {
+///     x * 2
+/// }
+///
+/// assert_eq!(expensive_if_cached(1), None);
+/// expensive(1);
+/// assert_eq!(expensive_if_cached(1), Some(2));
+/// ```
+///
+/// `arc = true` caches the values in an `Arc`, the function returns `Arc<T>` (`Result<Arc<T>, E>`,
+/// `Option<Arc<T>>`) instead of `T`, so a hit doesn't clone a large value. Weighers and
+/// `record_size` closures get the `Arc` as well.
+///
+/// ```rust
+/// use std::sync::Arc;
+/// use cold_moka::cached;
+///
+/// #[cached(arc = true)]
+/// fn blob(len: usize) -> Vec<u8> {
+///     vec![0; len]
+/// }
+///
+/// let blob: Arc<Vec<u8>> = blob(1024);
+/// assert_eq!(blob.len(), 1024);
+/// ```
+///
+/// Functions returning an `Arc<T>` (or a `Result` or an `Option` of it) already get cheap hits,
+/// the `Arc` itself is cached, so they can't use `arc = true`.
+///
+/// `sync_cache = true` stores the values of an `async fn` in a sync cache, so hits don't go
+/// through the future cache. A miss awaits the function and inserts its value afterwards, so
+/// concurrent misses of a key all compute it. The companions stay `async`.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[cached(sync_cache = true)]
+/// async fn parse(raw: String) -> Option<u64> {
+///     raw.parse().ok()
+/// }
+/// ```
+///
+/// `blocking = true` runs the body of an `async fn` doing blocking work on tokio's blocking
+/// threads with `spawn_blocking`, so a miss doesn't stall the executor. The body can't await
+/// anything and the inputs have to be `Send + 'static`. It needs the `tokio` feature.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[cached(blocking = true)]
+/// async fn checksum(data: Vec<u8>) -> u64 {
+///     data.iter().map(|&byte| u64::from(byte)).sum()
+/// }
+/// ```
+///
+/// `trace = true` emits a `tracing` event at the trace level for every lookup, named
+/// `__COLD_MOKA_<FN> hit` or `__COLD_MOKA_<FN> miss` after the static holding the cache, with
+/// the target `cold_moka` and the name of the function as `function`. It needs the `tracing`
+/// feature.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[cached(trace = true)]
+/// fn traced(x: u64) -> u64 {
+///     x
+/// }
+/// ```
+///
+/// `wrap_return = true` tells the callers whether the value came from the cache. The function
+/// returns a [`Return<T>`](../cold_moka/struct.Return.html), or a `Result` or an `Option` of it,
+/// built with `Return::new`. The cache holds the value alone and `was_cached` is set on every
+/// call, a value computed by a concurrent caller counts as cached.
+///
+/// ```rust
+/// use cold_moka::{cached, Return};
+///
+/// #[cached(wrap_return = true)]
+/// fn page(path: String) -> Result<Return<String>, String> {
+///     Ok(Return::new(format!("<h1>{}</h1>", path)))
+/// }
+///
+/// assert!(!page("/".to_owned()).unwrap().was_cached);
+/// assert!(page("/".to_owned()).unwrap().was_cached);
+/// ```
+///
+/// `on_hit` and `on_miss` name functions called with a reference to the key on every hit and
+/// every miss, for logging or stats of your own. A miss is a computation of the value.
+///
+/// ```rust
+/// use cold_moka::cached;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// static MISSES: AtomicUsize = AtomicUsize::new(0);
+///
+/// fn missed(_: &u64) {
+///     MISSES.fetch_add(1, Ordering::Relaxed);
+/// }
+///
+/// #[cached(on_miss = "missed")]
+/// fn square(x: u64) -> u64 {
+///     x * x
+/// }
+///
+/// square(2);
+/// square(2);
+/// assert_eq!(MISSES.load(Ordering::Relaxed), 1);
+/// ```
+///
+/// `<fn>_cache_entry_count` and `<fn>_cache_weighted_size` tell how full the cache is. Sync
+/// caches run their pending tasks first, the numbers of future caches may lag behind a bit.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[cached]
+/// fn double(x: u64) -> u64 {
+///     x * 2
+/// }
+///
+/// double(1);
+/// double(2);
+/// assert_eq!(double_cache_entry_count(), 2);
+/// ```
+///
+/// Moka evicts and expires entries lazily, during its maintenance. `<fn>_cache_sync` runs it
+/// right away, so tests can check evictions deterministically. It's hidden from the docs.
+///
+/// `dump = true` generates `<fn>_cache_dump`, returning the cached entries, at most `limit` of
+/// them, in no particular order. Meant for debugging, it walks the whole cache.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[cached(dump = true)]
+/// fn triple(x: u64) -> u64 {
+///     x * 3
+/// }
+///
+/// triple(1);
+/// assert_eq!(triple_cache_dump(None), vec![(1, 3)]);
+/// assert!(triple_cache_dump(Some(0)).is_empty());
+/// ```
+///
+/// `max_weight` bounds the total weight of the entries instead of their count, with `weigher`
+/// weighing each of them. `oversize` handles the values weighing more than `oversize_fraction`
+/// (0.5 by default) of `max_weight`, which would otherwise evict most of the cache:
+/// `"bypass"` computes them on every call, `"dedicated"` (needs the `swap` feature) keeps the
+/// last one in a slot outside the cache. `on_oversize` names a function called with the weight
+/// of every oversized value. Concurrent misses of such functions aren't coalesced.
+///
+/// ```ignore
+/// #[cached(max_weight = 1_000_000, weigher = "|_, v: &Vec<u8>| v.len() as u32", oversize = "bypass")]
+/// fn load(path: String) -> Vec<u8> {
+///     std::fs::read(path).unwrap_or_default()
+/// }
+/// ```
+///
+/// `<fn>_warm` preloads the values of the given inputs, a tuple of all the inputs of the
+/// function or the input itself if there is a single one, and tells how many of them were
+/// computed and how many were already cached. Async functions warm up to `concurrency` values
+/// at once.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[cached]
+/// fn score(user: u64, game: u64) -> u64 {
+///     user * game
+/// }
+///
+/// score(1, 1);
+/// let warmed = score_warm([(1, 1), (1, 2), (2, 2)]);
+/// assert_eq!((warmed.computed, warmed.present), (2, 1));
+/// ```
+///
+/// `many = true` generates `<fn>_many`, taking inputs like `<fn>_warm` and returning what the
+/// function returns for each of them, in the order of the inputs. Async functions compute up to
+/// `concurrency` missing values at once instead of one after another.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[cached(many = true)]
+/// fn price(item: u64) -> Option<u64> {
+///     (item != 0).then_some(item * 100)
+/// }
+///
+/// assert_eq!(price_many([1, 0, 2]), vec![Some(100), None, Some(200)]);
+/// ```
+///
+/// `<fn>_cache_contains` takes the same inputs and tells whether the value is cached, unlike
+/// `<fn>_if_cached` it doesn't count as an access of the entry.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[cached(key = "id")]
+/// fn row(id: u64, verbose: bool) -> String {
+///     format!("row {} {}", id, verbose)
+/// }
+///
+/// assert!(!row_cache_contains(1));
+/// row(1, true);
+/// assert!(row_cache_contains(1));
+/// ```
+///
+/// With the `metrics` feature, every cached function counts its lookups in the
+/// [`cold_moka_cache_hits_total`](../cold_moka/metrics/constant.HITS.html) and
+/// [`cold_moka_cache_misses_total`](../cold_moka/metrics/constant.MISSES.html) counters, labeled
+/// with the name of the function as `fn`. A miss is a computation of the value. Nothing is
+/// exported until a `metrics` recorder is installed.
+///
+/// With the `metrics` feature, `record_size` takes a closure measuring a freshly computed value,
+/// the measurements go to the [`cold_moka_value_size`](../cold_moka/metrics/constant.VALUE_SIZE.html)
+/// histogram, complementing the totals of a weigher with the distribution of the sizes.
+///
+/// ```ignore
+/// #[cached(record_size = "|v| v.len() as f64")]
+/// fn load(path: String) -> Vec<u8> {
+///     std::fs::read(path).unwrap_or_default()
+/// }
+/// ```
+///
+/// `stats = true` counts the hits and misses of the function, `<fn>_cache_stats` returns them
+/// as [`CacheStats`](../cold_moka/struct.CacheStats.html). A miss is a computation of the
+/// value, concurrent callers waiting for the same computation count as hits.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[cached(stats = true)]
+/// fn square(x: u64) -> u64 {
+///     x * x
+/// }
+///
+/// square(2);
+/// square(2);
+/// let stats = square_cache_stats();
+/// assert_eq!((stats.hits, stats.misses), (1, 1));
+/// ```
+///
+/// `warmup = "30s"` (`ms`, `s`, `m` and `h` units are accepted) skips recording the metrics and
+/// stats for that long after the cache is created, so the misses of a cold cache don't skew
+/// them.
+///
+/// With the `swap` feature, `swap = true` keeps the cache behind an `ArcSwap` and generates
+/// `<fn>_cache_swap`, which fills a fresh cache with the given entries and replaces the current
+/// one in a single store. Readers see either the old or the new entries, never a mix of both,
+/// which suits reloading whole lookup tables.
+///
+/// ```ignore
+/// #[cached(swap = true)]
+/// fn route(host: String) -> Option<String> {
+///     None
+/// }
+///
+/// route_cache_swap(reloaded_routes); // `(String, String)` pairs
+/// ```
+///
+/// `<fn>_cache_invalidate` takes the inputs the key is built from and drops that single entry.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[cached(key = "id")]
+/// fn user(id: u64, verbose: bool) -> String {
+///     format!("user {} {}", id, verbose)
+/// }
+///
+/// user(1, false);
+/// user_cache_invalidate(1);
+/// assert!(!user_cache_contains(1));
+/// ```
+///
+/// `invalidation = true` generates `<fn>_cache_invalidate_if`, dropping every entry the predicate
+/// returns `true` for. The entries are removed in the background, but they aren't returned
+/// anymore once it returns.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[cached(invalidation = true)]
+/// fn report(tenant: u64, id: u64) -> String {
+///     format!("report {} of {}", id, tenant)
+/// }
+///
+/// report(1, 1);
+/// report(2, 1);
+/// report_cache_invalidate_if(|(tenant, _), _| *tenant == 1).unwrap();
+/// assert!(!report_cache_contains(1, 1));
+/// assert!(report_cache_contains(2, 1));
+/// ```
+///
+/// Arguments shared by all the cached functions of a crate can be set with the
+/// `COLD_MOKA_DEFAULTS` environment variable at build time, e.g. `COLD_MOKA_DEFAULTS="ttl = 300,
+/// stats = true"`. Arguments given to `#[cached]` take precedence, and skip the defaults they
+/// can't be used with: `value_ttl` the `ttl` and `negative_ttl`, `max_weight` the `size`.
+/// Methods ignore the defaults they don't support. Cargo doesn't know the expansion depends on
+/// the variable: rebuild the crate (`cargo clean -p <crate>`) after changing it, or set it in
+/// `.cargo/config.toml` under `[env]`.
+///
+/// `enabled` names a `static AtomicBool` switching the cache on and off at runtime, e.g. to rule
+/// it out during an incident. While it's `false` every call computes the value without looking
+/// at the cache or filling it, and `stats` counts it neither as a hit nor as a miss.
+///
+/// ```rust
+/// use std::sync::atomic::{AtomicBool, Ordering};
+/// use cold_moka::cached;
+///
+/// static PRICES_CACHED: AtomicBool = AtomicBool::new(true);
+///
+/// #[cached(enabled = "PRICES_CACHED")]
+/// fn price(sku: u64) -> u64 {
+///     sku * 100
+/// }
+///
+/// PRICES_CACHED.store(false, Ordering::Relaxed);
+/// price(1);
+/// assert!(!price_cache_contains(1));
+/// ```
+///
+/// `failpoints = true` injects the [`fail`](https://docs.rs/fail) failpoints
+/// `cold_moka::<fn>::before_get`, `cold_moka::<fn>::after_compute` and
+/// `cold_moka::<fn>::before_insert` into the function, so tests can delay or panic it with
+/// `fail::cfg`. `return` on `before_get` computes the value without looking at the cache. They
+/// are only evaluated with the `chaos` feature, without it they compile away.
+///
+/// `backend = "PATH"` replaces the cache with a static implementing `cold_moka::CacheBackend` of
+/// the key and value types, e.g. a `cold_moka::testing::RecordingBackend` to assert how a test
+/// used the cache. The function gets, computes and inserts values, concurrent misses aren't
+/// coalesced. Only `<fn>_cache_invalidate`, `<fn>_cache_insert` and `<fn>_if_cached` are
+/// generated, and the arguments which need a moka cache can't be used.
+///
+/// ```rust
+/// use cold_moka::cached;
+/// use cold_moka::once_cell::sync::Lazy;
+/// use cold_moka::testing::{Op, RecordingBackend};
+///
+/// static PRICES: Lazy<RecordingBackend<u64, u64>> = Lazy::new(RecordingBackend::new);
+///
+/// #[cached(backend = "PRICES")]
+/// fn price(sku: u64) -> u64 {
+///     sku * 100
+/// }
+///
+/// price(1);
+/// price(1);
+/// assert_eq!(
+///     PRICES.ops(),
+///     vec![Op::Get { key: 1, hit: false }, Op::Insert(1), Op::Get { key: 1, hit: true }]
+/// );
+/// ```
+///
+/// `bypass = true` generates `<fn>_uncached`, which takes the same arguments and runs the body
+/// without looking at the cache or filling it, e.g. for a value which must be fresh right after
+/// a write. It keeps the visibility, the asyncness and the `#[allow]`s of the function, and
+/// `bypass_name` names it differently when `<fn>_uncached` is taken.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[cached(bypass = true)]
+/// fn balance(account: u64) -> u64 {
+///     account * 100
+/// }
+///
+/// assert_eq!(balance_uncached(1), 100);
+/// assert!(!balance_cache_contains(1));
+/// ```
+///
+/// `pin = true` generates `<fn>_pin` with the same arguments as the function. Entries cached
+/// through it never expire, while the rest of the cache keeps obeying `ttl`.
+///
+/// ```rust
+/// use cold_moka::cached;
+///
+/// #[cached(ttl = 60, pin = true)]
+/// fn config(name: String) -> String {
+///     name.to_uppercase()
+/// }
+///
+/// config_pin("hot".to_owned()); // stays cached past the ttl
+/// config("cold".to_owned()); // expires after 60 seconds
+/// ```
+#[proc_macro_attribute]
+pub fn cached(args: TokenStream, input: TokenStream) -> TokenStream {
+    let attr_args = match NestedMeta::parse_meta_list(args.into()) {
+        Ok(v) => v,
+        Err(e) => {
+            return TokenStream::from(darling::Error::from(e).write_errors());
+        }
+    };
+    let input = parse_macro_input!(input as ItemFn);
+    let is_method = matches!(input.sig.inputs.first(), Some(FnArg::Receiver(_)))
+        || attr_args.iter().any(|arg| is_flag_set(arg, "associated"));
+    // proc macros can't declare the variable as an input of the build, so changing it
+    // doesn't rebuild the crates which already expanded `#[cached]`
+    let attr_args = match std::env::var(DEFAULTS_VAR) {
+        Ok(defaults) => match with_defaults(attr_args, &defaults, is_method) {
+            Ok(v) => v,
+            Err(e) => {
+                return Diagnostic::InvalidArgument
+                    .compile_error(
+                        Span::call_site(),
+                        format!("unable to parse {}: {}", DEFAULTS_VAR, e),
+                    )
+                    .into();
+            }
+        },
+        Err(_) => attr_args,
+    };
+    let mut args = match MacroArgs::from_list(&attr_args) {
+        Ok(v) => v,
+        Err(e) => {
+            return TokenStream::from(e.write_errors());
+        }
+    };
+    if let Some(key_expr) = args.key_expr.take() {
+        if args.key.is_some() || args.convert.is_some() || args.key_fn.is_some() {
+            return Diagnostic::ConflictingArguments
+                .compile_error(
+                    arg_span(&attr_args, "key_expr"),
+                    "key_expr can't be used together with key, convert or key_fn",
+                )
+                .into();
+        }
+        if args.cache_type.is_none() {
+            return Diagnostic::MissingArgument
+                .compile_error(
+                    arg_span(&attr_args, "key_expr"),
+                    "key_expr requires key_type to be set",
+                )
+                .into();
+        }
+        args.convert = Some(key_expr);
+    }
+    if args.group.is_some() {
+        let configured = attr_args
+            .iter()
+            .filter_map(meta_name)
+            .find(|name| GROUP_UNSUPPORTED.contains(&name.as_str()));
+        if let Some(name) = configured {
+            return Diagnostic::ConflictingArguments
+                .compile_error(
+                    arg_span(&attr_args, "group"),
+                    format!(
+                        "{} configures the cache, it can't be used by a group member which uses the cache of the group",
+                        name
+                    ),
+                )
+                .into();
+        }
+    }
+
+    // pull out the parts of the input
+    let attributes = input.attrs;
+    let visibility = input.vis;
+    let signature = input.sig;
+    let body = input.block;
+
+    // pull out the parts of the function signature
+    let fn_ident = signature.ident.clone();
+    let inputs = signature.inputs.clone();
+    let output = signature.output.clone();
+    // the cache is a single static, every `N` would share it
+    if let Some(param) = signature.generics.const_params().next() {
+        return Diagnostic::UnsupportedFunction
+            .compile_error(
+                param.span(),
+                format!(
+                    "const generic parameters aren't supported, all the values of `{}` would share one cache, take a slice instead of an array",
+                    param.ident
+                ),
+            )
+            .into();
+    }
+    let fn_output_ty = match &output {
+        ReturnType::Default => quote! {()},
+        ReturnType::Type(_, ty) => quote! {#ty},
+    };
+    let is_async = signature.asyncness.is_some();
+    // functions returning a future without being `async`, e.g. built from combinators, cache
+    // the value it resolves to like the boxed ones
+    if !is_async && boxed_future_output(&output, fn_ident.span()).is_ok() {
+        args.boxed_future = true;
+    }
+    // `impl Future` can't name the type of the body, nor has to be boxed to be returned
+    let impl_future = args.boxed_future
+        && matches!(&output, ReturnType::Type(_, ty) if matches!(ty.as_ref(), Type::ImplTrait(_)));
+    // methods can't have items next to them (think of trait impls), so their cache stays
+    // inside the function and no companion functions are generated
+    let is_method = args.associated || inputs.iter().any(|x| matches!(x, FnArg::Receiver(_)));
+    if args.boxed_future && is_async {
+        return Diagnostic::ConflictingArguments
+            .compile_error(
+                signature.asyncness.span(),
+                "boxed_future is meant for functions returning a boxed future without `async`",
+            )
+            .into();
+    }
+    if args.blocking && (!is_async || is_method || args.key_borrow) {
+        return Diagnostic::ConflictingArguments
+            .compile_error(
+                fn_ident.span(),
+                "blocking is meant for `async fn`s which aren't methods and don't use key_borrow",
+            )
+            .into();
+    }
+    if args.sync_cache && !is_async {
+        return Diagnostic::ConflictingArguments
+            .compile_error(fn_ident.span(), "sync_cache is meant for `async fn`s")
+            .into();
+    }
+    let async_key = args
+        .convert
+        .as_deref()
+        .and_then(|convert| convert.parse::<TokenStream2>().ok())
+        .is_some_and(awaits);
+    if async_key && !is_async {
+        return Diagnostic::ConflictingArguments
+            .compile_error(
+                arg_span(&attr_args, "convert"),
+                "convert can only `.await` in an `async fn`",
+            )
+            .into();
+    }
+    // a sync cache of an `async fn` still gets async companions, they only don't await the cache
+    let async_fn = is_async || args.boxed_future;
+    // a backend is looked up without awaiting, like a sync cache
+    let async_cache = async_fn && !args.sync_cache && args.backend.is_none();
+    let cache_await = async_cache.then(|| quote! {.await});
+    if args.wrap_return && (args.arc || args.boxed_future) {
+        return Diagnostic::ConflictingArguments
+            .compile_error(
+                fn_ident.span(),
+                "wrap_return is not supported with arc or boxed_future",
+            )
+            .into();
+    }
+    if args.arc && args.boxed_future {
+        return Diagnostic::ConflictingArguments
+            .compile_error(fn_ident.span(), "arc is not supported with boxed_future")
+            .into();
+    }
+    if args.pin && is_method {
+        return Diagnostic::ConflictingArguments
+            .compile_error(fn_ident.span(), "pin is not supported on methods")
+            .into();
+    }
+    if args.swap && is_method {
+        return Diagnostic::ConflictingArguments
+            .compile_error(fn_ident.span(), "swap is not supported on methods")
+            .into();
+    }
+    if args.stats && is_method {
+        return Diagnostic::ConflictingArguments
+            .compile_error(fn_ident.span(), "stats is not supported on methods")
+            .into();
+    }
+    if args.dump && is_method {
+        return Diagnostic::ConflictingArguments
+            .compile_error(fn_ident.span(), "dump is not supported on methods")
+            .into();
+    }
+    if args.invalidation && is_method {
+        return Diagnostic::ConflictingArguments
+            .compile_error(fn_ident.span(), "invalidation is not supported on methods")
+            .into();
+    }
+    if args.many && is_method {
+        return Diagnostic::ConflictingArguments
+            .compile_error(fn_ident.span(), "many is not supported on methods")
+            .into();
+    }
+    if args.backend.is_some() {
+        let configured = attr_args
+            .iter()
+            .filter_map(meta_name)
+            .find(|name| BACKEND_UNSUPPORTED.contains(&name.as_str()));
+        if let Some(name) = configured {
+            return Diagnostic::ConflictingArguments
+                .compile_error(
+                    arg_span(&attr_args, "backend"),
+                    format!(
+                        "{} needs a moka cache, it can't be used with a backend which only gets, inserts and invalidates values",
+                        name
+                    ),
+                )
+                .into();
+        }
+    }
+    if args.bypass && (is_method || args.boxed_future || args.blocking) {
+        return Diagnostic::ConflictingArguments
+            .compile_error(
+                fn_ident.span(),
+                "bypass is not supported on methods, with boxed_future or blocking",
+            )
+            .into();
+    }
+    let bypass_ident = match (&args.bypass_name, args.bypass) {
+        (Some(name), true) => match parse_str::<Ident>(name) {
+            Ok(ident) => ident,
+            Err(_) => {
+                return Diagnostic::InvalidArgument
+                    .compile_error(
+                        arg_span(&attr_args, "bypass_name"),
+                        "unable to parse bypass_name",
+                    )
+                    .into()
+            }
+        },
+        (Some(_), false) => {
+            return Diagnostic::ConflictingArguments
+                .compile_error(
+                    arg_span(&attr_args, "bypass_name"),
+                    "bypass_name names the function generated by bypass = true",
+                )
+                .into();
+        }
+        (None, _) => companion_ident(&fn_ident, "uncached"),
+    };
+
+    if args.key.is_some() && args.ignore.is_some() {
+        return Diagnostic::ConflictingArguments
+            .compile_error(
+                arg_span(&attr_args, "ignore"),
+                "key and ignore can't be used together",
+            )
+            .into();
+    }
+    let key_encoding = match (args.key_hash, args.debug_key) {
+        (true, true) => {
+            return Diagnostic::ConflictingArguments
+                .compile_error(
+                    arg_span(&attr_args, "debug_key"),
+                    "key_hash and debug_key can't be used together",
+                )
+                .into();
+        }
+        (true, false) => KeyEncoding::Hash,
+        (false, true) => KeyEncoding::Debug,
+        (false, false) => KeyEncoding::Inputs,
+    };
+    if key_encoding != KeyEncoding::Inputs && (args.key_fn.is_some() || args.convert.is_some()) {
+        return Diagnostic::ConflictingArguments
+            .compile_error(
+                arg_span(
+                    &attr_args,
+                    match key_encoding {
+                        KeyEncoding::Hash => "key_hash",
+                        _ => "debug_key",
+                    },
+                ),
+                "key_hash and debug_key can't be used together with key_fn or convert",
+            )
+            .into();
+    }
+    let split_names = |x: &String| {
+        x.split(',')
+            .map(|x| x.trim().to_owned())
+            .collect::<Vec<String>>()
+    };
+    let ignore_args = args.ignore.as_ref().map(split_names);
+
+    let input_names: Vec<_> = get_input_names(&inputs).map(|x| x.0).collect();
+    let input_tys = match get_input_types(&inputs) {
+        Ok(input_tys) => input_tys,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    let key_fields = match &args.key {
+        Some(key) if key.contains('.') => {
+            match parse_key_fields(key, &input_names, arg_span(&attr_args, "key")) {
+                Ok(fields) => Some(fields),
+                Err(e) => return e.to_compile_error().into(),
+            }
+        }
+        _ => None,
+    };
+    if key_fields.is_some() && (args.key_fn.is_some() || args.convert.is_some()) {
+        return Diagnostic::ConflictingArguments
+            .compile_error(
+                arg_span(&attr_args, "key"),
+                "key fields can't be used together with key_fn or convert",
+            )
+            .into();
+    }
+    if key_fields.is_some() && key_encoding == KeyEncoding::Inputs && args.cache_type.is_none() {
+        return Diagnostic::MissingArgument
+            .compile_error(
+                arg_span(&attr_args, "key"),
+                "key fields require key_type to be set",
+            )
+            .into();
+    }
+    // a misspelled name would silently leave its input out of the key, or in it
+    let listed_names = [
+        ("key", args.key.as_ref().filter(|_| key_fields.is_none())),
+        ("ignore", args.ignore.as_ref()),
+    ];
+    for (arg, list) in listed_names {
+        let Some(list) = list else {
+            continue;
+        };
+        let span = arg_span(&attr_args, arg);
+        let names = split_names(list);
+        for (idx, name) in names.iter().enumerate() {
+            if names[..idx].contains(name) {
+                return Diagnostic::InvalidArgument
+                    .compile_error(
+                        span,
+                        format!("`{}` is listed more than once in {}", name, arg),
+                    )
+                    .into();
+            }
+            if !input_names.iter().any(|ident| ident == name) {
+                let available: Vec<_> = input_names.iter().map(ToString::to_string).collect();
+                return Diagnostic::InvalidArgument
+                    .compile_error(
+                        span,
+                        format!(
+                            "`{}` is not an argument of `{}`, expected one of: {}",
+                            name,
+                            fn_ident,
+                            available.join(", ")
+                        ),
+                    )
+                    .into();
+            }
+        }
+    }
+    // the inputs the fields belong to make up the key
+    let filter_args_by = match &key_fields {
+        Some(fields) => Some(
+            fields
+                .iter()
+                .filter_map(field_root)
+                .map(|root| root.to_string())
+                .collect(),
+        ),
+        None => args.key.as_ref().map(split_names),
+    };
+
+    // the key tuple follows the order of `key`, otherwise the order of the parameters
+    let cache_key_type_indexes: Vec<usize> = match &filter_args_by {
+        Some(filter) => {
+            let mut indexes = Vec::new();
+            for name in filter {
+                match input_names.iter().position(|ident| ident == name) {
+                    Some(idx) if !indexes.contains(&idx) => indexes.push(idx),
+                    _ => {}
+                }
+            }
+            indexes
+        }
+        None => input_names
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, ident)| match &ignore_args {
+                Some(ignore) => (!ignore.contains(&ident.to_string())).then_some(idx),
+                None => Some(idx),
+            })
+            .collect(),
+    };
+
+    // `key_fn` names the key type itself, the inputs are only borrowed
+    if args.key_fn.is_none() {
+        let impl_trait = input_tys
+            .iter()
+            .enumerate()
+            .filter(|(idx, _)| cache_key_type_indexes.contains(idx))
+            .find_map(|(_, ty)| find_impl_trait(ty));
+        if let Some(impl_trait) = impl_trait {
+            return Diagnostic::UnnameableKey
+                .compile_error(
+                    impl_trait.span(),
+                    "`impl Trait` inputs can't be a part of the key, leave them out with `key`",
+                )
+                .into();
+        }
+    }
+
+    let inner_function_call_args = match get_wrapped_type_for_function_call(&inputs) {
+        Ok(args) => args,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    // boxed futures cache the value they resolve to
+    let value_output = if args.boxed_future {
+        let ty = match boxed_future_output(&output, arg_span(&attr_args, "boxed_future")) {
+            Ok(ty) => ty,
+            Err(e) => return e.to_compile_error().into(),
+        };
+        parse_quote! { -> #ty }
+    } else {
+        output.clone()
+    };
+    // `wrap_return` functions cache the value inside the `Return<T>`, which is built again
+    // around every lookup in the same shape
+    let (value_output, return_shape) = if args.wrap_return {
+        match unwrap_return(&value_output) {
+            Ok((value_output, shape)) => (value_output, Some(shape)),
+            Err(e) => return e.to_compile_error().into(),
+        }
+    } else {
+        (value_output, None)
+    };
+
+    // pull out the output type
+    let output_ty = match &value_output {
+        ReturnType::Default => quote! {()},
+        ReturnType::Type(_, ty) => quote! {#ty},
+    };
+
+    let return_ty = match (args.result, args.option) {
+        (Some(true), Some(true)) => {
+            return Diagnostic::ConflictingArguments
+                .compile_error(
+                    arg_span(&attr_args, "option"),
+                    "`result = true` can't be used with `option = true`",
+                )
+                .into()
+        }
+        (Some(true), _) if result_holds_option(&value_output) => RetTurnTy::ResultOption,
+        (Some(true), _) => RetTurnTy::Result,
+        (_, Some(true)) => RetTurnTy::Option,
+        (result, option) => match return_fallible_type(&value_output) {
+            RetTurnTy::Result | RetTurnTy::ResultOption if result == Some(false) => RetTurnTy::Bare,
+            RetTurnTy::Option if option == Some(false) => RetTurnTy::Bare,
+            return_ty => return_ty,
+        },
+    };
+    // errors and `None`s are cached as a part of the value, so the function is treated as a
+    // bare one. `Ok(None)`s are cached as the value of a plain `Result`
+    let return_ty = match return_ty {
+        RetTurnTy::Result | RetTurnTy::ResultOption if args.cache_errors => RetTurnTy::Bare,
+        RetTurnTy::ResultOption if args.cache_none => RetTurnTy::Result,
+        RetTurnTy::Option if args.cache_none => RetTurnTy::Bare,
+        _ if args.cache_errors => {
+            return Diagnostic::ConflictingArguments
+                .compile_error(
+                    fn_ident.span(),
+                    "cache_errors only applies to functions returning a `Result`",
+                )
+                .into()
+        }
+        _ if args.cache_none => {
+            return Diagnostic::ConflictingArguments
+                .compile_error(
+                    fn_ident.span(),
+                    "cache_none only applies to functions returning an `Option` or a `Result<Option<T>, E>`",
+                )
+                .into()
+        }
+        _ if args.negative_ttl.is_some() => {
+            return Diagnostic::MissingArgument
+                .compile_error(
+                    arg_span(&attr_args, "negative_ttl"),
+                    "`negative_ttl` requires `cache_errors`",
+                )
+                .into()
+        }
+        return_ty => return_ty,
+    };
+    if return_ty != RetTurnTy::Bare && matches!(value_output, ReturnType::Default) {
+        return Diagnostic::UnsupportedReturnType
+            .compile_error(
+                fn_ident.span(),
+                "`result = true` and `option = true` need the function to return a value",
+            )
+            .into();
+    }
+    if args.arc && holds_arc(return_ty, &value_output) {
+        return Diagnostic::ConflictingArguments
+            .compile_error(
+                signature.output.span(),
+                "the function returns an `Arc` already, it is cached as is, remove arc = true",
+            )
+            .into();
+    }
+    // the stale value is looked up with the key once the computation failed, which oversized
+    // values have already moved into the cache
+    if args.result_fallback
+        && (return_ty != RetTurnTy::Result
+            || args.boxed_future
+            || args.oversize.is_some()
+            || args.cache_create.is_some())
+    {
+        return Diagnostic::ConflictingArguments
+            .compile_error(
+                fn_ident.span(),
+                "result_fallback only applies to functions returning a `Result`, without boxed_future, oversize or cache_create",
+            )
+            .into();
+    }
+    if args.cache_if.is_some() && args.boxed_future {
+        return Diagnostic::ConflictingArguments
+            .compile_error(
+                fn_ident.span(),
+                "cache_if is not supported with boxed_future",
+            )
+            .into();
+    }
+    let cache_if = match args.cache_if.as_deref().map(|cache_if| {
+        parse_argument::<Expr>(cache_if, "cache_if", arg_span(&attr_args, "cache_if"))
+    }) {
+        Some(Ok(cache_if)) => Some(cache_if),
+        Some(Err(e)) => return e.to_compile_error().into(),
+        None => None,
+    };
+    // values inserted by the wrapper itself are checked first, the rest is dropped right after
+    // the lookup inserted it
+    let keep_if = |insert: TokenStream2| match &cache_if {
+        Some(cache_if) => quote! {
+            if (#cache_if)(value) {
+                #insert
+            }
+        },
+        None => insert,
+    };
+    // the given type keeps the span of the attribute, so a mismatch is reported there
+    let cache_value_ty = match &args.value_type {
+        Some(_) if args.arc => {
+            return Diagnostic::ConflictingArguments
+                .compile_error(
+                    arg_span(&attr_args, "value_type"),
+                    "value_type can't be used together with arc",
+                )
+                .into()
+        }
+        Some(value_type) => {
+            parse_argument::<Type>(value_type, "value_type", arg_span(&attr_args, "value_type"))
+                .map(|value_type| value_type.into_token_stream())
+        }
+        None => find_value_type(
+            return_ty,
+            &value_output,
+            output_ty,
+            arg_span(
+                &attr_args,
+                if args.option == Some(true) {
+                    "option"
+                } else {
+                    "result"
+                },
+            ),
+        ),
+    };
+    let cache_value_ty = match cache_value_ty {
+        Ok(cache_value_ty) if args.arc => quote! { ::std::sync::Arc<#cache_value_ty> },
+        Ok(cache_value_ty) => cache_value_ty,
+        Err(e) => return e.to_compile_error().into(),
+    };
+    let error_conversion = match (args.error.as_deref(), &args.from_arc) {
+        (Some(_), _) | (_, Some(_))
+            if !matches!(return_ty, RetTurnTy::Result | RetTurnTy::ResultOption) =>
+        {
+            return Diagnostic::ConflictingArguments
+                .compile_error(
+                    arg_span(
+                        &attr_args,
+                        if args.error.is_some() {
+                            "error"
+                        } else {
+                            "from_arc"
+                        },
+                    ),
+                    "`error` and `from_arc` only apply to functions returning a `Result`",
+                )
+                .into()
+        }
+        (Some("arc"), Some(from_arc)) => {
+            match parse_argument::<Expr>(from_arc, "from_arc", arg_span(&attr_args, "from_arc")) {
+                Ok(from_arc) => ErrorConversion::FromArc(from_arc),
+                Err(e) => return e.to_compile_error().into(),
+            }
+        }
+        (_, Some(_)) => {
+            return Diagnostic::MissingArgument
+                .compile_error(
+                    arg_span(&attr_args, "from_arc"),
+                    "`from_arc` requires `error = \"arc\"`",
+                )
+                .into()
+        }
+        (None, None) if has_shared_error(&value_output) => ErrorConversion::Shared,
+        (None | Some("clone" | "arc"), None) => ErrorConversion::Clone,
+        (Some("shared"), None) => ErrorConversion::Shared,
+        (Some(other), None) => {
+            return Diagnostic::InvalidArgument
+                .compile_error(
+                    arg_span(&attr_args, "error"),
+                    format!(
+                        "unknown error `{}`, expected \"clone\", \"shared\" or \"arc\"",
+                        other
+                    ),
+                )
+                .into()
+        }
+    };
+    let cache_ident = static_ident(&fn_ident, None);
+    let pinned_ident = args.pin.then(|| static_ident(&fn_ident, Some("PINNED")));
+    if args.value_ttl && (args.ttl.is_some() || args.negative_ttl.is_some()) {
+        return Diagnostic::ConflictingArguments
+            .compile_error(
+                arg_span(&attr_args, "value_ttl"),
+                "`value_ttl` replaces `ttl`, it can't be used with `ttl` or `negative_ttl`",
+            )
+            .into();
+    }
+    if pinned_ident.is_some() && args.negative_ttl.is_some() {
+        return Diagnostic::ConflictingArguments
+            .compile_error(
+                arg_span(&attr_args, "pin"),
+                "`pin` can't be used with `negative_ttl`",
+            )
+            .into();
+    }
+    if pinned_ident.is_some() && args.ttl.is_none() {
+        return Diagnostic::MissingArgument
+            .compile_error(
+                arg_span(&attr_args, "pin"),
+                "`pin` requires `ttl` to be set",
+            )
+            .into();
+    }
+
+    let weigher = match (&args.weigher, args.max_weight) {
+        (Some(weigher), Some(_)) => match parse_str::<Expr>(weigher) {
+            Ok(weigher) => Some(weigher),
+            Err(_) => {
+                return Diagnostic::InvalidArgument
+                    .compile_error(
+                        arg_span(&attr_args, "weigher"),
+                        "unable to parse weigher closure",
+                    )
+                    .into()
+            }
+        },
+        (None, None) => None,
+        _ => {
+            return Diagnostic::MissingArgument
+                .compile_error(
+                    arg_span(
+                        &attr_args,
+                        if args.weigher.is_some() {
+                            "weigher"
+                        } else {
+                            "max_weight"
+                        },
+                    ),
+                    "`weigher` and `max_weight` have to be set together",
+                )
+                .into()
+        }
+    };
+    if args.max_weight.is_some() && args.size.is_some() {
+        return Diagnostic::ConflictingArguments
+            .compile_error(
+                arg_span(&attr_args, "size"),
+                "`size` can't be used with `max_weight`",
+            )
+            .into();
+    }
+    let oversize = match args.oversize.as_deref() {
+        None => {
+            if args.oversize_fraction.is_some() || args.on_oversize.is_some() {
+                return Diagnostic::MissingArgument
+                    .compile_error(
+                        arg_span(
+                            &attr_args,
+                            if args.on_oversize.is_some() {
+                                "on_oversize"
+                            } else {
+                                "oversize_fraction"
+                            },
+                        ),
+                        "`oversize_fraction` and `on_oversize` require `oversize`",
+                    )
+                    .into();
+            }
+            None
+        }
+        Some(_) if weigher.is_none() => {
+            return Diagnostic::MissingArgument
+                .compile_error(
+                    arg_span(&attr_args, "oversize"),
+                    "`oversize` requires `weigher` and `max_weight`",
+                )
+                .into()
+        }
+        Some("bypass") => Some(Oversize::Bypass),
+        Some("dedicated") => Some(Oversize::Dedicated),
+        Some(other) => {
+            return Diagnostic::InvalidArgument
+                .compile_error(
+                    arg_span(&attr_args, "oversize"),
+                    format!(
+                        "unknown oversize `{}`, expected \"bypass\" or \"dedicated\"",
+                        other
+                    ),
+                )
+                .into()
+        }
+    };
+    let on_oversize = match &args.on_oversize {
+        Some(on_oversize) => match parse_str::<Path>(on_oversize) {
+            Ok(on_oversize) => Some(on_oversize),
+            Err(_) => {
+                return Diagnostic::InvalidArgument
+                    .compile_error(
+                        arg_span(&attr_args, "on_oversize"),
+                        "unable to parse on_oversize path",
+                    )
+                    .into()
+            }
+        },
+        None => None,
+    };
+    let parse_hook = |hook: &Option<String>, name: &str| match hook {
+        Some(hook) => parse_str::<Path>(hook).map(Some).map_err(|_| {
+            Diagnostic::InvalidArgument.compile_error(
+                arg_span(&attr_args, name),
+                format!("unable to parse {} path", name),
+            )
+        }),
+        None => Ok(None),
+    };
+    let on_hit = match parse_hook(&args.on_hit, "on_hit") {
+        Ok(on_hit) => on_hit,
+        Err(e) => return e.into(),
+    };
+    let on_miss = match parse_hook(&args.on_miss, "on_miss") {
+        Ok(on_miss) => on_miss,
+        Err(e) => return e.into(),
+    };
+
+    let key_type = match &key_fields {
+        Some(fields) => make_field_key_type(&attr_args, fields, &args.cache_type, key_encoding),
+        None => make_cache_key_type(
+            &attr_args,
+            &cache_key_type_indexes,
+            &args.convert,
+            &args.key_fn,
+            &args.cache_type,
+            key_encoding,
+            input_tys.clone(),
+            &input_names,
+        ),
+    };
+    let (cache_key_ty, key_body) = match key_type {
+        Ok(key_type) => key_type,
+        Err(e) => return e.to_compile_error().into(),
+    };
+
+    // companions working with a single entry take only the inputs the key is built from
+    let key_inputs: Vec<_> = cache_key_type_indexes
+        .iter()
+        .map(|&idx| (&input_names[idx], &input_tys[idx]))
+        .collect();
+    let key_params: Vec<_> = key_inputs
+        .iter()
+        .map(|(name, ty)| quote! {#name: #ty})
+        .collect();
+
+    // the key is built by a single function, so the wrapper and the companions can't disagree
+    let key_fn_ident = private_ident(&fn_ident, "key");
+    let key_names: Vec<_> = key_inputs.iter().map(|(name, _)| name).collect();
+    let key_ref_params = key_inputs.iter().map(|(name, ty)| quote! {#name: &#ty});
+    let key_asyncness = async_key.then(|| quote! {async});
+    let key_await = async_key.then(|| quote! {.await});
+    let key_function = quote! {
+        #[allow(clippy::ptr_arg, clippy::unused_unit)]
+        #key_asyncness fn #key_fn_ident(#(#key_ref_params),*) -> #cache_key_ty {
+            #key_body
+        }
+    };
+    let key_convert_block = quote! { #key_fn_ident(#(&#key_names),*)#key_await };
+
+    // hits look the value up with the inputs themselves instead of a copy of them
+    let plain_inputs: HashSet<_> = inputs
+        .iter()
+        .filter_map(|input| match input {
+            FnArg::Typed(pat_type) => match pat_type.pat.deref() {
+                Pat::Ident(pat_ident) if pat_ident.subpat.is_none() => Some(&pat_ident.ident),
+                _ => None,
+            },
+            FnArg::Receiver(_) => None,
+        })
+        .collect();
+    if args.key_borrow {
+        if args.boxed_future
+            || is_method
+            || args.convert.is_some()
+            || args.key_fn.is_some()
+            || key_encoding != KeyEncoding::Inputs
+            || key_fields.is_some()
+        {
+            return Diagnostic::ConflictingArguments
+                .compile_error(
+                    arg_span(&attr_args, "key_borrow"),
+                    "key_borrow can't be used on methods or together with boxed_future, convert, key_fn, key_hash, debug_key or key fields",
+                )
+                .into();
+        }
+        if let Some((name, _)) = key_inputs.iter().find(|(_, ty)| owned_key_type(ty).1 > 0) {
+            return Diagnostic::ConflictingArguments
+                .compile_error(
+                    name.span(),
+                    "key_borrow can't be used with borrowed key inputs, they are copied into the key anyway",
+                )
+                .into();
+        }
+        if let Some(name) = key_names.iter().find(|name| !plain_inputs.contains(**name)) {
+            return Diagnostic::UnsupportedPattern
+                .compile_error(
+                    name.span(),
+                    "key_borrow needs the key inputs to be bound to plain identifiers",
+                )
+                .into();
+        }
+    }
+    // locals of the wrapper are hygienic, so inputs named like them don't clash
+    let key = local_ident("key");
+    let key_ref = local_ident("key_ref");
+    let was_cached = local_ident("was_cached");
+    let key_statement = if args.key_borrow {
+        let borrow = (!key_names.is_empty()).then(|| quote! { let #key_ref = &#key; });
+        quote! {
+            let #key = (#(#key_names),*);
+            #borrow
+        }
+    } else {
+        quote! {
+            let #key = #key_convert_block;
+        }
+    };
+
+    let size = if inner_function_call_args.is_empty() {
+        args.size.unwrap_or(1) // () is the only possible input
+    } else {
+        args.size.unwrap_or(1000)
+    };
+
+    // pinned keys live next to the cache, the expiry of the cache consults them
+    let pinned_static = pinned_ident.as_ref().map(|pinned_ident| {
+        quote! {
+            static #pinned_ident: ::cold_moka::once_cell::sync::Lazy<::cold_moka::PinnedKeys<#cache_key_ty>> = ::cold_moka::once_cell::sync::Lazy::new(::std::default::Default::default);
+        }
+    });
+
+    // make the cache type and create statement
+    let (cache_ty, mut cache_create) = cache_creation_statement(
+        &args,
+        async_cache,
+        cache_value_ty.clone(),
+        cache_key_ty.clone(),
+        size as u64,
+        weigher.as_ref(),
+        pinned_ident.as_ref(),
+    );
+    // values leaving the cache are kept aside until their key is invalidated
+    let stale_ident = static_ident(&fn_ident, Some("STALE"));
+    let stale_static = args.result_fallback.then(|| {
+        cache_create = quote! {
+            #cache_create.eviction_listener(|key: ::std::sync::Arc<#cache_key_ty>, value: #cache_value_ty, cause: ::cold_moka::moka::notification::RemovalCause| {
+                match cause {
+                    ::cold_moka::moka::notification::RemovalCause::Expired
+                    | ::cold_moka::moka::notification::RemovalCause::Size => {
+                        #stale_ident.keep((*key).clone(), value)
+                    }
+                    ::cold_moka::moka::notification::RemovalCause::Explicit => #stale_ident.forget(&key),
+                    ::cold_moka::moka::notification::RemovalCause::Replaced => {}
+                }
+            })
+        };
+        quote! {
+            static #stale_ident: ::cold_moka::once_cell::sync::Lazy<::cold_moka::StaleValues<#cache_key_ty, #cache_value_ty>> = ::cold_moka::once_cell::sync::Lazy::new(|| ::cold_moka::StaleValues::new(#size));
+        }
+    });
+    cache_create = quote! { #cache_create.build() };
+    if let Some(create) = args.cache_create {
+        cache_create = quote! {#create};
+    }
+    // swapped in caches are built the same way, they just don't start the warmup
+    let fresh_cache = cache_create.clone();
+
+    // the warmup window starts together with the cache
+    let warmup_ident = static_ident(&fn_ident, Some("WARMUP"));
+    let warmup_static = match &args.warmup {
+        Some(warmup) => {
+            if args.record_size.is_none() && !args.stats {
+                return Diagnostic::MissingArgument
+                    .compile_error(
+                        arg_span(&attr_args, "warmup"),
+                        "warmup only affects metrics, it requires record_size or stats",
+                    )
+                    .into();
+            }
+            let millis = match parse_duration(warmup) {
+                Some(duration) => duration.as_millis() as u64,
+                None => {
+                    return Diagnostic::InvalidArgument
+                        .compile_error(
+                            arg_span(&attr_args, "warmup"),
+                            format!("unable to parse warmup duration `{}`", warmup),
+                        )
+                        .into()
+                }
+            };
+            cache_create = quote! {
+                {
+                    #warmup_ident.start();
+                    #cache_create
+                }
+            };
+            Some(quote! {
+                static #warmup_ident: ::cold_moka::Warmup = ::cold_moka::Warmup::new(::std::time::Duration::from_millis(#millis));
+            })
+        }
+        None => None,
+    };
+    let record_metrics = |metrics: TokenStream2| {
+        if warmup_static.is_some() {
+            quote! {
+                if #warmup_ident.is_over() {
+                    #metrics
+                }
+            }
+        } else {
+            metrics
+        }
+    };
+
+    let oversize_ident = static_ident(&fn_ident, Some("OVERSIZE"));
+    let oversize_static = (oversize == Some(Oversize::Dedicated)).then(|| {
+        quote! {
+            static #oversize_ident: ::cold_moka::OversizeSlot<#cache_key_ty, #cache_value_ty> = ::cold_moka::OversizeSlot::new();
+        }
+    });
+
+    let stats_ident = static_ident(&fn_ident, Some("STATS"));
+    let stats_static = args.stats.then(|| {
+        quote! {
+            static #stats_ident: ::cold_moka::HitMissCounters = ::cold_moka::HitMissCounters::new();
+        }
+    });
+    let record_call = args
+        .stats
+        .then(|| record_metrics(quote! { #stats_ident.call(); }));
+
+    let no_cache_fn_ident = private_ident(&fn_ident, "inner");
+    let cache_static = if args.swap {
+        quote! {
+            static #cache_ident: ::cold_moka::once_cell::sync::Lazy<::cold_moka::arc_swap::ArcSwap<#cache_ty>> = ::cold_moka::once_cell::sync::Lazy::new(|| ::cold_moka::arc_swap::ArcSwap::from_pointee(#cache_create));
+        }
+    } else {
+        quote! {
+            static #cache_ident: ::cold_moka::once_cell::sync::Lazy<#cache_ty> = ::cold_moka::once_cell::sync::Lazy::new(|| #cache_create);
+        }
+    };
+    // misuse fails on these bounds, before moka reports it deep inside the generated code. A
+    // key made of the inputs themselves is checked input by input, the error names the input
+    let key_assertions = if args.convert.is_none()
+        && args.key_fn.is_none()
+        && key_fields.is_none()
+        && key_encoding == KeyEncoding::Inputs
+    {
+        key_inputs
+            .iter()
+            .map(|(name, ty)| {
+                let owned_ty = owned_key_type(ty).0;
+                quote_spanned! {name.span()=> cached_key::<#owned_ty>(); }
+            })
+            .collect()
+    } else {
+        quote! { cached_key::<#cache_key_ty>(); }
+    };
+    let bound_assertions = quote! {
+        const _: fn() = || {
+            fn cached_value<T: ::cold_moka::CacheValue>() {}
+            fn cached_key<T: ::cold_moka::CacheKey>() {}
+            cached_value::<#cache_value_ty>();
+            #key_assertions
+        };
+    };
+    // a group member uses the cache of the group, which has to be of the type it would declare
+    let group = match args
+        .group
+        .as_deref()
+        .map(|group| parse_argument::<Path>(group, "group", arg_span(&attr_args, "group")))
+    {
+        Some(Ok(group)) => Some(group),
+        Some(Err(e)) => return e.to_compile_error().into(),
+        None => None,
+    };
+    let cache_static = match &group {
+        Some(group) => quote_spanned! {arg_span(&attr_args, "group")=>
+            const _: fn() = || {
+                let _: &#cache_ty = ::std::ops::Deref::deref(&#group);
+            };
+        },
+        None => cache_static,
+    };
+    // a backend is only reached through the trait, whatever the type of the static
+    let backend =
+        match args.backend.as_deref().map(|backend| {
+            parse_argument::<Path>(backend, "backend", arg_span(&attr_args, "backend"))
+        }) {
+            Some(Ok(backend)) => Some(backend),
+            Some(Err(e)) => return e.to_compile_error().into(),
+            None => None,
+        };
+    let backend_ident = private_ident(&fn_ident, "backend");
+    let cache_static = match &backend {
+        Some(backend) => quote_spanned! {arg_span(&attr_args, "backend")=>
+            fn #backend_ident() -> ::cold_moka::BackendRef<'static, #cache_key_ty, #cache_value_ty> {
+                ::cold_moka::BackendRef::new(::std::ops::Deref::deref(&#backend))
+            }
+        },
+        None => cache_static,
+    };
+    let cache_type = quote! {
+        #bound_assertions
+        #pinned_static
+        #warmup_static
+        #stats_static
+        #oversize_static
+        #stale_static
+        #cache_static
+        #key_function
+    };
+    // how the cache is reached, a swappable one is loaded first. Guards of `load` must not
+    // be held across awaits, futures take a full `Arc` instead
+    let cache = match (&group, args.swap, async_fn) {
+        _ if backend.is_some() => quote! {#backend_ident()},
+        (Some(group), _, _) => quote! {#group},
+        (None, false, _) => quote! {#cache_ident},
+        (None, true, false) => quote! {#cache_ident.load()},
+        (None, true, true) => quote! {#cache_ident.load_full()},
+    };
+
+    let box_future = (!impl_future).then(|| quote! { ::std::boxed::Box::pin });
+    let function_no_cache = if args.boxed_future {
+        // the body is evaluated in place: it may borrow `self` which can't be passed to an
+        // inner function, and only builds the future, the actual work happens on a miss
+        if let (true, ReturnType::Type(_, value_ty)) = (impl_future, &value_output) {
+            quote! {
+                let fut = ::cold_moka::typed_future::<#value_ty, _>((|| #body)());
+            }
+        } else {
+            quote! {
+                let fut: #fn_output_ty = (|| #body)();
+            }
+        }
+    } else if is_method {
+        // `self` can't be passed to an inner function either, the body runs in place on a miss
+        quote! {}
+    } else {
+        // the inner function keeps the generics and the `where` clause of the cached one
+        let generics = &signature.generics;
+        let where_clause = &generics.where_clause;
+        // a blocking body runs on a thread of its own, it can't await anything
+        let asyncness = signature.asyncness.filter(|_| !args.blocking);
+        quote! {
+            #asyncness fn #no_cache_fn_ident #generics (#inputs) #output #where_clause #body
+        }
+    };
+
+    let init = if args.boxed_future {
+        quote! {fut}
+    } else if is_method && is_async {
+        quote! { ::cold_moka::typed_future::<#fn_output_ty, _>(async move #body) }
+    } else if is_method {
+        quote! { (|| -> #fn_output_ty #body)() }
+    } else if args.key_borrow {
+        // the inputs were moved into the key, the function gets copies of them
+        let arg_names = inputs.iter().filter_map(|input| match input {
+            FnArg::Typed(pat_type) => match pat_type.pat.deref() {
+                Pat::Ident(pat_ident) => Some(Some(&pat_ident.ident)),
+                _ => Some(None),
+            },
+            FnArg::Receiver(_) => None,
+        });
+        let init_args = inner_function_call_args
+            .iter()
+            .zip(arg_names)
+            .map(|(arg, name)| {
+                match key_names
+                    .iter()
+                    .position(|key_name| Some(**key_name) == name)
+                {
+                    Some(_) if key_names.len() == 1 => {
+                        quote! { ::std::clone::Clone::clone(#key_ref) }
+                    }
+                    Some(idx) => {
+                        let idx = syn::Index::from(idx);
+                        quote! { ::std::clone::Clone::clone(&#key_ref.#idx) }
+                    }
+                    None => quote! {#arg},
+                }
+            });
+        let call = quote! {#no_cache_fn_ident(#(#init_args),*)};
+        if is_async {
+            // copied when the future runs, hits don't copy anything
+            quote! { async move { #call.await } }
+        } else {
+            call
+        }
+    } else if args.blocking {
+        // a panic of the body is resumed in the caller, as if it ran in place
+        quote! {
+            async move {
+                match ::cold_moka::tokio::task::spawn_blocking(move || #no_cache_fn_ident(#(#inner_function_call_args),*)).await {
+                    Ok(value) => value,
+                    Err(e) => ::std::panic::resume_unwind(e.into_panic()),
+                }
+            }
+        }
+    } else {
+        quote! {#no_cache_fn_ident(#(#inner_function_call_args),*)}
+    };
+    // the cache and the hooks only see the value inside the `Return`
+    let init = match (return_shape, async_fn) {
+        (None, _) => init,
+        (Some(RetTurnTy::Bare), false) => quote! { #init.value },
+        (Some(RetTurnTy::Bare), true) => quote! { async move { #init.await.value } },
+        (Some(_), false) => quote! { #init.map(|value| value.value) },
+        (Some(_), true) => quote! { async move { #init.await.map(|value| value.value) } },
+    };
+    // the value is wrapped right away, everything from the hooks to the weigher sees the `Arc`
+    let init = match (args.arc, return_ty, is_async) {
+        (false, _, _) => init,
+        (true, RetTurnTy::Bare, false) => quote! { ::std::sync::Arc::new(#init) },
+        (true, RetTurnTy::Bare, true) => quote! {
+            async move { ::std::sync::Arc::new(#init.await) }
+        },
+        (true, RetTurnTy::ResultOption, false) => quote! {
+            #init.map(|value| value.map(::std::sync::Arc::new))
+        },
+        (true, RetTurnTy::ResultOption, true) => quote! {
+            async move { #init.await.map(|value| value.map(::std::sync::Arc::new)) }
+        },
+        (true, _, false) => quote! { #init.map(::std::sync::Arc::new) },
+        (true, _, true) => quote! {
+            async move { #init.await.map(::std::sync::Arc::new) }
+        },
+    };
+    let failpoint = |point: &str| {
+        let name = format!("cold_moka::{}::{}", fn_ident, point);
+        quote! { ::cold_moka::failpoint(#name) }
+    };
+    // a `return` action on `before_get` or a disabled cache computes the value without looking
+    // at the cache
+    let uncached = {
+        let await_call = is_async.then(|| quote! {.await});
+        quote! { #init #await_call }
+    };
+    let mut bypass_conditions = Vec::new();
+    if args.failpoints {
+        bypass_conditions.push(failpoint("before_get"));
+    }
+    if let Some(enabled) = &args.enabled {
+        let enabled = match parse_str::<Path>(enabled) {
+            Ok(enabled) => enabled,
+            Err(_) => {
+                return Diagnostic::InvalidArgument
+                    .compile_error(
+                        arg_span(&attr_args, "enabled"),
+                        "unable to parse enabled path",
+                    )
+                    .into()
+            }
+        };
+        bypass_conditions.push(quote! { !::cold_moka::is_enabled(&#enabled) });
+    }
+    let after_failpoint = |init: TokenStream2, point: &str| {
+        if !args.failpoints {
+            return init;
+        }
+        let failpoint = failpoint(point);
+        if async_fn {
+            quote! {
+                async move {
+                    let value = #init.await;
+                    #failpoint;
+                    value
+                }
+            }
+        } else {
+            quote! {
+                {
+                    let value = #init;
+                    #failpoint;
+                    value
+                }
+            }
+        }
+    };
+    let init = after_failpoint(init, "after_compute");
+    let init = match &args.record_size {
+        Some(record_size) => {
+            let record_size = match parse_argument::<Expr>(
+                record_size,
+                "record_size",
+                arg_span(&attr_args, "record_size"),
+            ) {
+                Ok(record_size) => record_size,
+                Err(e) => return e.to_compile_error().into(),
+            };
+            let fn_name = fn_ident.to_string();
+            on_computed(
+                init,
+                return_ty,
+                async_fn,
+                record_metrics(quote! {
+                    ::cold_moka::metrics::record_size(#fn_name, value, #record_size);
+                }),
+            )
+        }
+        None => init,
+    };
+    // misses are counted by the computation itself, it runs once for coalesced callers
+    let init = match (args.stats, async_fn) {
+        (false, _) => init,
+        (true, false) => {
+            let record_miss = record_metrics(quote! { #stats_ident.miss(); });
+            quote! {
+                {
+                    #record_miss
+                    #init
+                }
+            }
+        }
+        (true, true) => {
+            let record_miss = record_metrics(quote! { #stats_ident.miss(); });
+            quote! {
+                async move {
+                    #record_miss
+                    #init.await
+                }
+            }
+        }
+    };
+
+    let init = after_failpoint(init, "before_insert");
+
+    // observers of the lookups, run with `fresh` telling a computed value from a hit
+    let fn_name = fn_ident.to_string();
+    let cache_name = cache_ident.to_string();
+    let trace = args.trace.then(|| {
+        let hit = format!("{} hit", cache_name);
+        let miss = format!("{} miss", cache_name);
+        quote! {
+            if fresh {
+                ::cold_moka::tracing::trace!(name: #miss, target: "cold_moka", function = #fn_name, "cache miss");
+            } else {
+                ::cold_moka::tracing::trace!(name: #hit, target: "cold_moka", function = #fn_name, "cache hit");
+            }
+        }
+    });
+    // the proc macro crate gets the `metrics` feature from the facade, so every function counts
+    let count = cfg!(feature = "metrics").then(|| {
+        record_metrics(quote! {
+            ::cold_moka::metrics::record_lookup(#fn_name, fresh);
+        })
+    });
+    let hooks = (on_hit.is_some() || on_miss.is_some()).then(|| {
+        let on_hit = on_hit.map(|on_hit| quote! { #on_hit(&#key); });
+        let on_miss = on_miss.map(|on_miss| quote! { #on_miss(&#key); });
+        quote! {
+            if fresh {
+                #on_miss
+            } else {
+                #on_hit
+            }
+        }
+    });
+    let mark_cached = return_shape.map(|_| quote! { #was_cached.set(!fresh); });
+    let observers: Vec<_> = [trace, count, hooks, mark_cached]
+        .into_iter()
+        .flatten()
+        .collect();
+    let observe = (!observers.is_empty()).then(|| quote! { #(#observers)* });
+    let observe_hit = observe.as_ref().map(|observe| {
+        quote! {
+            let fresh = false;
+            #observe
+        }
+    });
+    let observe_miss = observe.as_ref().map(|observe| {
+        quote! {
+            {
+                let fresh = true;
+                #observe
+            }
+        }
+    });
+    let wrap_value = match return_ty {
+        RetTurnTy::Bare => quote! {value},
+        RetTurnTy::Result => quote! {Ok(value)},
+        RetTurnTy::Option => quote! {Some(value)},
+        RetTurnTy::ResultOption => quote! {Ok(Some(value))},
+    };
+    let rewrap = |value: TokenStream2, cached: TokenStream2| match return_shape {
+        None => value,
+        Some(RetTurnTy::Bare) => quote! {
+            ::cold_moka::Return { value: #value, was_cached: #cached }
+        },
+        Some(_) => quote! {
+            #value.map(|value| ::cold_moka::Return { value, was_cached: #cached })
+        },
+    };
+    let wrap_value = rewrap(wrap_value, quote! {true});
+    let bypass = (!bypass_conditions.is_empty()).then(|| {
+        let uncached = rewrap(uncached, quote! {false});
+        quote! {
+            if #(#bypass_conditions)||* {
+                return #uncached;
+            }
+        }
+    });
+    let function_call = match (oversize, weigher.as_ref()) {
+        (Some(oversize), Some(weigher)) => {
+            // values are weighed before being inserted, so this can't go through `get_with`
+            let max_weight = args.max_weight.unwrap_or_default();
+            let fraction = args.oversize_fraction.unwrap_or(0.5);
+            let threshold = (max_weight as f64 * fraction) as u64;
+            let await_call = async_fn.then(|| quote! {.await});
+            let on_oversize = on_oversize.map(|on_oversize| quote! { #on_oversize(weight); });
+            let (slot_lookup, keep_oversized) = match oversize {
+                Oversize::Bypass => (None, None),
+                Oversize::Dedicated => (
+                    Some(quote! {
+                        if let Some(value) = #oversize_ident.get(&#key) {
+                            #observe_hit
+                            return #wrap_value;
+                        }
+                    }),
+                    Some(quote! { #oversize_ident.store(#key, value.clone()); }),
+                ),
+            };
+            let computed = on_computed(
+                init,
+                return_ty,
+                async_fn,
+                keep_if(quote! {
+                    let weight = (#weigher)(&#key, value);
+                    if u64::from(weight) > #threshold {
+                        #on_oversize
+                        #keep_oversized
+                    } else {
+                        #cache.insert(#key, value.clone())#cache_await;
+                    }
+                }),
+            );
+            let function_call = quote! {
+                if let Some(value) = #cache.get(&#key)#cache_await {
+                    #observe_hit
+                    return #wrap_value;
+                }
+                #slot_lookup
+                #observe_miss
+                #computed #await_call
+            };
+            if args.boxed_future {
+                quote! {
+                    #box_future(async move { #function_call })
+                }
+            } else {
+                function_call
+            }
+        }
+        // a backend only gets and inserts values, concurrent misses aren't coalesced either
+        _ if backend.is_some() => {
+            let await_call = async_fn.then(|| quote! {.await});
+            let computed = on_computed(
+                init,
+                return_ty,
+                async_fn,
+                keep_if(quote! { #cache.insert(#key, value.clone()); }),
+            );
+            let function_call = quote! {
+                if let Some(value) = #cache.get(&#key) {
+                    #observe_hit
+                    return #wrap_value;
+                }
+                #observe_miss
+                #computed #await_call
+            };
+            if args.boxed_future {
+                quote! {
+                    #box_future(async move { #function_call })
+                }
+            } else {
+                function_call
+            }
+        }
+        // the sync cache can't run the future, so concurrent misses aren't coalesced
+        _ if args.sync_cache => {
+            let computed = on_computed(
+                init,
+                return_ty,
+                true,
+                keep_if(quote! { #cache.insert(#key, value.clone()); }),
+            );
+            quote! {
+                if let Some(value) = #cache.get(&#key) {
+                    #observe_hit
+                    return #wrap_value;
+                }
+                #observe_miss
+                #computed.await
+            }
+        }
+        _ if args.boxed_future => {
+            let function_call = inner_function_call(
+                init,
+                return_ty,
+                &error_conversion,
+                &cache,
+                true,
+                observe.as_ref(),
+            );
+            quote! {
+                #box_future(async move { #function_call })
+            }
+        }
+        _ => inner_function_call(
+            init,
+            return_ty,
+            &error_conversion,
+            &cache,
+            is_async,
+            observe.as_ref(),
+        ),
+    };
+
+    let inserts_itself =
+        oversize.is_some() && weigher.is_some() || args.sync_cache || backend.is_some();
+    let function_call = match &cache_if {
+        Some(cache_if) if !inserts_itself => {
+            let check = quote! {
+                if !(#cache_if)(value) {
+                    #cache.invalidate(&#key)#cache_await;
+                }
+            };
+            let check = match return_ty {
+                RetTurnTy::Bare => quote! {
+                    let value = &result;
+                    #check
+                },
+                RetTurnTy::Result => quote! {
+                    if let Ok(value) = &result {
+                        #check
+                    }
+                },
+                RetTurnTy::Option => quote! {
+                    if let Some(value) = &result {
+                        #check
+                    }
+                },
+                RetTurnTy::ResultOption => quote! {
+                    if let Ok(Some(value)) = &result {
+                        #check
+                    }
+                },
+            };
+            quote! {
+                let result = { #function_call };
+                #check
+                result
+            }
+        }
+        _ => function_call,
+    };
+    // a concurrent call may have filled the entry meanwhile, the values which left the cache
+    // reach the stale ones through the listener, with moka's maintenance
+    let function_call = if args.result_fallback {
+        quote! {
+            match { #function_call } {
+                Err(e) => {
+                    match #cache.get(&#key)#cache_await.or_else(|| #stale_ident.get(&#key)) {
+                        Some(value) => Ok(value),
+                        None => Err(e),
+                    }
+                }
+                result => result,
+            }
+        }
+    } else {
+        function_call
+    };
+    let function_call = match return_shape {
+        Some(_) => {
+            let result = rewrap(quote! {result}, quote! {#was_cached.get()});
+            quote! {
+                let #was_cached = ::std::cell::Cell::new(false);
+                let result = { #function_call };
+                #result
+            }
+        }
+        None => function_call,
+    };
+
+    // the body of boxed futures and methods is used as is, so it keeps the `mut`s
+    let mut signature = if args.boxed_future || is_method {
+        signature
+    } else {
+        get_mut_signature(signature)
+    };
+    // the body without the cache, it keeps the signature the function was written with
+    let (bypass_function, function_no_cache) = if args.bypass {
+        let allows: Vec<_> = attributes
+            .iter()
+            .filter(|attr| attr.path().is_ident("allow"))
+            .collect();
+        let mut bypass_signature = signature.clone();
+        bypass_signature.ident = bypass_ident;
+        let await_call = is_async.then(|| quote! {.await});
+        let function = quote! {
+            #(#allows)*
+            #function_no_cache
+
+            #(#allows)*
+            #[allow(dead_code)]
+            #visibility #bypass_signature {
+                #no_cache_fn_ident(#(#inner_function_call_args),*) #await_call
+            }
+        };
+        // the inner function is shared with the wrapper, so it moves next to it
+        (Some(function), None)
+    } else {
+        (None, Some(function_no_cache))
+    };
+    if args.arc {
+        signature.output = arc_output(return_ty, &signature.output);
+    }
+
+    let pin_function = pinned_ident.as_ref().map(|pinned_ident| {
+        let pin_signature = companion_signature(&signature, "pin");
+        let await_call = async_fn.then(|| quote! {.await});
+        quote! {
+            #[allow(dead_code)]
+            #visibility #pin_signature {
+                #pinned_ident.pin(#key_convert_block);
+                #fn_ident(#(#inner_function_call_args),*) #await_call
+            }
+        }
+    });
+
+    let cache_clear_ident = companion_ident(&fn_ident, "cache_clear");
+    let clear_oversized = oversize_static
+        .is_some()
+        .then(|| quote! { #oversize_ident.clear(); });
+    let clear_stale = stale_static
+        .is_some()
+        .then(|| quote! { #stale_ident.clear(); });
+    let cache_clear_function = quote! {
+        #[allow(dead_code)]
+        #visibility fn #cache_clear_ident() {
+            #cache.invalidate_all();
+            #clear_oversized
+            #clear_stale
+        }
+    };
+
+    let cache_invalidate_ident = companion_ident(&fn_ident, "cache_invalidate");
+    let invalidate_oversized = oversize_static
+        .is_some()
+        .then(|| quote! { #oversize_ident.remove(&#key); });
+    let forget_stale = stale_static
+        .is_some()
+        .then(|| quote! { #stale_ident.forget(&#key); });
+    let cache_invalidate_function = if async_fn {
+        quote! {
+            #[allow(dead_code, unused_variables)]
+            #visibility async fn #cache_invalidate_ident(#(#key_params),*) {
+                let #key = #key_convert_block;
+                #invalidate_oversized
+                #forget_stale
+                #cache.invalidate(&#key)#cache_await;
+            }
+        }
+    } else {
+        quote! {
+            #[allow(dead_code, unused_variables)]
+            #visibility fn #cache_invalidate_ident(#(#key_params),*) {
+                let #key = #key_convert_block;
+                #invalidate_oversized
+                #forget_stale
+                #cache.invalidate(&#key);
+            }
+        }
+    };
+
+    // `invalidate_entries_if` isn't async for any cache
+    let cache_invalidate_if_ident = companion_ident(&fn_ident, "cache_invalidate_if");
+    let cache_invalidate_if_function = args.invalidation.then(|| {
+        let invalidate_oversized = oversize_static
+            .is_some()
+            .then(|| quote! { #oversize_ident.remove_if(&predicate); });
+        quote! {
+            #[allow(dead_code)]
+            #visibility fn #cache_invalidate_if_ident(
+                predicate: impl Fn(&#cache_key_ty, &#cache_value_ty) -> bool + Send + Sync + 'static,
+            ) -> ::std::result::Result<(), ::cold_moka::moka::PredicateError> {
+                #invalidate_oversized
+                #cache.invalidate_entries_if(predicate).map(|_| ())
+            }
+        }
+    });
+
+    let cache_insert_ident = companion_ident(&fn_ident, "cache_insert");
+    let value = local_ident("value");
+    let cache_insert_function = if async_fn {
+        quote! {
+            #[allow(dead_code, unused_variables)]
+            #visibility async fn #cache_insert_ident(#(#key_params,)* #value: #cache_value_ty) {
+                let #key = #key_convert_block;
+                #cache.insert(#key, #value)#cache_await;
+            }
+        }
+    } else {
+        quote! {
+            #[allow(dead_code, unused_variables)]
+            #visibility fn #cache_insert_ident(#(#key_params,)* #value: #cache_value_ty) {
+                let #key = #key_convert_block;
+                #cache.insert(#key, #value);
+            }
+        }
+    };
+
+    let if_cached_ident = companion_ident(&fn_ident, "if_cached");
+    let if_cached_function = if async_fn {
+        quote! {
+            #[allow(dead_code, unused_variables)]
+            #visibility async fn #if_cached_ident(#(#key_params),*) -> ::std::option::Option<#cache_value_ty> {
+                let #key = #key_convert_block;
+                #cache.get(&#key)#cache_await
+            }
+        }
+    } else {
+        quote! {
+            #[allow(dead_code, unused_variables)]
+            #visibility fn #if_cached_ident(#(#key_params),*) -> ::std::option::Option<#cache_value_ty> {
+                let #key = #key_convert_block;
+                #cache.get(&#key)
+            }
+        }
+    };
+
+    // `contains_key` doesn't touch the recency of the entry and isn't async for any cache, the
+    // companion is async only to await the key
+    let cache_contains_ident = companion_ident(&fn_ident, "cache_contains");
+    let cache_contains_function = quote! {
+        #[allow(dead_code, unused_variables)]
+        #visibility #key_asyncness fn #cache_contains_ident(#(#key_params),*) -> bool {
+            let #key = #key_convert_block;
+            #cache.contains_key(&#key)
+        }
+    };
+
+    let cache_swap_ident = companion_ident(&fn_ident, "cache_swap");
+    let cache_swap_function = match (args.swap, async_fn) {
+        (false, _) => None,
+        (true, false) => Some(quote! {
+            #[allow(dead_code)]
+            #visibility fn #cache_swap_ident(entries: impl ::std::iter::IntoIterator<Item = (#cache_key_ty, #cache_value_ty)>) {
+                let cache: #cache_ty = #fresh_cache;
+                for (key, value) in entries {
+                    cache.insert(key, value);
+                }
+                cache.run_pending_tasks();
+                #cache_ident.store(::std::sync::Arc::new(cache));
+            }
+        }),
+        (true, true) => Some(quote! {
+            #[allow(dead_code)]
+            #visibility async fn #cache_swap_ident(entries: impl ::std::iter::IntoIterator<Item = (#cache_key_ty, #cache_value_ty)>) {
+                let cache: #cache_ty = #fresh_cache;
+                for (key, value) in entries {
+                    cache.insert(key, value)#cache_await;
+                }
+                cache.run_pending_tasks()#cache_await;
+                #cache_ident.store(::std::sync::Arc::new(cache));
+            }
+        }),
+    };
+
+    // pending tasks of a sync cache are run first so the numbers are up to date, running them
+    // for a future cache would need an `.await`
+    let run_pending_tasks = (!async_cache).then(|| {
+        quote! { #cache.run_pending_tasks(); }
+    });
+    let cache_entry_count_ident = companion_ident(&fn_ident, "cache_entry_count");
+    let cache_weighted_size_ident = companion_ident(&fn_ident, "cache_weighted_size");
+    let cache_size_functions = quote! {
+        #[allow(dead_code)]
+        #visibility fn #cache_entry_count_ident() -> u64 {
+            #run_pending_tasks
+            #cache.entry_count()
+        }
+        #[allow(dead_code)]
+        #visibility fn #cache_weighted_size_ident() -> u64 {
+            #run_pending_tasks
+            #cache.weighted_size()
+        }
+    };
+
+    // a testing hook, evictions and expirations happen once moka ran its maintenance
+    let cache_sync_ident = companion_ident(&fn_ident, "cache_sync");
+    let cache_sync_function = if async_fn {
+        quote! {
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            #visibility async fn #cache_sync_ident() {
+                #cache.run_pending_tasks()#cache_await;
+            }
+        }
+    } else {
+        quote! {
+            #[doc(hidden)]
+            #[allow(dead_code)]
+            #visibility fn #cache_sync_ident() {
+                #cache.run_pending_tasks();
+            }
+        }
+    };
+
+    // warming goes through the function itself, so misses are coalesced and errors aren't cached
+    let warm_ident = companion_ident(&fn_ident, "warm");
+    let (mut warm_tys, warm_pats): (Vec<_>, Vec<_>) = inputs
+        .iter()
+        .filter_map(|input| match input {
+            FnArg::Receiver(_) => None,
+            FnArg::Typed(pat_type) => Some((pat_type.ty.clone(), strip_mut_from_pat(pat_type))),
+        })
+        .unzip();
+    // they take every input, so they need the generics of the function as well, and a lifetime
+    // for the references elided in the inputs
+    let mut generics = signature.generics.clone();
+    let input_lifetime: Lifetime = parse_quote! { '__cold_moka_input };
+    let mut named = false;
+    for ty in &mut warm_tys {
+        named |= name_elided_lifetimes(ty, &input_lifetime);
+    }
+    if named {
+        generics.params.insert(0, parse_quote! { #input_lifetime });
+    }
+    let generics = &generics;
+    let where_clause = &generics.where_clause;
+    let (warm_item_ty, warm_item_pat) = if warm_tys.len() == 1 {
+        (quote! {#(#warm_tys)*}, quote! {#(#warm_pats)*})
+    } else {
+        (quote! {(#(#warm_tys),*)}, quote! {(#(#warm_pats),*)})
+    };
+    let warm_function = if async_fn {
+        quote! {
+            #[allow(dead_code)]
+            #visibility async fn #warm_ident #generics (inputs: impl ::std::iter::IntoIterator<Item = #warm_item_ty>, concurrency: usize) -> ::cold_moka::Warmed #where_clause {
+                use ::cold_moka::futures_util::stream::StreamExt as _;
+                let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+                    .map(|#warm_item_pat| async move {
+                        let #key = #key_convert_block;
+                        if #cache.contains_key(&#key) {
+                            return true;
+                        }
+                        let _ = #fn_ident(#(#inner_function_call_args),*).await;
+                        false
+                    })
+                    .buffer_unordered(concurrency.max(1));
+                let mut warmed = ::cold_moka::Warmed::default();
+                while let Some(present) = loads.next().await {
+                    warmed.record(present);
+                }
+                warmed
+            }
+        }
+    } else {
+        quote! {
+            #[allow(dead_code)]
+            #visibility fn #warm_ident #generics (inputs: impl ::std::iter::IntoIterator<Item = #warm_item_ty>) -> ::cold_moka::Warmed #where_clause {
+                let mut warmed = ::cold_moka::Warmed::default();
+                for #warm_item_pat in inputs {
+                    let #key = #key_convert_block;
+                    let present = #cache.contains_key(&#key);
+                    warmed.record(present);
+                    if !present {
+                        let _ = #fn_ident(#(#inner_function_call_args),*);
+                    }
+                }
+                warmed
+            }
+        }
+    };
 
-    #[darling(default)]
-    convert: Option<String>,
+    // the batch goes through the function itself, so it gets the hits, coalescing and metrics
+    let many_ident = companion_ident(&fn_ident, "many");
+    let many_output = match (&value_output, &signature.output) {
+        (ReturnType::Type(_, ty), _) if args.boxed_future => quote! {#ty},
+        (_, ReturnType::Type(_, ty)) => quote! {#ty},
+        (_, ReturnType::Default) => quote! {()},
+    };
+    let many_function = match (args.many, async_fn) {
+        (false, _) => None,
+        (true, true) => Some(quote! {
+            #[allow(dead_code)]
+            #visibility async fn #many_ident #generics (inputs: impl ::std::iter::IntoIterator<Item = #warm_item_ty>, concurrency: usize) -> ::std::vec::Vec<#many_output> #where_clause {
+                use ::cold_moka::futures_util::stream::StreamExt as _;
+                ::cold_moka::futures_util::stream::iter(inputs)
+                    .map(|#warm_item_pat| #fn_ident(#(#inner_function_call_args),*))
+                    .buffered(concurrency.max(1))
+                    .collect()
+                    .await
+            }
+        }),
+        (true, false) => Some(quote! {
+            #[allow(dead_code)]
+            #visibility fn #many_ident #generics (inputs: impl ::std::iter::IntoIterator<Item = #warm_item_ty>) -> ::std::vec::Vec<#many_output> #where_clause {
+                inputs
+                    .into_iter()
+                    .map(|#warm_item_pat| #fn_ident(#(#inner_function_call_args),*))
+                    .collect()
+            }
+        }),
+    };
 
-    #[darling(default, rename = "type")]
-    cache_type: Option<String>,
-    #[darling(default, rename = "create")]
-    cache_create: Option<String>,
+    // `iter` isn't async for any cache
+    let cache_dump_ident = companion_ident(&fn_ident, "cache_dump");
+    let cache_dump_function = args.dump.then(|| {
+        quote! {
+            #[allow(dead_code)]
+            #visibility fn #cache_dump_ident(limit: ::std::option::Option<usize>) -> ::std::vec::Vec<(#cache_key_ty, #cache_value_ty)> {
+                #cache
+                    .iter()
+                    .take(limit.unwrap_or(usize::MAX))
+                    .map(|(key, value)| ((*key).clone(), value))
+                    .collect()
+            }
+        }
+    });
+
+    let cache_stats_ident = companion_ident(&fn_ident, "cache_stats");
+    let cache_stats_function = args.stats.then(|| {
+        quote! {
+            #[allow(dead_code)]
+            #visibility fn #cache_stats_ident() -> ::cold_moka::CacheStats {
+                #stats_ident.stats(#cache.entry_count())
+            }
+        }
+    });
+
+    let (module_items, function_items, companions) = if is_method {
+        (None, Some(cache_type), None)
+    } else if backend.is_some() {
+        // the companions reaching past the entry of a key need a moka cache
+        (
+            Some(cache_type),
+            None,
+            Some(quote! {
+                #cache_invalidate_function
+                #cache_insert_function
+                #if_cached_function
+                #bypass_function
+            }),
+        )
+    } else {
+        (
+            Some(cache_type),
+            None,
+            Some(quote! {
+                #pin_function
+                #cache_clear_function
+                #cache_invalidate_function
+                #cache_invalidate_if_function
+                #cache_insert_function
+                #if_cached_function
+                #cache_contains_function
+                #cache_size_functions
+                #cache_sync_function
+                #warm_function
+                #many_function
+                #cache_swap_function
+                #cache_stats_function
+                #cache_dump_function
+                #bypass_function
+            }),
+        )
+    };
+
+    let expanded = quote!(
+        // cache creation
+        #module_items
+
+        #(#attributes)*
+        #visibility
+        // original function signature
+        #signature
+        {
+            #function_items
+            // inner function
+            #function_no_cache
+            #key_statement
+            #bypass
+            // calls bypassing the cache are neither hits nor misses
+            #record_call
+            // call to inner function
+            #function_call
+        }
+
+        #companions
+    );
+
+    expanded.into()
 }
 
-/// ```ignore
-/// use cold_moka::moka::sync::Cache;
-/// use cold_moka::cached;
-/// use cold_moka::once_cell::sync::Lazy;
+/// Invalidates the entry of a `#[cached]` function after the annotated function ran, e.g. the
+/// cached `get_user(id)` after `update_user(id, ...)`.
 ///
+/// `target` is the path of the cached function, `key` lists the arguments of the annotated
+/// function building its key, in the order of the target's key inputs. All the arguments are
+/// used when it's left out. The entry is invalidated whatever the function returns.
 ///
-/// #[cached(ttl = 100, size = 100)]
-/// fn foo(bar: i32) -> i32 {
-///     bar + 1
-/// }
+/// The target is reached through `<target>_cache_invalidate`, so it has to be visible from the
+/// annotated function, and an `async fn` can only invalidate the cache of an `async fn`. A
+/// function which isn't `async` targeting one which is fails to compile.
 ///
-/// fn foo(bar: i32) -> i32 {
-///     static FOO: Lazy<Cache<i32, i32>> = Lazy::new(|| {
-///         Cache::builder()
-///             .max_capacity(100)
-///             .time_to_live(std::time::Duration::from_secs(100))
-///             .build()
-///     });
-///     
-///     fn foo_inner(bar: i32) -> i32 {
-///         bar + 1
-///     }
+/// ```rust
+/// use cold_moka::{cache_invalidate, cached};
 ///
-///     FOO.get_with_by_ref(&bar, || bar + 1)
+/// #[cached]
+/// fn get_user(id: u64) -> String {
+///     format!("user {}", id)
 /// }
+///
+/// #[cache_invalidate(target = "get_user", key = "id")]
+/// fn update_user(id: u64, name: String) {
+///     let _ = name;
+/// }
+///
+/// get_user(1);
+/// update_user(1, "new".to_owned());
+/// assert!(!get_user_cache_contains(1));
 /// ```
+#[proc_macro_attribute]
+pub fn cache_invalidate(args: TokenStream, input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as ItemFn);
+    mutation::expand(Mutation::Invalidate, args.into(), input).into()
+}
+
+/// Writes the value returned by the annotated function into the cache of a `#[cached]`
+/// function, e.g. the user returned by `save_user(id, user)` into the cache of `get_user(id)`.
 ///
-/// async functions will use `moka::future::Cache` instead of `moka::sync::Cache`
+/// It takes the same arguments as [`macro@cache_invalidate`]. Functions returning `Result` or
+/// `Option` only write their `Ok` and `Some` values. The value type has to be the one cached by
+/// the target, and an `async fn` can only update the cache of an `async fn`.
 ///
-/// ```ignore
-/// use cold_moka::moka::future::Cache;
-/// use cold_moka::cached;
-/// use cold_moka::once_cell::sync::Lazy;
+/// ```rust
+/// use cold_moka::{cache_update, cached};
 ///
-/// #[cached(ttl = 100, size = 100)]
-/// async fn bar(arg1: i32) ->String{
-///   arg1.to_string()
+/// #[cached]
+/// fn get_user(id: u64) -> String {
+///     format!("user {}", id)
 /// }
 ///
-/// // becomes
-/// async fn bar(arg1: i32) ->String{
-///  static BAR: Lazy<Cache<i32, String>> = Lazy::new(|| {
-///        Cache::builder()
-///           .max_capacity(100)
-///          .time_to_live(std::time::Duration::from_secs(100))
-///         .build()
-///   });
-///  BAR.get_with_by_ref(&arg1, || arg1.to_string()).await
+/// #[cache_update(target = "get_user", key = "id")]
+/// fn save_user(id: u64, name: String) -> Result<String, String> {
+///     Ok(format!("user {} {}", id, name))
 /// }
+///
+/// save_user(1, "new".to_owned()).unwrap();
+/// assert_eq!(get_user(1), "user 1 new");
 /// ```
 ///
-/// for functions with multiple arguments, you can specify which arguments to use for the cache key
+/// A value type differing from the one of the target doesn't compile.
 ///
-/// ```rust
-/// use cold_moka::cached;
+/// ```rust,compile_fail
+/// use cold_moka::{cache_update, cached};
 ///
-/// struct Context;
+/// #[cached]
+/// fn get_user(id: u64) -> String {
+///     format!("user {}", id)
+/// }
 ///
-/// #[cached(key = "arg1, arg2")]
-/// async fn frobnicate(ctx: Context,arg1:i32, arg2: i32) -> Result<i32, String> {
-///     Ok(arg1 + arg2)
+/// #[cache_update(target = "get_user", key = "id")]
+/// fn save_user(id: u64) -> Option<u64> {
+///     Some(id)
 /// }
 /// ```
-/// functions returning `Result` or `Option` will use `try_get_with_by_ref` and `optional_get_with_by_ref` respectively  
+#[proc_macro_attribute]
+pub fn cache_update(args: TokenStream, input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as ItemFn);
+    mutation::expand(Mutation::Update, args.into(), input).into()
+}
+
+/// Memoizes a function without arguments into a `OnceCell`, which is cheaper than a cache
+/// holding a single entry. The function runs once and every later call returns a copy of its
+/// value.
+///
+/// Functions returning `Result` or `Option` only keep their `Ok` and `Some` values, the next
+/// call after an error or a `None` runs the function again. The function can't be `async`,
+/// generic or a method, and it takes no arguments other than `()`.
 ///
 /// ```rust
-/// use cold_moka::cached;
-/// struct Context;
+/// use cold_moka::once;
 ///
-/// struct Wrapper<T>(T);
+/// #[once]
+/// fn config_path() -> String {
+///     std::env::var("APP_CONFIG").unwrap_or_else(|_| "app.toml".to_owned())
+/// }
 ///
-/// #[cached(key = "arg1,str")]
-/// async fn frobnicate(
-///     _ctx: Context,
-///     Wrapper(arg1): Wrapper<i32> ,
-///     str: String
-/// ) -> Result<String, String> {
-///     Ok(format!("{}{}", arg1, str))
+/// #[once]
+/// fn port() -> Result<u16, std::num::ParseIntError> {
+///     "8080".parse()
 /// }
+///
+/// assert_eq!(config_path(), config_path());
+/// assert_eq!(port(), Ok(8080));
 /// ```
 #[proc_macro_attribute]
-pub fn cached(args: TokenStream, input: TokenStream) -> TokenStream {
-    let attr_args = match NestedMeta::parse_meta_list(args.into()) {
-        Ok(v) => v,
-        Err(e) => {
-            return TokenStream::from(darling::Error::from(e).write_errors());
-        }
-    };
-    let args = match MacroArgs::from_list(&attr_args) {
-        Ok(v) => v,
-        Err(e) => {
-            return TokenStream::from(e.write_errors());
-        }
-    };
+pub fn once(args: TokenStream, input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as ItemFn);
+    once::expand(args.into(), input).into()
+}
 
-    // pull out the parts of the input
-    let attributes = input.attrs;
-    let visibility = input.vis;
-    let signature = input.sig;
-    let body = input.block;
+// default arguments of every `#[cached]`, e.g. "ttl = 300, stats = true"
+const DEFAULTS_VAR: &str = "COLD_MOKA_DEFAULTS";
 
-    // pull out the parts of the function signature
-    let fn_ident = signature.ident.clone();
-    let inputs = signature.inputs.clone();
-    let output = signature.output.clone();
-    let is_async = signature.asyncness.is_some();
+// arguments generating companions or statics methods can't have
+const METHOD_UNSUPPORTED: [&str; 7] = [
+    "pin",
+    "swap",
+    "stats",
+    "dump",
+    "invalidation",
+    "many",
+    "bypass",
+];
 
-    let filter_args_by: Option<HashSet<String>> = args.key.as_ref().map(|x| {
-        x.split(',')
-            .map(|x| x.trim().to_owned())
-            .collect::<HashSet<String>>()
-    });
+// arguments configuring the cache, a group member uses the cache declared by the user
+const GROUP_UNSUPPORTED: [&str; 13] = [
+    "size",
+    "ttl",
+    "max_weight",
+    "weigher",
+    "oversize",
+    "pin",
+    "negative_ttl",
+    "value_ttl",
+    "result_fallback",
+    "warmup",
+    "swap",
+    "invalidation",
+    "create",
+];
 
-    let input_names_with_depth: Vec<_> = get_input_names(&inputs).collect();
-    let ty_depths_info: Vec<u8> = input_names_with_depth.iter().map(|x| x.1).collect();
-    let input_tys = get_input_types(&inputs, &ty_depths_info);
-    let input_names: Vec<_> = input_names_with_depth
-        .into_iter()
-        .map(|x| x.0.clone())
-        .collect();
+// arguments relying on a moka cache, a backend only gets, inserts and invalidates values
+const BACKEND_UNSUPPORTED: [&str; 19] = [
+    "group",
+    "size",
+    "ttl",
+    "max_weight",
+    "weigher",
+    "oversize",
+    "pin",
+    "negative_ttl",
+    "value_ttl",
+    "result_fallback",
+    "warmup",
+    "swap",
+    "invalidation",
+    "create",
+    "sync_cache",
+    "key_borrow",
+    "stats",
+    "dump",
+    "many",
+];
+
+// arguments replacing others, set explicitly they skip the defaults they can't be used with
+const DEFAULT_CONFLICTS: [(&str, &[&str]); 2] = [
+    ("value_ttl", &["ttl", "negative_ttl"]),
+    ("max_weight", &["size"]),
+];
 
-    let cache_key_type_indexes: HashSet<_> = input_names
+// appends the defaults which aren't set explicitly, methods, group members and functions with
+// a backend skip the ones they don't support
+fn with_defaults(
+    mut args: Vec<NestedMeta>,
+    defaults: &str,
+    is_method: bool,
+) -> syn::Result<Vec<NestedMeta>> {
+    let defaults = NestedMeta::parse_meta_list(defaults.parse()?)?;
+    let explicit: HashSet<_> = args.iter().filter_map(meta_name).collect();
+    let grouped = explicit.contains("group");
+    let backed = explicit.contains("backend");
+    let replaced: HashSet<_> = DEFAULT_CONFLICTS
         .iter()
-        .enumerate()
-        .filter_map(|(idx, ident)| {
-            if let Some(filter) = &filter_args_by {
-                filter.contains(ident.to_string().trim()).then_some(idx)
-            } else {
-                Some(idx)
-            }
-        })
+        .filter(|(name, _)| explicit.contains(*name))
+        .flat_map(|(_, conflicts)| conflicts.iter().copied())
         .collect();
+    args.extend(defaults.into_iter().filter(|default| {
+        meta_name(default).is_none_or(|name| {
+            !(explicit.contains(&name)
+                || replaced.contains(name.as_str())
+                || is_method && METHOD_UNSUPPORTED.contains(&&*name)
+                || grouped && GROUP_UNSUPPORTED.contains(&&*name)
+                || backed && BACKEND_UNSUPPORTED.contains(&&*name))
+        })
+    }));
+    Ok(args)
+}
 
-    let inner_function_call_args = get_wrapped_type_for_function_call(&inputs);
-
-    // pull out the output type
-    let output_ty = match &output {
-        ReturnType::Default => quote! {()},
-        ReturnType::Type(_, ty) => quote! {#ty},
-    };
+// the span of the value of `name = "..."`, or of the name of a flag set without a value, errors
+// about the argument point at it
+fn arg_span(args: &[NestedMeta], name: &str) -> Span {
+    args.iter()
+        .find_map(|arg| match arg {
+            NestedMeta::Meta(Meta::NameValue(meta)) if meta.path.is_ident(name) => {
+                Some(meta.value.span())
+            }
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident(name) => Some(path.span()),
+            _ => None,
+        })
+        .unwrap_or_else(Span::call_site)
+}
 
-    let return_ty = return_fallible_type(&output);
-    let cache_value_ty = find_value_type(return_ty, &output, output_ty);
-    let cache_ident = Ident::new(&fn_ident.to_string().to_uppercase(), fn_ident.span());
+// whether a bool argument is set, for the decisions taken before the arguments are parsed
+fn is_flag_set(meta: &NestedMeta, name: &str) -> bool {
+    match meta {
+        NestedMeta::Meta(Meta::Path(path)) => path.is_ident(name),
+        NestedMeta::Meta(Meta::NameValue(meta)) => {
+            meta.path.is_ident(name)
+                && matches!(&meta.value, Expr::Lit(ExprLit { lit: Lit::Bool(value), .. }) if value.value)
+        }
+        _ => false,
+    }
+}
 
-    let (cache_key_ty, key_convert_block) = make_cache_key_type(
-        &cache_key_type_indexes,
-        &args.convert,
-        &args.cache_type,
-        input_tys,
-        &input_names,
-    );
+fn meta_name(meta: &NestedMeta) -> Option<String> {
+    match meta {
+        NestedMeta::Meta(meta) => meta.path().get_ident().map(ToString::to_string),
+        NestedMeta::Lit(_) => None,
+    }
+}
 
-    let size = if inner_function_call_args.is_empty() {
-        args.size.unwrap_or(1) // () is the only possible input
-    } else {
-        args.size.unwrap_or(1000)
-    };
+// how the `Arc<E>` moka shares a failed computation in is turned back into the error
+enum ErrorConversion {
+    Clone,
+    Shared,
+    FromArc(Expr),
+}
 
-    // make the cache type and create statement
-    let (cache_ty, mut cache_create) =
-        cache_creation_statement(&args, is_async, cache_value_ty, cache_key_ty, size as u64);
-    if let Some(create) = args.cache_create {
-        cache_create = quote! {#create};
-    }
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Oversize {
+    // computed on every call, never cached
+    Bypass,
+    // kept in a slot of its own next to the cache
+    Dedicated,
+}
 
-    let no_cache_fn_ident = Ident::new(&format!("{}_inner", fn_ident), fn_ident.span());
-    let cache_type = quote! {
-        static #cache_ident: ::cold_moka::once_cell::sync::Lazy<#cache_ty> = ::cold_moka::once_cell::sync::Lazy::new(|| #cache_create);
+// runs `hook` with the freshly computed `value` before it's handed to the cache,
+// errors and `None`s are skipped as they aren't cached
+fn on_computed(
+    init: TokenStream2,
+    return_ty: RetTurnTy,
+    is_async: bool,
+    hook: TokenStream2,
+) -> TokenStream2 {
+    let hook = match return_ty {
+        RetTurnTy::Bare => quote! {
+            let value = &result;
+            #hook
+        },
+        RetTurnTy::Result => quote! {
+            if let Ok(value) = &result {
+                #hook
+            }
+        },
+        RetTurnTy::Option => quote! {
+            if let Some(value) = &result {
+                #hook
+            }
+        },
+        RetTurnTy::ResultOption => quote! {
+            if let Ok(Some(value)) = &result {
+                #hook
+            }
+        },
     };
 
-    let function_no_cache = if is_async {
+    if is_async {
         quote! {
-            async fn #no_cache_fn_ident(#inputs) #output #body
+            async move {
+                let result = #init.await;
+                #hook
+                result
+            }
         }
     } else {
         quote! {
-            fn #no_cache_fn_ident(#inputs) #output #body
-        }
-    };
-
-    let function_call = inner_function_call(
-        inner_function_call_args,
-        return_ty,
-        &cache_ident,
-        no_cache_fn_ident,
-        is_async,
-    );
-
-    let signature = get_mut_signature(signature);
-    let expanded = quote!(
-        #(#attributes)*
-        #visibility
-        // original function signature
-        #signature
-        {
-            // inner function
-            #function_no_cache
-            // cache creation
-            #cache_type
-            let key = #key_convert_block;
-            // call to inner function
-            #function_call
+            {
+                let result = #init;
+                #hook
+                result
+            }
         }
-    );
-
-    expanded.into()
+    }
 }
 
+// `init` is the expression computing the value on a miss,
+// a call to the inner function or the future to await.
+// `observe` runs after each lookup with `fresh` telling if the value was computed by it, it
+// needs moka's entry API which is skipped when nothing observes the lookups
 fn inner_function_call(
-    input_names: Vec<TokenStream2>,
+    init: TokenStream2,
     return_ty: RetTurnTy,
-    cache_ident: &Ident,
-    no_cache_fn_ident: Ident,
+    error_conversion: &ErrorConversion,
+    cache: &TokenStream2,
     is_async: bool,
+    observe: Option<&TokenStream2>,
 ) -> TokenStream2 {
+    let key = local_ident("key");
+    let error = match error_conversion {
+        ErrorConversion::Clone => quote! {
+            {
+                #[allow(unused_imports)]
+                use ::cold_moka::{CloneArcError as _, FromArcError as _};
+                (&&::cold_moka::ArcError::new(e)).into_error()
+            }
+        },
+        // errors which can't be cloned out of the `Arc` are wrapped again, sharing it
+        ErrorConversion::Shared => {
+            quote! { ::std::convert::From::from(::cold_moka::SharedError::from(e)) }
+        }
+        ErrorConversion::FromArc(from_arc) => quote! { (#from_arc)(e) },
+    };
+    // `Ok(None)` fails the computation like an error does, so it isn't cached and coalesced
+    // callers get it as well
+    let (init, shared_none) = if return_ty == RetTurnTy::ResultOption {
+        let result = if is_async {
+            quote! {#init.await}
+        } else {
+            init
+        };
+        let init = quote! {
+            match #result {
+                Ok(Some(value)) => Ok(value),
+                Ok(None) => Err(None),
+                Err(e) => Err(Some(::std::sync::Arc::new(e))),
+            }
+        };
+        let init = if is_async {
+            quote! { async move { #init } }
+        } else {
+            init
+        };
+        let shared_none = quote! {
+            match &*shared {
+                None => Ok(None),
+                Some(e) => {
+                    let e = ::std::sync::Arc::clone(e);
+                    Err(#error)
+                }
+            }
+        };
+        (init, shared_none)
+    } else {
+        (init, quote! {})
+    };
+    if let Some(observe) = observe {
+        let init = if is_async {
+            init
+        } else {
+            quote! { || #init }
+        };
+        let await_call = is_async.then(|| quote! {.await});
+        return match return_ty {
+            RetTurnTy::Bare => quote! {
+                let entry = #cache.entry_by_ref(&#key).or_insert_with(#init)#await_call;
+                let fresh = entry.is_fresh();
+                #observe
+                entry.into_value()
+            },
+            RetTurnTy::Result => quote! {
+                match #cache.entry_by_ref(&#key).or_try_insert_with(#init)#await_call {
+                    Ok(entry) => {
+                        let fresh = entry.is_fresh();
+                        #observe
+                        Ok(entry.into_value())
+                    }
+                    Err(e) => {
+                        let fresh = true;
+                        #observe
+                        Err(#error)
+                    }
+                }
+            },
+            RetTurnTy::ResultOption => quote! {
+                match #cache.entry_by_ref(&#key).or_try_insert_with(#init)#await_call {
+                    Ok(entry) => {
+                        let fresh = entry.is_fresh();
+                        #observe
+                        Ok(Some(entry.into_value()))
+                    }
+                    Err(shared) => {
+                        let fresh = true;
+                        #observe
+                        #shared_none
+                    }
+                }
+            },
+            RetTurnTy::Option => quote! {
+                match #cache.entry_by_ref(&#key).or_optionally_insert_with(#init)#await_call {
+                    Some(entry) => {
+                        let fresh = entry.is_fresh();
+                        #observe
+                        Some(entry.into_value())
+                    }
+                    None => {
+                        let fresh = true;
+                        #observe
+                        None
+                    }
+                }
+            },
+        };
+    }
     match (return_ty, is_async) {
         (RetTurnTy::Bare, false) => {
             quote! {
-                #cache_ident.get_with_by_ref(&key, || #no_cache_fn_ident(#(#input_names),*))
+                #cache.get_with_by_ref(&#key, || #init)
             }
         }
         (RetTurnTy::Bare, true) => {
             quote! {
-                #cache_ident.get_with_by_ref(&key,  #no_cache_fn_ident(#(#input_names),*)).await
+                #cache.get_with_by_ref(&#key, #init).await
             }
         }
         (RetTurnTy::Result, false) => {
             quote! {
-                let result = #cache_ident.try_get_with_by_ref(&key, || #no_cache_fn_ident(#(#input_names),*));
+                let result = #cache.try_get_with_by_ref(&#key, || #init);
                 match result {
                     Ok(v) => Ok(v),
-                    Err(e) => return Err(e.into()),
+                    Err(e) => Err(#error),
                 }
             }
         }
         (RetTurnTy::Result, true) => {
             quote! {
-                let result = #cache_ident.try_get_with_by_ref(&key, #no_cache_fn_ident(#(#input_names),*)).await;
+                let result = #cache.try_get_with_by_ref(&#key, #init).await;
                 match result {
                     Ok(v) => Ok(v),
-                    Err(e) => Err(e.into()),
+                    Err(e) => Err(#error),
+                }
+            }
+        }
+        (RetTurnTy::ResultOption, false) => {
+            quote! {
+                match #cache.try_get_with_by_ref(&#key, || #init) {
+                    Ok(v) => Ok(Some(v)),
+                    Err(shared) => #shared_none,
+                }
+            }
+        }
+        (RetTurnTy::ResultOption, true) => {
+            quote! {
+                match #cache.try_get_with_by_ref(&#key, #init).await {
+                    Ok(v) => Ok(Some(v)),
+                    Err(shared) => #shared_none,
                 }
             }
         }
         (RetTurnTy::Option, false) => {
             quote! {
-                #cache_ident.optionally_get_with_by_ref(&key, #no_cache_fn_ident(#(#input_names),*))
+                #cache.optionally_get_with_by_ref(&#key, || #init)
             }
         }
         (RetTurnTy::Option, true) => {
             quote! {
-                #cache_ident.optionally_get_with_by_ref(&key, #no_cache_fn_ident(#(#input_names),*)).await
+                #cache.optionally_get_with_by_ref(&#key, #init).await
             }
         }
     }
@@ -330,53 +3676,181 @@ fn cache_creation_statement(
     cache_value_ty: TokenStream2,
     cache_key_ty: TokenStream2,
     size: u64,
+    weigher: Option<&Expr>,
+    pinned_ident: Option<&Ident>,
 ) -> (TokenStream2, TokenStream2) {
-    let (cache_ty, cache_create) = match (args.ttl, is_async) {
-        (Some(ttl), true) => {
-            let cache_ty = quote! {
-                ::cold_moka::moka::future::Cache<#cache_key_ty, #cache_value_ty>
-            };
+    let cache_path = if is_async {
+        quote! { ::cold_moka::moka::future::Cache }
+    } else {
+        quote! { ::cold_moka::moka::sync::Cache }
+    };
+    let cache_ty = quote! {
+        #cache_path<#cache_key_ty, #cache_value_ty>
+    };
 
-            let create = quote! {
-                ::cold_moka::moka::future::Cache::builder().max_capacity(#size).time_to_live(::std::time::Duration::from_secs(#ttl)).build()
+    let mut create = match (args.max_weight, weigher) {
+        (Some(max_weight), Some(weigher)) => quote! {
+            #cache_path::builder().max_capacity(#max_weight).weigher(#weigher)
+        },
+        _ => quote! {
+            #cache_path::builder().max_capacity(#size)
+        },
+    };
+    match (args.ttl, pinned_ident) {
+        _ if args.value_ttl => {
+            create = quote! {
+                #create.expire_after(::cold_moka::ValueTtl)
             };
-            (cache_ty, create)
         }
-        (None, true) => {
-            let cache_ty = quote! {
-                ::cold_moka::moka::future::Cache<#cache_key_ty, #cache_value_ty>
+        _ if args.negative_ttl.is_some() => {
+            // errors get a ttl of their own, the rest keeps the common one
+            let negative_ttl = args.negative_ttl.unwrap_or_default();
+            let ttl = match args.ttl {
+                Some(ttl) => {
+                    quote! { ::std::option::Option::Some(::std::time::Duration::from_secs(#ttl)) }
+                }
+                None => quote! { ::std::option::Option::None },
             };
-            let create = quote! {
-                ::cold_moka::moka::future::Cache::builder().max_capacity(#size).build()
+            create = quote! {
+                #create.expire_after(::cold_moka::ErrorExpiry::new(#ttl, ::std::time::Duration::from_secs(#negative_ttl), |value: &#cache_value_ty| value.is_err()))
             };
-            (cache_ty, create)
         }
-        (Some(ttl), false) => {
-            let cache_ty = quote! {
-                ::cold_moka::moka::sync::Cache<#cache_key_ty, #cache_value_ty>
+        (Some(ttl), Some(pinned_ident)) => {
+            // ttl is applied per entry so pinned ones can opt out of it
+            create = quote! {
+                #create.expire_after(::cold_moka::PinnedExpiry::new(&#pinned_ident, ::std::time::Duration::from_secs(#ttl)))
             };
-            let create = quote! {
-               ::cold_moka::moka::sync::Cache::builder().max_capacity(#size).time_to_live(::std::time::Duration::from_secs(#ttl)).build()
-            };
-            (cache_ty, create)
         }
-        (None, false) => {
-            let cache_ty = quote! {
-                ::cold_moka::moka::sync::Cache<#cache_key_ty, #cache_value_ty>
-            };
-            let create = quote! {
-                ::cold_moka::moka::sync::Cache::builder().max_capacity(#size).build()
+        (Some(ttl), None) => {
+            create = quote! {
+                #create.time_to_live(::std::time::Duration::from_secs(#ttl))
             };
-            (cache_ty, create)
         }
-    };
-    (cache_ty, cache_create)
+        (None, _) => {}
+    }
+    if args.invalidation {
+        create = quote! {
+            #create.support_invalidation_closures()
+        };
+    }
+    (cache_ty, create)
 }
 
 #[cfg(test)]
 mod test {
+    use darling::ast::NestedMeta;
+    use darling::FromMeta;
+
+    use quote::quote;
+
+    use super::{
+        inner_function_call, is_flag_set, with_defaults, ErrorConversion, MacroArgs, RetTurnTy,
+    };
+
     #[test]
     pub fn pass() {
         macrotest::expand("tests/*.rs");
     }
+
+    fn args(explicit: &str, defaults: &str) -> MacroArgs {
+        let explicit = NestedMeta::parse_meta_list(explicit.parse().unwrap()).unwrap();
+        MacroArgs::from_list(&with_defaults(explicit, defaults, false).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn defaults_fill_missing_args() {
+        let args = args("size = 10", "ttl = 300, stats = true");
+        assert_eq!(args.size, Some(10));
+        assert_eq!(args.ttl, Some(300));
+        assert!(args.stats);
+    }
+
+    #[test]
+    fn explicit_args_win() {
+        let args = args("ttl = 5, stats = false", "ttl = 300, stats = true");
+        assert_eq!(args.ttl, Some(5));
+        assert!(!args.stats);
+    }
+
+    #[test]
+    fn explicit_args_skip_conflicting_defaults() {
+        let timed = args(
+            "value_ttl = true",
+            "ttl = 300, negative_ttl = 5, stats = true",
+        );
+        assert!(timed.value_ttl);
+        assert_eq!((timed.ttl, timed.negative_ttl), (None, None));
+        assert!(timed.stats);
+
+        let weighed = args("max_weight = 1000", "size = 100");
+        assert_eq!((weighed.max_weight, weighed.size), (Some(1000), None));
+    }
+
+    #[test]
+    fn no_defaults() {
+        let args = args("ttl = 5", "");
+        assert_eq!(args.ttl, Some(5));
+        assert!(!args.stats);
+    }
+
+    #[test]
+    fn group_members_skip_cache_defaults() {
+        let explicit = NestedMeta::parse_meta_list(quote! { group = "USERS" }).unwrap();
+        let defaults = with_defaults(explicit, "ttl = 300, stats = true", false).unwrap();
+        let args = MacroArgs::from_list(&defaults).unwrap();
+        assert_eq!(args.ttl, None);
+        assert!(args.stats);
+    }
+
+    #[test]
+    fn backends_skip_cache_defaults() {
+        let args = args("backend = \"USERS\"", "ttl = 300, stats = true");
+        assert_eq!(args.ttl, None);
+        assert!(!args.stats);
+    }
+
+    #[test]
+    fn invalid_defaults() {
+        assert!(with_defaults(Vec::new(), "ttl = ", false).is_err());
+    }
+
+    #[test]
+    fn methods_skip_unsupported_defaults() {
+        let defaults = with_defaults(Vec::new(), "ttl = 300, stats = true", true).unwrap();
+        let args = MacroArgs::from_list(&defaults).unwrap();
+        assert_eq!(args.ttl, Some(300));
+        assert!(!args.stats);
+    }
+
+    #[test]
+    fn associated_flag() {
+        let set = |list: &str| {
+            let args = NestedMeta::parse_meta_list(list.parse().unwrap()).unwrap();
+            args.iter().any(|arg| is_flag_set(arg, "associated"))
+        };
+        assert!(set("associated"));
+        assert!(set("size = 10, associated = true"));
+        assert!(!set("associated = false"));
+        assert!(!set("size = 10"));
+    }
+
+    #[test]
+    fn sync_and_async_results_expand_alike() {
+        let expand = |is_async| {
+            inner_function_call(
+                quote! { compute() },
+                RetTurnTy::Result,
+                &ErrorConversion::Clone,
+                &quote! { CACHE },
+                is_async,
+                None,
+            )
+            .to_string()
+        };
+        // the sync cache takes a closure, the future one a future to await
+        let sync = expand(false).replace("|| ", "");
+        let async_ = expand(true).replace(" . await", "");
+        assert_eq!(sync, async_);
+        assert!(!sync.contains("return"));
+    }
 }
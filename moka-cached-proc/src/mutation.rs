@@ -0,0 +1,188 @@
+use darling::ast::NestedMeta;
+use darling::FromMeta;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{parse_str, FnArg, ItemFn, Path, ReturnType};
+
+use crate::diagnostics::Diagnostic;
+use crate::helpers::*;
+
+// what `#[cache_invalidate]` and `#[cache_update]` do to the entry of their target
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Mutation {
+    Invalidate,
+    Update,
+}
+
+#[derive(FromMeta)]
+struct MutationArgs {
+    // path of the `#[cached]` function whose entry is invalidated or updated
+    target: String,
+    #[darling(default)]
+    // inputs building the key of the target, in the order of its key
+    key: Option<String>,
+}
+
+pub(crate) fn expand(mutation: Mutation, args: TokenStream2, input: ItemFn) -> TokenStream2 {
+    let attr_args = match NestedMeta::parse_meta_list(args) {
+        Ok(v) => v,
+        Err(e) => return darling::Error::from(e).write_errors(),
+    };
+    let args = match MutationArgs::from_list(&attr_args) {
+        Ok(v) => v,
+        Err(e) => return e.write_errors(),
+    };
+
+    let mut target = match parse_str::<Path>(&args.target) {
+        Ok(target) => target,
+        Err(_) => {
+            return Diagnostic::InvalidArgument.compile_error(
+                crate::arg_span(&attr_args, "target"),
+                "unable to parse target path",
+            )
+        }
+    };
+    // the target is reached through its `<fn>_cache_invalidate`/`<fn>_cache_insert` companion
+    let companion = match mutation {
+        Mutation::Invalidate => "cache_invalidate",
+        Mutation::Update => "cache_insert",
+    };
+    if let Some(last) = target.segments.last_mut() {
+        last.ident = companion_ident(&last.ident, companion);
+    }
+
+    let attributes = input.attrs;
+    let visibility = input.vis;
+    let signature = input.sig;
+    let body = input.block;
+
+    let fn_ident = signature.ident.clone();
+    let inputs = signature.inputs.clone();
+    let output = signature.output.clone();
+    if let Some(FnArg::Receiver(receiver)) = inputs.first() {
+        return Diagnostic::UnsupportedReceiver.compile_error(
+            receiver.span(),
+            "methods (functions taking 'self') are not supported",
+        );
+    }
+    let is_async = signature.asyncness.is_some();
+
+    let input_names: Vec<_> = get_input_names(&inputs).map(|x| x.0).collect();
+    let key_names = match &args.key {
+        Some(key) => {
+            let mut key_names = Vec::new();
+            for name in key.split(',').map(str::trim) {
+                match input_names.iter().find(|ident| *ident == name) {
+                    Some(ident) => key_names.push(ident.clone()),
+                    None => {
+                        let available: Vec<_> =
+                            input_names.iter().map(ToString::to_string).collect();
+                        return Diagnostic::InvalidArgument.compile_error(
+                            crate::arg_span(&attr_args, "key"),
+                            format!(
+                                "`{}` is not an argument of `{}`, expected one of: {}",
+                                name,
+                                fn_ident,
+                                available.join(", ")
+                            ),
+                        );
+                    }
+                }
+            }
+            key_names
+        }
+        None => input_names,
+    };
+    // the inputs are moved into the function, so the key is copied out first
+    let key_copies: Vec<_> = key_names
+        .iter()
+        .map(|name| private_ident(name, "copy"))
+        .collect();
+
+    let no_mutation_fn_ident = private_ident(&fn_ident, "inner");
+    let function_no_mutation = if is_async {
+        quote! {
+            async fn #no_mutation_fn_ident(#inputs) #output #body
+        }
+    } else {
+        quote! {
+            fn #no_mutation_fn_ident(#inputs) #output #body
+        }
+    };
+    let call_args = match get_wrapped_type_for_function_call(&inputs) {
+        Ok(call_args) => call_args,
+        Err(e) => return e.to_compile_error(),
+    };
+    let await_call = is_async.then(|| quote! {.await});
+    // a sync function can't wait for an async target, the future it would get is rejected
+    // here instead of being dropped without touching the cache
+    let target_span = crate::arg_span(&attr_args, "target");
+    let mutate_call = |target_args: TokenStream2| {
+        if is_async {
+            quote! { #target(#target_args).await; }
+        } else {
+            quote_spanned! {target_span=> let (): () = #target(#target_args); }
+        }
+    };
+    let mutate = match mutation {
+        Mutation::Invalidate => mutate_call(quote! { #(#key_copies),* }),
+        Mutation::Update => {
+            // only the values the target would cache are written, errors and `None`s are not
+            let return_ty = return_fallible_type(&output);
+            let output_ty = match &output {
+                ReturnType::Default => quote! {()},
+                ReturnType::Type(_, ty) => quote! {#ty},
+            };
+            let value_ty = match find_value_type(return_ty, &output, output_ty, output.span()) {
+                Ok(value_ty) => value_ty,
+                Err(e) => return e.to_compile_error(),
+            };
+            // a value type differing from the one of the target is reported on the return type
+            let copy_value = quote_spanned! {output.span()=>
+                let value: #value_ty = ::std::clone::Clone::clone(value);
+            };
+            let value = quote_spanned! {output.span()=> value};
+            let insert_call = mutate_call(quote! { #(#key_copies,)* #value });
+            let insert = quote! {
+                #copy_value
+                #insert_call
+            };
+            match return_ty {
+                RetTurnTy::Bare => quote! {
+                    let value = &result;
+                    #insert
+                },
+                RetTurnTy::Result => quote! {
+                    if let Ok(value) = &result {
+                        #insert
+                    }
+                },
+                RetTurnTy::Option => quote! {
+                    if let Some(value) = &result {
+                        #insert
+                    }
+                },
+                RetTurnTy::ResultOption => quote! {
+                    if let Ok(Some(value)) = &result {
+                        #insert
+                    }
+                },
+            }
+        }
+    };
+    let signature = get_mut_signature(signature);
+
+    quote! {
+        #(#attributes)*
+        #visibility
+        #signature
+        {
+            #function_no_mutation
+            #(let #key_copies = ::std::clone::Clone::clone(&#key_names);)*
+            let result = #no_mutation_fn_ident(#(#call_args),*) #await_call;
+            #mutate
+            result
+        }
+    }
+}
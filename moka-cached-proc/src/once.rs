@@ -0,0 +1,115 @@
+use darling::ast::NestedMeta;
+use darling::FromMeta;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{parse_quote, FnArg, ItemFn, ReturnType, Type};
+
+use crate::diagnostics::Diagnostic;
+use crate::helpers::*;
+
+#[derive(FromMeta)]
+struct OnceArgs {}
+
+pub(crate) fn expand(args: TokenStream2, input: ItemFn) -> TokenStream2 {
+    let attr_args = match NestedMeta::parse_meta_list(args) {
+        Ok(v) => v,
+        Err(e) => return darling::Error::from(e).write_errors(),
+    };
+    if let Err(e) = OnceArgs::from_list(&attr_args) {
+        return e.write_errors();
+    }
+
+    let attributes = input.attrs;
+    let visibility = input.vis;
+    let signature = input.sig;
+    let body = input.block;
+
+    let fn_ident = signature.ident.clone();
+    let inputs = signature.inputs.clone();
+    let output = signature.output.clone();
+    let generics = signature.generics.clone();
+    if let Some(asyncness) = signature.asyncness {
+        return Diagnostic::UnsupportedFunction.compile_error(
+            asyncness.span(),
+            "#[once] is meant for functions which aren't `async`",
+        );
+    }
+    if !generics.params.is_empty() {
+        return Diagnostic::UnsupportedFunction.compile_error(
+            generics.span(),
+            "#[once] can't hold the value of a generic function in a static",
+        );
+    }
+    // the single value doesn't depend on the inputs, so only `()` ones make sense
+    for input in &inputs {
+        let unit = match input {
+            FnArg::Typed(pat_type) => {
+                matches!(pat_type.ty.as_ref(), Type::Tuple(tuple) if tuple.elems.is_empty())
+            }
+            FnArg::Receiver(_) => false,
+        };
+        if !unit {
+            return Diagnostic::UnsupportedFunction.compile_error(
+                input.span(),
+                "#[once] is meant for functions without arguments, use #[cached] instead",
+            );
+        }
+    }
+
+    let return_ty = return_fallible_type(&output);
+    let output_ty = match &output {
+        ReturnType::Default => quote! {()},
+        ReturnType::Type(_, ty) => quote! {#ty},
+    };
+    let value_ty = match find_value_type(return_ty, &output, output_ty, fn_ident.span()) {
+        Ok(value_ty) => value_ty,
+        Err(e) => return e.to_compile_error(),
+    };
+
+    let cell = static_ident(&fn_ident, None);
+    let no_cache_fn_ident = private_ident(&fn_ident, "inner");
+    let units = inputs.iter().map(|_| quote! {()});
+    let init = quote! { #no_cache_fn_ident(#(#units),*) };
+    // errors and `None`s aren't kept, the next call runs the function again
+    let function_call = match return_ty {
+        RetTurnTy::Bare => quote! {
+            ::std::clone::Clone::clone(#cell.get_or_init(|| #init))
+        },
+        RetTurnTy::Result => quote! {
+            #cell.get_or_try_init(|| #init).map(::std::clone::Clone::clone)
+        },
+        RetTurnTy::Option => quote! {
+            #cell.get_or_try_init(|| #init.ok_or(())).ok().cloned()
+        },
+        RetTurnTy::ResultOption => quote! {
+            match #cell.get_or_try_init(|| match #init {
+                Ok(Some(value)) => Ok(value),
+                uncached => Err(uncached),
+            }) {
+                Ok(value) => Ok(Some(::std::clone::Clone::clone(value))),
+                Err(uncached) => uncached,
+            }
+        },
+    };
+    // the `()` inputs are passed to the function as they are, the wrapper ignores them
+    let mut signature = signature;
+    for input in &mut signature.inputs {
+        if let FnArg::Typed(pat_type) = input {
+            pat_type.pat = parse_quote! {_};
+        }
+    }
+
+    quote! {
+        static #cell: ::cold_moka::once_cell::sync::OnceCell<#value_ty> =
+            ::cold_moka::once_cell::sync::OnceCell::new();
+
+        #(#attributes)*
+        #visibility
+        #signature
+        {
+            fn #no_cache_fn_ident(#inputs) #output #body
+            #function_call
+        }
+    }
+}
@@ -1,50 +1,5126 @@
 use cold_moka::cached;
 fn main() {}
-#[cached]
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+};
+static __COLD_MOKA_CACHED: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_cached() -> () {
+    ()
+}
 pub async fn cached() -> i32 {
-    1 + 2
-}
-#[cached]
-pub async fn cached2(mut i8: i8) -> i32 {
-    i8 += 1;
-    let x = i8 + 3;
-    x as i32
-}
-#[cached]
-pub async fn cached3(mut i8: i8, _kek: u128) -> i32 {
-    i8 += 1;
-    let x = i8 + 3;
-    x as i32
-}
-#[cached(ttl = "13")]
-pub async fn cached4(mut i8: i8, _kek: u128) -> i32 {
-    i8 += 1;
-    let x = i8 + 3;
-    x as i32
-}
-#[cached(ttl = "13", size = 1337)]
-pub async fn cached5(mut i8: i8, _kek: u128) -> i32 {
-    i8 += 1;
-    let x = i8 + 3;
-    x as i32
+    async fn __cold_moka_inner_cached() -> i32 {
+        1 + 2
+    }
+    let key = __cold_moka_key_cached();
+    __COLD_MOKA_CACHED.get_with_by_ref(&key, __cold_moka_inner_cached()).await
+}
+#[allow(dead_code)]
+pub fn cached_cache_clear() {
+    __COLD_MOKA_CACHED.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn cached_cache_invalidate() {
+    let key = __cold_moka_key_cached();
+    __COLD_MOKA_CACHED.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn cached_cache_insert(value: i32) {
+    let key = __cold_moka_key_cached();
+    __COLD_MOKA_CACHED.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn cached_if_cached() -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_cached();
+    __COLD_MOKA_CACHED.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn cached_cache_contains() -> bool {
+    let key = __cold_moka_key_cached();
+    __COLD_MOKA_CACHED.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn cached_cache_entry_count() -> u64 {
+    __COLD_MOKA_CACHED.entry_count()
+}
+#[allow(dead_code)]
+pub fn cached_cache_weighted_size() -> u64 {
+    __COLD_MOKA_CACHED.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn cached_cache_sync() {
+    __COLD_MOKA_CACHED.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn cached_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = ()>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|()| async move {
+            let key = __cold_moka_key_cached();
+            if __COLD_MOKA_CACHED.contains_key(&key) {
+                return true;
+            }
+            let _ = cached().await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i8>();
+};
+static __COLD_MOKA_CACHED2: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i8), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_cached2(i8: &i8) -> (i8) {
+    (i8.clone())
+}
+pub async fn cached2(i8: i8) -> i32 {
+    async fn __cold_moka_inner_cached2(mut i8: i8) -> i32 {
+        i8 += 1;
+        let x = i8 + 3;
+        x as i32
+    }
+    let key = __cold_moka_key_cached2(&i8);
+    __COLD_MOKA_CACHED2.get_with_by_ref(&key, __cold_moka_inner_cached2(i8)).await
+}
+#[allow(dead_code)]
+pub fn cached2_cache_clear() {
+    __COLD_MOKA_CACHED2.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn cached2_cache_invalidate(i8: i8) {
+    let key = __cold_moka_key_cached2(&i8);
+    __COLD_MOKA_CACHED2.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn cached2_cache_insert(i8: i8, value: i32) {
+    let key = __cold_moka_key_cached2(&i8);
+    __COLD_MOKA_CACHED2.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn cached2_if_cached(i8: i8) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_cached2(&i8);
+    __COLD_MOKA_CACHED2.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn cached2_cache_contains(i8: i8) -> bool {
+    let key = __cold_moka_key_cached2(&i8);
+    __COLD_MOKA_CACHED2.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn cached2_cache_entry_count() -> u64 {
+    __COLD_MOKA_CACHED2.entry_count()
+}
+#[allow(dead_code)]
+pub fn cached2_cache_weighted_size() -> u64 {
+    __COLD_MOKA_CACHED2.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn cached2_cache_sync() {
+    __COLD_MOKA_CACHED2.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn cached2_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i8>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|i8| async move {
+            let key = __cold_moka_key_cached2(&i8);
+            if __COLD_MOKA_CACHED2.contains_key(&key) {
+                return true;
+            }
+            let _ = cached2(i8).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i8>();
+    cached_key::<u128>();
+};
+static __COLD_MOKA_CACHED3: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i8, u128), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_cached3(i8: &i8, _kek: &u128) -> (i8, u128) {
+    (i8.clone(), _kek.clone())
+}
+pub async fn cached3(i8: i8, _kek: u128) -> i32 {
+    async fn __cold_moka_inner_cached3(mut i8: i8, _kek: u128) -> i32 {
+        i8 += 1;
+        let x = i8 + 3;
+        x as i32
+    }
+    let key = __cold_moka_key_cached3(&i8, &_kek);
+    __COLD_MOKA_CACHED3.get_with_by_ref(&key, __cold_moka_inner_cached3(i8, _kek)).await
+}
+#[allow(dead_code)]
+pub fn cached3_cache_clear() {
+    __COLD_MOKA_CACHED3.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn cached3_cache_invalidate(i8: i8, _kek: u128) {
+    let key = __cold_moka_key_cached3(&i8, &_kek);
+    __COLD_MOKA_CACHED3.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn cached3_cache_insert(i8: i8, _kek: u128, value: i32) {
+    let key = __cold_moka_key_cached3(&i8, &_kek);
+    __COLD_MOKA_CACHED3.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn cached3_if_cached(i8: i8, _kek: u128) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_cached3(&i8, &_kek);
+    __COLD_MOKA_CACHED3.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn cached3_cache_contains(i8: i8, _kek: u128) -> bool {
+    let key = __cold_moka_key_cached3(&i8, &_kek);
+    __COLD_MOKA_CACHED3.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn cached3_cache_entry_count() -> u64 {
+    __COLD_MOKA_CACHED3.entry_count()
+}
+#[allow(dead_code)]
+pub fn cached3_cache_weighted_size() -> u64 {
+    __COLD_MOKA_CACHED3.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn cached3_cache_sync() {
+    __COLD_MOKA_CACHED3.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn cached3_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (i8, u128)>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|(i8, _kek)| async move {
+            let key = __cold_moka_key_cached3(&i8, &_kek);
+            if __COLD_MOKA_CACHED3.contains_key(&key) {
+                return true;
+            }
+            let _ = cached3(i8, _kek).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i8>();
+    cached_key::<u128>();
+};
+static __COLD_MOKA_CACHED4: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i8, u128), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder()
+        .max_capacity(1000u64)
+        .time_to_live(::std::time::Duration::from_secs(13u64))
+        .build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_cached4(i8: &i8, _kek: &u128) -> (i8, u128) {
+    (i8.clone(), _kek.clone())
+}
+pub async fn cached4(i8: i8, _kek: u128) -> i32 {
+    async fn __cold_moka_inner_cached4(mut i8: i8, _kek: u128) -> i32 {
+        i8 += 1;
+        let x = i8 + 3;
+        x as i32
+    }
+    let key = __cold_moka_key_cached4(&i8, &_kek);
+    __COLD_MOKA_CACHED4.get_with_by_ref(&key, __cold_moka_inner_cached4(i8, _kek)).await
+}
+#[allow(dead_code)]
+pub fn cached4_cache_clear() {
+    __COLD_MOKA_CACHED4.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn cached4_cache_invalidate(i8: i8, _kek: u128) {
+    let key = __cold_moka_key_cached4(&i8, &_kek);
+    __COLD_MOKA_CACHED4.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn cached4_cache_insert(i8: i8, _kek: u128, value: i32) {
+    let key = __cold_moka_key_cached4(&i8, &_kek);
+    __COLD_MOKA_CACHED4.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn cached4_if_cached(i8: i8, _kek: u128) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_cached4(&i8, &_kek);
+    __COLD_MOKA_CACHED4.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn cached4_cache_contains(i8: i8, _kek: u128) -> bool {
+    let key = __cold_moka_key_cached4(&i8, &_kek);
+    __COLD_MOKA_CACHED4.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn cached4_cache_entry_count() -> u64 {
+    __COLD_MOKA_CACHED4.entry_count()
+}
+#[allow(dead_code)]
+pub fn cached4_cache_weighted_size() -> u64 {
+    __COLD_MOKA_CACHED4.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn cached4_cache_sync() {
+    __COLD_MOKA_CACHED4.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn cached4_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (i8, u128)>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|(i8, _kek)| async move {
+            let key = __cold_moka_key_cached4(&i8, &_kek);
+            if __COLD_MOKA_CACHED4.contains_key(&key) {
+                return true;
+            }
+            let _ = cached4(i8, _kek).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i8>();
+    cached_key::<u128>();
+};
+static __COLD_MOKA_CACHED5: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i8, u128), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder()
+        .max_capacity(1337u64)
+        .time_to_live(::std::time::Duration::from_secs(13u64))
+        .build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_cached5(i8: &i8, _kek: &u128) -> (i8, u128) {
+    (i8.clone(), _kek.clone())
+}
+pub async fn cached5(i8: i8, _kek: u128) -> i32 {
+    async fn __cold_moka_inner_cached5(mut i8: i8, _kek: u128) -> i32 {
+        i8 += 1;
+        let x = i8 + 3;
+        x as i32
+    }
+    let key = __cold_moka_key_cached5(&i8, &_kek);
+    __COLD_MOKA_CACHED5.get_with_by_ref(&key, __cold_moka_inner_cached5(i8, _kek)).await
+}
+#[allow(dead_code)]
+pub fn cached5_cache_clear() {
+    __COLD_MOKA_CACHED5.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn cached5_cache_invalidate(i8: i8, _kek: u128) {
+    let key = __cold_moka_key_cached5(&i8, &_kek);
+    __COLD_MOKA_CACHED5.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn cached5_cache_insert(i8: i8, _kek: u128, value: i32) {
+    let key = __cold_moka_key_cached5(&i8, &_kek);
+    __COLD_MOKA_CACHED5.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn cached5_if_cached(i8: i8, _kek: u128) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_cached5(&i8, &_kek);
+    __COLD_MOKA_CACHED5.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn cached5_cache_contains(i8: i8, _kek: u128) -> bool {
+    let key = __cold_moka_key_cached5(&i8, &_kek);
+    __COLD_MOKA_CACHED5.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn cached5_cache_entry_count() -> u64 {
+    __COLD_MOKA_CACHED5.entry_count()
+}
+#[allow(dead_code)]
+pub fn cached5_cache_weighted_size() -> u64 {
+    __COLD_MOKA_CACHED5.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn cached5_cache_sync() {
+    __COLD_MOKA_CACHED5.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn cached5_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (i8, u128)>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|(i8, _kek)| async move {
+            let key = __cold_moka_key_cached5(&i8, &_kek);
+            if __COLD_MOKA_CACHED5.contains_key(&key) {
+                return true;
+            }
+            let _ = cached5(i8, _kek).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
 }
 pub struct NoHash;
-#[cached(ttl = "13", size = 1337, convert = "{i8}", key = "i8")]
-pub async fn cached6(mut i8: i8, _ctx: NoHash) -> i32 {
-    i8 += 1;
-    let x = i8 + 3;
-    x as i32
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<(i8)>();
+};
+static __COLD_MOKA_CACHED6: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i8), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder()
+        .max_capacity(1337u64)
+        .time_to_live(::std::time::Duration::from_secs(13u64))
+        .build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_cached6(i8: &i8) -> (i8) {
+    #[allow(unused_variables)]
+    let i8 = ::std::clone::Clone::clone(i8);
+    { i8 }
+}
+pub async fn cached6(i8: i8, _ctx: NoHash) -> i32 {
+    async fn __cold_moka_inner_cached6(mut i8: i8, _ctx: NoHash) -> i32 {
+        i8 += 1;
+        let x = i8 + 3;
+        x as i32
+    }
+    let key = __cold_moka_key_cached6(&i8);
+    __COLD_MOKA_CACHED6.get_with_by_ref(&key, __cold_moka_inner_cached6(i8, _ctx)).await
+}
+#[allow(dead_code)]
+pub fn cached6_cache_clear() {
+    __COLD_MOKA_CACHED6.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn cached6_cache_invalidate(i8: i8) {
+    let key = __cold_moka_key_cached6(&i8);
+    __COLD_MOKA_CACHED6.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn cached6_cache_insert(i8: i8, value: i32) {
+    let key = __cold_moka_key_cached6(&i8);
+    __COLD_MOKA_CACHED6.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn cached6_if_cached(i8: i8) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_cached6(&i8);
+    __COLD_MOKA_CACHED6.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn cached6_cache_contains(i8: i8) -> bool {
+    let key = __cold_moka_key_cached6(&i8);
+    __COLD_MOKA_CACHED6.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn cached6_cache_entry_count() -> u64 {
+    __COLD_MOKA_CACHED6.entry_count()
+}
+#[allow(dead_code)]
+pub fn cached6_cache_weighted_size() -> u64 {
+    __COLD_MOKA_CACHED6.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn cached6_cache_sync() {
+    __COLD_MOKA_CACHED6.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn cached6_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (i8, NoHash)>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|(i8, _ctx)| async move {
+            let key = __cold_moka_key_cached6(&i8);
+            if __COLD_MOKA_CACHED6.contains_key(&key) {
+                return true;
+            }
+            let _ = cached6(i8, _ctx).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_RESULT: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_result(inp: &i32) -> (i32) {
+    (inp.clone())
 }
-#[cached]
 pub async fn result(inp: i32) -> Result<i32, i32> {
-    Ok(inp)
+    async fn __cold_moka_inner_result(inp: i32) -> Result<i32, i32> {
+        Ok(inp)
+    }
+    let key = __cold_moka_key_result(&inp);
+    let result = __COLD_MOKA_RESULT
+        .try_get_with_by_ref(&key, __cold_moka_inner_result(inp))
+        .await;
+    match result {
+        Ok(v) => Ok(v),
+        Err(e) => {
+            Err({
+                #[allow(unused_imports)]
+                use ::cold_moka::{CloneArcError as _, FromArcError as _};
+                (&&::cold_moka::ArcError::new(e)).into_error()
+            })
+        }
+    }
+}
+#[allow(dead_code)]
+pub fn result_cache_clear() {
+    __COLD_MOKA_RESULT.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn result_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_result(&inp);
+    __COLD_MOKA_RESULT.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn result_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_result(&inp);
+    __COLD_MOKA_RESULT.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn result_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_result(&inp);
+    __COLD_MOKA_RESULT.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn result_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_result(&inp);
+    __COLD_MOKA_RESULT.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn result_cache_entry_count() -> u64 {
+    __COLD_MOKA_RESULT.entry_count()
+}
+#[allow(dead_code)]
+pub fn result_cache_weighted_size() -> u64 {
+    __COLD_MOKA_RESULT.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn result_cache_sync() {
+    __COLD_MOKA_RESULT.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn result_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_result(&inp);
+            if __COLD_MOKA_RESULT.contains_key(&key) {
+                return true;
+            }
+            let _ = result(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_OPTION: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_option(inp: &i32) -> (i32) {
+    (inp.clone())
 }
-#[cached]
 async fn option(inp: i32) -> Option<i32> {
-    Some(inp)
+    async fn __cold_moka_inner_option(inp: i32) -> Option<i32> {
+        Some(inp)
+    }
+    let key = __cold_moka_key_option(&inp);
+    __COLD_MOKA_OPTION
+        .optionally_get_with_by_ref(&key, __cold_moka_inner_option(inp))
+        .await
+}
+#[allow(dead_code)]
+fn option_cache_clear() {
+    __COLD_MOKA_OPTION.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+async fn option_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_option(&inp);
+    __COLD_MOKA_OPTION.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+async fn option_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_option(&inp);
+    __COLD_MOKA_OPTION.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+async fn option_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_option(&inp);
+    __COLD_MOKA_OPTION.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+fn option_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_option(&inp);
+    __COLD_MOKA_OPTION.contains_key(&key)
+}
+#[allow(dead_code)]
+fn option_cache_entry_count() -> u64 {
+    __COLD_MOKA_OPTION.entry_count()
+}
+#[allow(dead_code)]
+fn option_cache_weighted_size() -> u64 {
+    __COLD_MOKA_OPTION.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+async fn option_cache_sync() {
+    __COLD_MOKA_OPTION.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+async fn option_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_option(&inp);
+            if __COLD_MOKA_OPTION.contains_key(&key) {
+                return true;
+            }
+            let _ = option(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
 }
 pub struct Wrapper<T>(T);
-#[cached]
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_DESTRUCT: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_destruct(aaaaaa: &i32) -> (i32) {
+    (aaaaaa.clone())
+}
 async fn destruct(Wrapper(aaaaaa): Wrapper<i32>) -> i32 {
-    aaaaaa
+    async fn __cold_moka_inner_destruct(Wrapper(aaaaaa): Wrapper<i32>) -> i32 {
+        aaaaaa
+    }
+    let key = __cold_moka_key_destruct(&aaaaaa);
+    __COLD_MOKA_DESTRUCT
+        .get_with_by_ref(&key, __cold_moka_inner_destruct(Wrapper(aaaaaa)))
+        .await
+}
+#[allow(dead_code)]
+fn destruct_cache_clear() {
+    __COLD_MOKA_DESTRUCT.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+async fn destruct_cache_invalidate(aaaaaa: i32) {
+    let key = __cold_moka_key_destruct(&aaaaaa);
+    __COLD_MOKA_DESTRUCT.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+async fn destruct_cache_insert(aaaaaa: i32, value: i32) {
+    let key = __cold_moka_key_destruct(&aaaaaa);
+    __COLD_MOKA_DESTRUCT.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+async fn destruct_if_cached(aaaaaa: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_destruct(&aaaaaa);
+    __COLD_MOKA_DESTRUCT.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+fn destruct_cache_contains(aaaaaa: i32) -> bool {
+    let key = __cold_moka_key_destruct(&aaaaaa);
+    __COLD_MOKA_DESTRUCT.contains_key(&key)
+}
+#[allow(dead_code)]
+fn destruct_cache_entry_count() -> u64 {
+    __COLD_MOKA_DESTRUCT.entry_count()
+}
+#[allow(dead_code)]
+fn destruct_cache_weighted_size() -> u64 {
+    __COLD_MOKA_DESTRUCT.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+async fn destruct_cache_sync() {
+    __COLD_MOKA_DESTRUCT.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+async fn destruct_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = Wrapper<i32>>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|Wrapper(aaaaaa)| async move {
+            let key = __cold_moka_key_destruct(&aaaaaa);
+            if __COLD_MOKA_DESTRUCT.contains_key(&key) {
+                return true;
+            }
+            let _ = destruct(Wrapper(aaaaaa)).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_PINNED_PINNED: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::PinnedKeys<(i32)>,
+> = ::cold_moka::once_cell::sync::Lazy::new(::std::default::Default::default);
+static __COLD_MOKA_PINNED: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder()
+        .max_capacity(1000u64)
+        .expire_after(
+            ::cold_moka::PinnedExpiry::new(
+                &__COLD_MOKA_PINNED_PINNED,
+                ::std::time::Duration::from_secs(13u64),
+            ),
+        )
+        .build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_pinned(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub async fn pinned(inp: i32) -> i32 {
+    async fn __cold_moka_inner_pinned(inp: i32) -> i32 {
+        inp
+    }
+    let key = __cold_moka_key_pinned(&inp);
+    __COLD_MOKA_PINNED.get_with_by_ref(&key, __cold_moka_inner_pinned(inp)).await
+}
+#[allow(dead_code)]
+pub async fn pinned_pin(inp: i32) -> i32 {
+    __COLD_MOKA_PINNED_PINNED.pin(__cold_moka_key_pinned(&inp));
+    pinned(inp).await
+}
+#[allow(dead_code)]
+pub fn pinned_cache_clear() {
+    __COLD_MOKA_PINNED.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn pinned_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_pinned(&inp);
+    __COLD_MOKA_PINNED.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn pinned_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_pinned(&inp);
+    __COLD_MOKA_PINNED.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn pinned_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_pinned(&inp);
+    __COLD_MOKA_PINNED.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn pinned_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_pinned(&inp);
+    __COLD_MOKA_PINNED.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn pinned_cache_entry_count() -> u64 {
+    __COLD_MOKA_PINNED.entry_count()
+}
+#[allow(dead_code)]
+pub fn pinned_cache_weighted_size() -> u64 {
+    __COLD_MOKA_PINNED.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn pinned_cache_sync() {
+    __COLD_MOKA_PINNED.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn pinned_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_pinned(&inp);
+            if __COLD_MOKA_PINNED.contains_key(&key) {
+                return true;
+            }
+            let _ = pinned(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_QUALIFIED_RESULT: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_qualified_result(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub async fn qualified_result(inp: i32) -> ::std::result::Result<i32, i32> {
+    async fn __cold_moka_inner_qualified_result(
+        inp: i32,
+    ) -> ::std::result::Result<i32, i32> {
+        Ok(inp)
+    }
+    let key = __cold_moka_key_qualified_result(&inp);
+    let result = __COLD_MOKA_QUALIFIED_RESULT
+        .try_get_with_by_ref(&key, __cold_moka_inner_qualified_result(inp))
+        .await;
+    match result {
+        Ok(v) => Ok(v),
+        Err(e) => {
+            Err({
+                #[allow(unused_imports)]
+                use ::cold_moka::{CloneArcError as _, FromArcError as _};
+                (&&::cold_moka::ArcError::new(e)).into_error()
+            })
+        }
+    }
+}
+#[allow(dead_code)]
+pub fn qualified_result_cache_clear() {
+    __COLD_MOKA_QUALIFIED_RESULT.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn qualified_result_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_qualified_result(&inp);
+    __COLD_MOKA_QUALIFIED_RESULT.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn qualified_result_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_qualified_result(&inp);
+    __COLD_MOKA_QUALIFIED_RESULT.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn qualified_result_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_qualified_result(&inp);
+    __COLD_MOKA_QUALIFIED_RESULT.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn qualified_result_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_qualified_result(&inp);
+    __COLD_MOKA_QUALIFIED_RESULT.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn qualified_result_cache_entry_count() -> u64 {
+    __COLD_MOKA_QUALIFIED_RESULT.entry_count()
+}
+#[allow(dead_code)]
+pub fn qualified_result_cache_weighted_size() -> u64 {
+    __COLD_MOKA_QUALIFIED_RESULT.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn qualified_result_cache_sync() {
+    __COLD_MOKA_QUALIFIED_RESULT.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn qualified_result_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_qualified_result(&inp);
+            if __COLD_MOKA_QUALIFIED_RESULT.contains_key(&key) {
+                return true;
+            }
+            let _ = qualified_result(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_STD_RESULT: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_std_result(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub async fn std_result(inp: i32) -> std::result::Result<i32, i32> {
+    async fn __cold_moka_inner_std_result(inp: i32) -> std::result::Result<i32, i32> {
+        Ok(inp)
+    }
+    let key = __cold_moka_key_std_result(&inp);
+    let result = __COLD_MOKA_STD_RESULT
+        .try_get_with_by_ref(&key, __cold_moka_inner_std_result(inp))
+        .await;
+    match result {
+        Ok(v) => Ok(v),
+        Err(e) => {
+            Err({
+                #[allow(unused_imports)]
+                use ::cold_moka::{CloneArcError as _, FromArcError as _};
+                (&&::cold_moka::ArcError::new(e)).into_error()
+            })
+        }
+    }
+}
+#[allow(dead_code)]
+pub fn std_result_cache_clear() {
+    __COLD_MOKA_STD_RESULT.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn std_result_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_std_result(&inp);
+    __COLD_MOKA_STD_RESULT.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn std_result_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_std_result(&inp);
+    __COLD_MOKA_STD_RESULT.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn std_result_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_std_result(&inp);
+    __COLD_MOKA_STD_RESULT.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn std_result_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_std_result(&inp);
+    __COLD_MOKA_STD_RESULT.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn std_result_cache_entry_count() -> u64 {
+    __COLD_MOKA_STD_RESULT.entry_count()
+}
+#[allow(dead_code)]
+pub fn std_result_cache_weighted_size() -> u64 {
+    __COLD_MOKA_STD_RESULT.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn std_result_cache_sync() {
+    __COLD_MOKA_STD_RESULT.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn std_result_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_std_result(&inp);
+            if __COLD_MOKA_STD_RESULT.contains_key(&key) {
+                return true;
+            }
+            let _ = std_result(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_QUALIFIED_OPTION: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_qualified_option(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub async fn qualified_option(inp: i32) -> ::core::option::Option<i32> {
+    async fn __cold_moka_inner_qualified_option(
+        inp: i32,
+    ) -> ::core::option::Option<i32> {
+        Some(inp)
+    }
+    let key = __cold_moka_key_qualified_option(&inp);
+    __COLD_MOKA_QUALIFIED_OPTION
+        .optionally_get_with_by_ref(&key, __cold_moka_inner_qualified_option(inp))
+        .await
+}
+#[allow(dead_code)]
+pub fn qualified_option_cache_clear() {
+    __COLD_MOKA_QUALIFIED_OPTION.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn qualified_option_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_qualified_option(&inp);
+    __COLD_MOKA_QUALIFIED_OPTION.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn qualified_option_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_qualified_option(&inp);
+    __COLD_MOKA_QUALIFIED_OPTION.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn qualified_option_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_qualified_option(&inp);
+    __COLD_MOKA_QUALIFIED_OPTION.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn qualified_option_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_qualified_option(&inp);
+    __COLD_MOKA_QUALIFIED_OPTION.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn qualified_option_cache_entry_count() -> u64 {
+    __COLD_MOKA_QUALIFIED_OPTION.entry_count()
+}
+#[allow(dead_code)]
+pub fn qualified_option_cache_weighted_size() -> u64 {
+    __COLD_MOKA_QUALIFIED_OPTION.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn qualified_option_cache_sync() {
+    __COLD_MOKA_QUALIFIED_OPTION.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn qualified_option_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_qualified_option(&inp);
+            if __COLD_MOKA_QUALIFIED_OPTION.contains_key(&key) {
+                return true;
+            }
+            let _ = qualified_option(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_CORE_OPTION: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_core_option(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub async fn core_option(inp: i32) -> core::option::Option<i32> {
+    async fn __cold_moka_inner_core_option(inp: i32) -> core::option::Option<i32> {
+        Some(inp)
+    }
+    let key = __cold_moka_key_core_option(&inp);
+    __COLD_MOKA_CORE_OPTION
+        .optionally_get_with_by_ref(&key, __cold_moka_inner_core_option(inp))
+        .await
+}
+#[allow(dead_code)]
+pub fn core_option_cache_clear() {
+    __COLD_MOKA_CORE_OPTION.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn core_option_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_core_option(&inp);
+    __COLD_MOKA_CORE_OPTION.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn core_option_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_core_option(&inp);
+    __COLD_MOKA_CORE_OPTION.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn core_option_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_core_option(&inp);
+    __COLD_MOKA_CORE_OPTION.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn core_option_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_core_option(&inp);
+    __COLD_MOKA_CORE_OPTION.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn core_option_cache_entry_count() -> u64 {
+    __COLD_MOKA_CORE_OPTION.entry_count()
+}
+#[allow(dead_code)]
+pub fn core_option_cache_weighted_size() -> u64 {
+    __COLD_MOKA_CORE_OPTION.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn core_option_cache_sync() {
+    __COLD_MOKA_CORE_OPTION.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn core_option_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_core_option(&inp);
+            if __COLD_MOKA_CORE_OPTION.contains_key(&key) {
+                return true;
+            }
+            let _ = core_option(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_CORE_RESULT: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_core_result(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub async fn core_result(inp: i32) -> core::result::Result<i32, i32> {
+    async fn __cold_moka_inner_core_result(inp: i32) -> core::result::Result<i32, i32> {
+        Ok(inp)
+    }
+    let key = __cold_moka_key_core_result(&inp);
+    let result = __COLD_MOKA_CORE_RESULT
+        .try_get_with_by_ref(&key, __cold_moka_inner_core_result(inp))
+        .await;
+    match result {
+        Ok(v) => Ok(v),
+        Err(e) => {
+            Err({
+                #[allow(unused_imports)]
+                use ::cold_moka::{CloneArcError as _, FromArcError as _};
+                (&&::cold_moka::ArcError::new(e)).into_error()
+            })
+        }
+    }
+}
+#[allow(dead_code)]
+pub fn core_result_cache_clear() {
+    __COLD_MOKA_CORE_RESULT.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn core_result_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_core_result(&inp);
+    __COLD_MOKA_CORE_RESULT.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn core_result_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_core_result(&inp);
+    __COLD_MOKA_CORE_RESULT.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn core_result_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_core_result(&inp);
+    __COLD_MOKA_CORE_RESULT.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn core_result_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_core_result(&inp);
+    __COLD_MOKA_CORE_RESULT.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn core_result_cache_entry_count() -> u64 {
+    __COLD_MOKA_CORE_RESULT.entry_count()
+}
+#[allow(dead_code)]
+pub fn core_result_cache_weighted_size() -> u64 {
+    __COLD_MOKA_CORE_RESULT.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn core_result_cache_sync() {
+    __COLD_MOKA_CORE_RESULT.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn core_result_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_core_result(&inp);
+            if __COLD_MOKA_CORE_RESULT.contains_key(&key) {
+                return true;
+            }
+            let _ = core_result(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_STD_OPTION: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_std_option(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub async fn std_option(inp: i32) -> ::std::option::Option<i32> {
+    async fn __cold_moka_inner_std_option(inp: i32) -> ::std::option::Option<i32> {
+        Some(inp)
+    }
+    let key = __cold_moka_key_std_option(&inp);
+    __COLD_MOKA_STD_OPTION
+        .optionally_get_with_by_ref(&key, __cold_moka_inner_std_option(inp))
+        .await
+}
+#[allow(dead_code)]
+pub fn std_option_cache_clear() {
+    __COLD_MOKA_STD_OPTION.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn std_option_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_std_option(&inp);
+    __COLD_MOKA_STD_OPTION.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn std_option_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_std_option(&inp);
+    __COLD_MOKA_STD_OPTION.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn std_option_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_std_option(&inp);
+    __COLD_MOKA_STD_OPTION.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn std_option_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_std_option(&inp);
+    __COLD_MOKA_STD_OPTION.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn std_option_cache_entry_count() -> u64 {
+    __COLD_MOKA_STD_OPTION.entry_count()
+}
+#[allow(dead_code)]
+pub fn std_option_cache_weighted_size() -> u64 {
+    __COLD_MOKA_STD_OPTION.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn std_option_cache_sync() {
+    __COLD_MOKA_STD_OPTION.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn std_option_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_std_option(&inp);
+            if __COLD_MOKA_STD_OPTION.contains_key(&key) {
+                return true;
+            }
+            let _ = std_option(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+pub trait Repo {
+    fn fetch(
+        &self,
+        id: u64,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = i32> + Send>>;
+}
+impl Repo for NoHash {
+    fn fetch(
+        &self,
+        id: u64,
+    ) -> std::pin::Pin<Box<dyn std::future::Future<Output = i32> + Send>> {
+        const _: fn() = || {
+            fn cached_value<T: ::cold_moka::CacheValue>() {}
+            fn cached_key<T: ::cold_moka::CacheKey>() {}
+            cached_value::<i32>();
+            cached_key::<u64>();
+        };
+        static __COLD_MOKA_FETCH: ::cold_moka::once_cell::sync::Lazy<
+            ::cold_moka::moka::future::Cache<(u64), i32>,
+        > = ::cold_moka::once_cell::sync::Lazy::new(|| {
+            ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+        });
+        #[allow(clippy::ptr_arg, clippy::unused_unit)]
+        fn __cold_moka_key_fetch(id: &u64) -> (u64) {
+            (id.clone())
+        }
+        let fut: std::pin::Pin<Box<dyn std::future::Future<Output = i32> + Send>> = (|| {
+            Box::pin(async move { id as i32 })
+        })();
+        let key = __cold_moka_key_fetch(&id);
+        ::std::boxed::Box::pin(async move {
+            __COLD_MOKA_FETCH.get_with_by_ref(&key, fut).await
+        })
+    }
+}
+impl NoHash {
+    pub async fn method(&self, inp: i32) -> Result<i32, i32> {
+        const _: fn() = || {
+            fn cached_value<T: ::cold_moka::CacheValue>() {}
+            fn cached_key<T: ::cold_moka::CacheKey>() {}
+            cached_value::<i32>();
+            cached_key::<i32>();
+        };
+        static __COLD_MOKA_METHOD: ::cold_moka::once_cell::sync::Lazy<
+            ::cold_moka::moka::future::Cache<(i32), i32>,
+        > = ::cold_moka::once_cell::sync::Lazy::new(|| {
+            ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+        });
+        #[allow(clippy::ptr_arg, clippy::unused_unit)]
+        fn __cold_moka_key_method(inp: &i32) -> (i32) {
+            (inp.clone())
+        }
+        let key = __cold_moka_key_method(&inp);
+        let result = __COLD_MOKA_METHOD
+            .try_get_with_by_ref(
+                &key,
+                ::cold_moka::typed_future::<Result<i32, i32>, _>(async move { Ok(inp) }),
+            )
+            .await;
+        match result {
+            Ok(v) => Ok(v),
+            Err(e) => {
+                Err({
+                    #[allow(unused_imports)]
+                    use ::cold_moka::{CloneArcError as _, FromArcError as _};
+                    (&&::cold_moka::ArcError::new(e)).into_error()
+                })
+            }
+        }
+    }
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_SYNC_CACHE: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_sync_cache(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub async fn sync_cache(inp: i32) -> Result<i32, i32> {
+    async fn __cold_moka_inner_sync_cache(inp: i32) -> Result<i32, i32> {
+        Ok(inp)
+    }
+    let key = __cold_moka_key_sync_cache(&inp);
+    if let Some(value) = __COLD_MOKA_SYNC_CACHE.get(&key) {
+        return Ok(value);
+    }
+    async move {
+        let result = __cold_moka_inner_sync_cache(inp).await;
+        if let Ok(value) = &result {
+            __COLD_MOKA_SYNC_CACHE.insert(key, value.clone());
+        }
+        result
+    }
+        .await
+}
+#[allow(dead_code)]
+pub fn sync_cache_cache_clear() {
+    __COLD_MOKA_SYNC_CACHE.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn sync_cache_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_sync_cache(&inp);
+    __COLD_MOKA_SYNC_CACHE.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub async fn sync_cache_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_sync_cache(&inp);
+    __COLD_MOKA_SYNC_CACHE.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub async fn sync_cache_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_sync_cache(&inp);
+    __COLD_MOKA_SYNC_CACHE.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn sync_cache_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_sync_cache(&inp);
+    __COLD_MOKA_SYNC_CACHE.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn sync_cache_cache_entry_count() -> u64 {
+    __COLD_MOKA_SYNC_CACHE.run_pending_tasks();
+    __COLD_MOKA_SYNC_CACHE.entry_count()
+}
+#[allow(dead_code)]
+pub fn sync_cache_cache_weighted_size() -> u64 {
+    __COLD_MOKA_SYNC_CACHE.run_pending_tasks();
+    __COLD_MOKA_SYNC_CACHE.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn sync_cache_cache_sync() {
+    __COLD_MOKA_SYNC_CACHE.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub async fn sync_cache_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_sync_cache(&inp);
+            if __COLD_MOKA_SYNC_CACHE.contains_key(&key) {
+                return true;
+            }
+            let _ = sync_cache(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<Result<i32, i32>>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_CACHE_ERRORS: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32), Result<i32, i32>>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder()
+        .max_capacity(1000u64)
+        .expire_after(
+            ::cold_moka::ErrorExpiry::new(
+                ::std::option::Option::Some(::std::time::Duration::from_secs(60u64)),
+                ::std::time::Duration::from_secs(5u64),
+                |value: &Result<i32, i32>| value.is_err(),
+            ),
+        )
+        .build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_cache_errors(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub async fn cache_errors(inp: i32) -> Result<i32, i32> {
+    async fn __cold_moka_inner_cache_errors(inp: i32) -> Result<i32, i32> {
+        Ok(inp)
+    }
+    let key = __cold_moka_key_cache_errors(&inp);
+    __COLD_MOKA_CACHE_ERRORS
+        .get_with_by_ref(&key, __cold_moka_inner_cache_errors(inp))
+        .await
+}
+#[allow(dead_code)]
+pub fn cache_errors_cache_clear() {
+    __COLD_MOKA_CACHE_ERRORS.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn cache_errors_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_cache_errors(&inp);
+    __COLD_MOKA_CACHE_ERRORS.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn cache_errors_cache_insert(inp: i32, value: Result<i32, i32>) {
+    let key = __cold_moka_key_cache_errors(&inp);
+    __COLD_MOKA_CACHE_ERRORS.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn cache_errors_if_cached(
+    inp: i32,
+) -> ::std::option::Option<Result<i32, i32>> {
+    let key = __cold_moka_key_cache_errors(&inp);
+    __COLD_MOKA_CACHE_ERRORS.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn cache_errors_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_cache_errors(&inp);
+    __COLD_MOKA_CACHE_ERRORS.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn cache_errors_cache_entry_count() -> u64 {
+    __COLD_MOKA_CACHE_ERRORS.entry_count()
+}
+#[allow(dead_code)]
+pub fn cache_errors_cache_weighted_size() -> u64 {
+    __COLD_MOKA_CACHE_ERRORS.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn cache_errors_cache_sync() {
+    __COLD_MOKA_CACHE_ERRORS.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn cache_errors_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_cache_errors(&inp);
+            if __COLD_MOKA_CACHE_ERRORS.contains_key(&key) {
+                return true;
+            }
+            let _ = cache_errors(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_BLOCKING: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_blocking(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub async fn blocking(inp: i32) -> Option<i32> {
+    fn __cold_moka_inner_blocking(inp: i32) -> Option<i32> {
+        Some(inp)
+    }
+    let key = __cold_moka_key_blocking(&inp);
+    __COLD_MOKA_BLOCKING
+        .optionally_get_with_by_ref(
+            &key,
+            async move {
+                match ::cold_moka::tokio::task::spawn_blocking(move || __cold_moka_inner_blocking(
+                        inp,
+                    ))
+                    .await
+                {
+                    Ok(value) => value,
+                    Err(e) => ::std::panic::resume_unwind(e.into_panic()),
+                }
+            },
+        )
+        .await
+}
+#[allow(dead_code)]
+pub fn blocking_cache_clear() {
+    __COLD_MOKA_BLOCKING.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn blocking_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_blocking(&inp);
+    __COLD_MOKA_BLOCKING.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn blocking_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_blocking(&inp);
+    __COLD_MOKA_BLOCKING.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn blocking_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_blocking(&inp);
+    __COLD_MOKA_BLOCKING.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn blocking_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_blocking(&inp);
+    __COLD_MOKA_BLOCKING.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn blocking_cache_entry_count() -> u64 {
+    __COLD_MOKA_BLOCKING.entry_count()
+}
+#[allow(dead_code)]
+pub fn blocking_cache_weighted_size() -> u64 {
+    __COLD_MOKA_BLOCKING.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn blocking_cache_sync() {
+    __COLD_MOKA_BLOCKING.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn blocking_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_blocking(&inp);
+            if __COLD_MOKA_BLOCKING.contains_key(&key) {
+                return true;
+            }
+            let _ = blocking(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<Option<i32>>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_CACHE_NONE: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32), Option<i32>>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_cache_none(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub async fn cache_none(inp: i32) -> Option<i32> {
+    async fn __cold_moka_inner_cache_none(inp: i32) -> Option<i32> {
+        Some(inp)
+    }
+    let key = __cold_moka_key_cache_none(&inp);
+    __COLD_MOKA_CACHE_NONE.get_with_by_ref(&key, __cold_moka_inner_cache_none(inp)).await
+}
+#[allow(dead_code)]
+pub fn cache_none_cache_clear() {
+    __COLD_MOKA_CACHE_NONE.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn cache_none_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_cache_none(&inp);
+    __COLD_MOKA_CACHE_NONE.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn cache_none_cache_insert(inp: i32, value: Option<i32>) {
+    let key = __cold_moka_key_cache_none(&inp);
+    __COLD_MOKA_CACHE_NONE.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn cache_none_if_cached(inp: i32) -> ::std::option::Option<Option<i32>> {
+    let key = __cold_moka_key_cache_none(&inp);
+    __COLD_MOKA_CACHE_NONE.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn cache_none_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_cache_none(&inp);
+    __COLD_MOKA_CACHE_NONE.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn cache_none_cache_entry_count() -> u64 {
+    __COLD_MOKA_CACHE_NONE.entry_count()
+}
+#[allow(dead_code)]
+pub fn cache_none_cache_weighted_size() -> u64 {
+    __COLD_MOKA_CACHE_NONE.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn cache_none_cache_sync() {
+    __COLD_MOKA_CACHE_NONE.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn cache_none_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_cache_none(&inp);
+            if __COLD_MOKA_CACHE_NONE.contains_key(&key) {
+                return true;
+            }
+            let _ = cache_none(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_RESULT_OPTION: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_result_option(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub async fn result_option(inp: i32) -> Result<Option<i32>, i32> {
+    async fn __cold_moka_inner_result_option(inp: i32) -> Result<Option<i32>, i32> {
+        Ok(Some(inp))
+    }
+    let key = __cold_moka_key_result_option(&inp);
+    match __COLD_MOKA_RESULT_OPTION
+        .try_get_with_by_ref(
+            &key,
+            async move {
+                match __cold_moka_inner_result_option(inp).await {
+                    Ok(Some(value)) => Ok(value),
+                    Ok(None) => Err(None),
+                    Err(e) => Err(Some(::std::sync::Arc::new(e))),
+                }
+            },
+        )
+        .await
+    {
+        Ok(v) => Ok(Some(v)),
+        Err(shared) => {
+            match &*shared {
+                None => Ok(None),
+                Some(e) => {
+                    let e = ::std::sync::Arc::clone(e);
+                    Err({
+                        #[allow(unused_imports)]
+                        use ::cold_moka::{CloneArcError as _, FromArcError as _};
+                        (&&::cold_moka::ArcError::new(e)).into_error()
+                    })
+                }
+            }
+        }
+    }
+}
+#[allow(dead_code)]
+pub fn result_option_cache_clear() {
+    __COLD_MOKA_RESULT_OPTION.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn result_option_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_result_option(&inp);
+    __COLD_MOKA_RESULT_OPTION.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn result_option_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_result_option(&inp);
+    __COLD_MOKA_RESULT_OPTION.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn result_option_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_result_option(&inp);
+    __COLD_MOKA_RESULT_OPTION.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn result_option_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_result_option(&inp);
+    __COLD_MOKA_RESULT_OPTION.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn result_option_cache_entry_count() -> u64 {
+    __COLD_MOKA_RESULT_OPTION.entry_count()
+}
+#[allow(dead_code)]
+pub fn result_option_cache_weighted_size() -> u64 {
+    __COLD_MOKA_RESULT_OPTION.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn result_option_cache_sync() {
+    __COLD_MOKA_RESULT_OPTION.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn result_option_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_result_option(&inp);
+            if __COLD_MOKA_RESULT_OPTION.contains_key(&key) {
+                return true;
+            }
+            let _ = result_option(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_WRAP_RETURN: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_wrap_return(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub async fn wrap_return(inp: i32) -> Result<cold_moka::Return<i32>, i32> {
+    async fn __cold_moka_inner_wrap_return(
+        inp: i32,
+    ) -> Result<cold_moka::Return<i32>, i32> {
+        Ok(cold_moka::Return::new(inp))
+    }
+    let key = __cold_moka_key_wrap_return(&inp);
+    let was_cached = ::std::cell::Cell::new(false);
+    let result = {
+        match __COLD_MOKA_WRAP_RETURN
+            .entry_by_ref(&key)
+            .or_try_insert_with(async move {
+                __cold_moka_inner_wrap_return(inp).await.map(|value| value.value)
+            })
+            .await
+        {
+            Ok(entry) => {
+                let fresh = entry.is_fresh();
+                was_cached.set(!fresh);
+                Ok(entry.into_value())
+            }
+            Err(e) => {
+                let fresh = true;
+                was_cached.set(!fresh);
+                Err({
+                    #[allow(unused_imports)]
+                    use ::cold_moka::{CloneArcError as _, FromArcError as _};
+                    (&&::cold_moka::ArcError::new(e)).into_error()
+                })
+            }
+        }
+    };
+    result
+        .map(|value| ::cold_moka::Return {
+            value,
+            was_cached: was_cached.get(),
+        })
+}
+#[allow(dead_code)]
+pub fn wrap_return_cache_clear() {
+    __COLD_MOKA_WRAP_RETURN.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn wrap_return_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_wrap_return(&inp);
+    __COLD_MOKA_WRAP_RETURN.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn wrap_return_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_wrap_return(&inp);
+    __COLD_MOKA_WRAP_RETURN.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn wrap_return_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_wrap_return(&inp);
+    __COLD_MOKA_WRAP_RETURN.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn wrap_return_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_wrap_return(&inp);
+    __COLD_MOKA_WRAP_RETURN.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn wrap_return_cache_entry_count() -> u64 {
+    __COLD_MOKA_WRAP_RETURN.entry_count()
+}
+#[allow(dead_code)]
+pub fn wrap_return_cache_weighted_size() -> u64 {
+    __COLD_MOKA_WRAP_RETURN.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn wrap_return_cache_sync() {
+    __COLD_MOKA_WRAP_RETURN.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn wrap_return_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_wrap_return(&inp);
+            if __COLD_MOKA_WRAP_RETURN.contains_key(&key) {
+                return true;
+            }
+            let _ = wrap_return(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+pub fn hook(_: &i32) {}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_LOOKUP_HOOKS: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_lookup_hooks(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub async fn lookup_hooks(inp: i32) -> i32 {
+    async fn __cold_moka_inner_lookup_hooks(inp: i32) -> i32 {
+        inp
+    }
+    let key = __cold_moka_key_lookup_hooks(&inp);
+    let entry = __COLD_MOKA_LOOKUP_HOOKS
+        .entry_by_ref(&key)
+        .or_insert_with(__cold_moka_inner_lookup_hooks(inp))
+        .await;
+    let fresh = entry.is_fresh();
+    if fresh {
+        hook(&key);
+    } else {
+        hook(&key);
+    }
+    entry.into_value()
+}
+#[allow(dead_code)]
+pub fn lookup_hooks_cache_clear() {
+    __COLD_MOKA_LOOKUP_HOOKS.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn lookup_hooks_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_lookup_hooks(&inp);
+    __COLD_MOKA_LOOKUP_HOOKS.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn lookup_hooks_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_lookup_hooks(&inp);
+    __COLD_MOKA_LOOKUP_HOOKS.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn lookup_hooks_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_lookup_hooks(&inp);
+    __COLD_MOKA_LOOKUP_HOOKS.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn lookup_hooks_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_lookup_hooks(&inp);
+    __COLD_MOKA_LOOKUP_HOOKS.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn lookup_hooks_cache_entry_count() -> u64 {
+    __COLD_MOKA_LOOKUP_HOOKS.entry_count()
+}
+#[allow(dead_code)]
+pub fn lookup_hooks_cache_weighted_size() -> u64 {
+    __COLD_MOKA_LOOKUP_HOOKS.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn lookup_hooks_cache_sync() {
+    __COLD_MOKA_LOOKUP_HOOKS.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn lookup_hooks_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_lookup_hooks(&inp);
+            if __COLD_MOKA_LOOKUP_HOOKS.contains_key(&key) {
+                return true;
+            }
+            let _ = lookup_hooks(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<std::sync::Arc<i32>>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_RETURNS_ARC: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32), std::sync::Arc<i32>>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_returns_arc(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub async fn returns_arc(inp: i32) -> std::sync::Arc<i32> {
+    async fn __cold_moka_inner_returns_arc(inp: i32) -> std::sync::Arc<i32> {
+        std::sync::Arc::new(inp)
+    }
+    let key = __cold_moka_key_returns_arc(&inp);
+    __COLD_MOKA_RETURNS_ARC
+        .get_with_by_ref(&key, __cold_moka_inner_returns_arc(inp))
+        .await
+}
+#[allow(dead_code)]
+pub fn returns_arc_cache_clear() {
+    __COLD_MOKA_RETURNS_ARC.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn returns_arc_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_returns_arc(&inp);
+    __COLD_MOKA_RETURNS_ARC.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn returns_arc_cache_insert(inp: i32, value: std::sync::Arc<i32>) {
+    let key = __cold_moka_key_returns_arc(&inp);
+    __COLD_MOKA_RETURNS_ARC.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn returns_arc_if_cached(
+    inp: i32,
+) -> ::std::option::Option<std::sync::Arc<i32>> {
+    let key = __cold_moka_key_returns_arc(&inp);
+    __COLD_MOKA_RETURNS_ARC.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn returns_arc_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_returns_arc(&inp);
+    __COLD_MOKA_RETURNS_ARC.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn returns_arc_cache_entry_count() -> u64 {
+    __COLD_MOKA_RETURNS_ARC.entry_count()
+}
+#[allow(dead_code)]
+pub fn returns_arc_cache_weighted_size() -> u64 {
+    __COLD_MOKA_RETURNS_ARC.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn returns_arc_cache_sync() {
+    __COLD_MOKA_RETURNS_ARC.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn returns_arc_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_returns_arc(&inp);
+            if __COLD_MOKA_RETURNS_ARC.contains_key(&key) {
+                return true;
+            }
+            let _ = returns_arc(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<std::sync::Arc<i32>>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_RETURNS_ARC_RESULT: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32), std::sync::Arc<i32>>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_returns_arc_result(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub async fn returns_arc_result(inp: i32) -> Result<std::sync::Arc<i32>, i32> {
+    async fn __cold_moka_inner_returns_arc_result(
+        inp: i32,
+    ) -> Result<std::sync::Arc<i32>, i32> {
+        Ok(std::sync::Arc::new(inp))
+    }
+    let key = __cold_moka_key_returns_arc_result(&inp);
+    let result = __COLD_MOKA_RETURNS_ARC_RESULT
+        .try_get_with_by_ref(&key, __cold_moka_inner_returns_arc_result(inp))
+        .await;
+    match result {
+        Ok(v) => Ok(v),
+        Err(e) => {
+            Err({
+                #[allow(unused_imports)]
+                use ::cold_moka::{CloneArcError as _, FromArcError as _};
+                (&&::cold_moka::ArcError::new(e)).into_error()
+            })
+        }
+    }
+}
+#[allow(dead_code)]
+pub fn returns_arc_result_cache_clear() {
+    __COLD_MOKA_RETURNS_ARC_RESULT.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn returns_arc_result_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_returns_arc_result(&inp);
+    __COLD_MOKA_RETURNS_ARC_RESULT.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn returns_arc_result_cache_insert(inp: i32, value: std::sync::Arc<i32>) {
+    let key = __cold_moka_key_returns_arc_result(&inp);
+    __COLD_MOKA_RETURNS_ARC_RESULT.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn returns_arc_result_if_cached(
+    inp: i32,
+) -> ::std::option::Option<std::sync::Arc<i32>> {
+    let key = __cold_moka_key_returns_arc_result(&inp);
+    __COLD_MOKA_RETURNS_ARC_RESULT.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn returns_arc_result_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_returns_arc_result(&inp);
+    __COLD_MOKA_RETURNS_ARC_RESULT.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn returns_arc_result_cache_entry_count() -> u64 {
+    __COLD_MOKA_RETURNS_ARC_RESULT.entry_count()
+}
+#[allow(dead_code)]
+pub fn returns_arc_result_cache_weighted_size() -> u64 {
+    __COLD_MOKA_RETURNS_ARC_RESULT.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn returns_arc_result_cache_sync() {
+    __COLD_MOKA_RETURNS_ARC_RESULT.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn returns_arc_result_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_returns_arc_result(&inp);
+            if __COLD_MOKA_RETURNS_ARC_RESULT.contains_key(&key) {
+                return true;
+            }
+            let _ = returns_arc_result(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<std::sync::Arc<i32>>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_RETURNS_ARC_OPTION: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32), std::sync::Arc<i32>>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_returns_arc_option(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub async fn returns_arc_option(inp: i32) -> Option<std::sync::Arc<i32>> {
+    async fn __cold_moka_inner_returns_arc_option(
+        inp: i32,
+    ) -> Option<std::sync::Arc<i32>> {
+        Some(std::sync::Arc::new(inp))
+    }
+    let key = __cold_moka_key_returns_arc_option(&inp);
+    __COLD_MOKA_RETURNS_ARC_OPTION
+        .optionally_get_with_by_ref(&key, __cold_moka_inner_returns_arc_option(inp))
+        .await
+}
+#[allow(dead_code)]
+pub fn returns_arc_option_cache_clear() {
+    __COLD_MOKA_RETURNS_ARC_OPTION.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn returns_arc_option_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_returns_arc_option(&inp);
+    __COLD_MOKA_RETURNS_ARC_OPTION.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn returns_arc_option_cache_insert(inp: i32, value: std::sync::Arc<i32>) {
+    let key = __cold_moka_key_returns_arc_option(&inp);
+    __COLD_MOKA_RETURNS_ARC_OPTION.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn returns_arc_option_if_cached(
+    inp: i32,
+) -> ::std::option::Option<std::sync::Arc<i32>> {
+    let key = __cold_moka_key_returns_arc_option(&inp);
+    __COLD_MOKA_RETURNS_ARC_OPTION.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn returns_arc_option_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_returns_arc_option(&inp);
+    __COLD_MOKA_RETURNS_ARC_OPTION.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn returns_arc_option_cache_entry_count() -> u64 {
+    __COLD_MOKA_RETURNS_ARC_OPTION.entry_count()
+}
+#[allow(dead_code)]
+pub fn returns_arc_option_cache_weighted_size() -> u64 {
+    __COLD_MOKA_RETURNS_ARC_OPTION.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn returns_arc_option_cache_sync() {
+    __COLD_MOKA_RETURNS_ARC_OPTION.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn returns_arc_option_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_returns_arc_option(&inp);
+            if __COLD_MOKA_RETURNS_ARC_OPTION.contains_key(&key) {
+                return true;
+            }
+            let _ = returns_arc_option(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_RESULT_FALLBACK_STALE: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::StaleValues<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| ::cold_moka::StaleValues::new(1000usize));
+static __COLD_MOKA_RESULT_FALLBACK: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder()
+        .max_capacity(1000u64)
+        .time_to_live(::std::time::Duration::from_secs(60u64))
+        .eviction_listener(|
+            key: ::std::sync::Arc<(i32)>,
+            value: i32,
+            cause: ::cold_moka::moka::notification::RemovalCause|
+        {
+            match cause {
+                ::cold_moka::moka::notification::RemovalCause::Expired
+                | ::cold_moka::moka::notification::RemovalCause::Size => {
+                    __COLD_MOKA_RESULT_FALLBACK_STALE.keep((*key).clone(), value)
+                }
+                ::cold_moka::moka::notification::RemovalCause::Explicit => {
+                    __COLD_MOKA_RESULT_FALLBACK_STALE.forget(&key)
+                }
+                ::cold_moka::moka::notification::RemovalCause::Replaced => {}
+            }
+        })
+        .build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_result_fallback(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub async fn result_fallback(inp: i32) -> Result<i32, i32> {
+    async fn __cold_moka_inner_result_fallback(inp: i32) -> Result<i32, i32> {
+        Ok(inp)
+    }
+    let key = __cold_moka_key_result_fallback(&inp);
+    match {
+        let result = __COLD_MOKA_RESULT_FALLBACK
+            .try_get_with_by_ref(&key, __cold_moka_inner_result_fallback(inp))
+            .await;
+        match result {
+            Ok(v) => Ok(v),
+            Err(e) => {
+                Err({
+                    #[allow(unused_imports)]
+                    use ::cold_moka::{CloneArcError as _, FromArcError as _};
+                    (&&::cold_moka::ArcError::new(e)).into_error()
+                })
+            }
+        }
+    } {
+        Err(e) => {
+            match __COLD_MOKA_RESULT_FALLBACK
+                .get(&key)
+                .await
+                .or_else(|| __COLD_MOKA_RESULT_FALLBACK_STALE.get(&key))
+            {
+                Some(value) => Ok(value),
+                None => Err(e),
+            }
+        }
+        result => result,
+    }
+}
+#[allow(dead_code)]
+pub fn result_fallback_cache_clear() {
+    __COLD_MOKA_RESULT_FALLBACK.invalidate_all();
+    __COLD_MOKA_RESULT_FALLBACK_STALE.clear();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn result_fallback_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_result_fallback(&inp);
+    __COLD_MOKA_RESULT_FALLBACK_STALE.forget(&key);
+    __COLD_MOKA_RESULT_FALLBACK.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn result_fallback_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_result_fallback(&inp);
+    __COLD_MOKA_RESULT_FALLBACK.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn result_fallback_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_result_fallback(&inp);
+    __COLD_MOKA_RESULT_FALLBACK.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn result_fallback_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_result_fallback(&inp);
+    __COLD_MOKA_RESULT_FALLBACK.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn result_fallback_cache_entry_count() -> u64 {
+    __COLD_MOKA_RESULT_FALLBACK.entry_count()
+}
+#[allow(dead_code)]
+pub fn result_fallback_cache_weighted_size() -> u64 {
+    __COLD_MOKA_RESULT_FALLBACK.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn result_fallback_cache_sync() {
+    __COLD_MOKA_RESULT_FALLBACK.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn result_fallback_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_result_fallback(&inp);
+            if __COLD_MOKA_RESULT_FALLBACK.contains_key(&key) {
+                return true;
+            }
+            let _ = result_fallback(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<()>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_DEBOUNCED: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32), ()>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder()
+        .max_capacity(1000u64)
+        .time_to_live(::std::time::Duration::from_secs(600u64))
+        .build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_debounced(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub async fn debounced(inp: i32) {
+    async fn __cold_moka_inner_debounced(inp: i32) {
+        let _ = inp;
+    }
+    let key = __cold_moka_key_debounced(&inp);
+    __COLD_MOKA_DEBOUNCED.get_with_by_ref(&key, __cold_moka_inner_debounced(inp)).await
+}
+#[allow(dead_code)]
+pub fn debounced_cache_clear() {
+    __COLD_MOKA_DEBOUNCED.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn debounced_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_debounced(&inp);
+    __COLD_MOKA_DEBOUNCED.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn debounced_cache_insert(inp: i32, value: ()) {
+    let key = __cold_moka_key_debounced(&inp);
+    __COLD_MOKA_DEBOUNCED.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn debounced_if_cached(inp: i32) -> ::std::option::Option<()> {
+    let key = __cold_moka_key_debounced(&inp);
+    __COLD_MOKA_DEBOUNCED.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn debounced_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_debounced(&inp);
+    __COLD_MOKA_DEBOUNCED.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn debounced_cache_entry_count() -> u64 {
+    __COLD_MOKA_DEBOUNCED.entry_count()
+}
+#[allow(dead_code)]
+pub fn debounced_cache_weighted_size() -> u64 {
+    __COLD_MOKA_DEBOUNCED.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn debounced_cache_sync() {
+    __COLD_MOKA_DEBOUNCED.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn debounced_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_debounced(&inp);
+            if __COLD_MOKA_DEBOUNCED.contains_key(&key) {
+                return true;
+            }
+            let _ = debounced(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_CACHE_IF: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_cache_if(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub async fn cache_if(inp: i32) -> Result<i32, i32> {
+    async fn __cold_moka_inner_cache_if(inp: i32) -> Result<i32, i32> {
+        Ok(inp)
+    }
+    let key = __cold_moka_key_cache_if(&inp);
+    let result = {
+        let result = __COLD_MOKA_CACHE_IF
+            .try_get_with_by_ref(&key, __cold_moka_inner_cache_if(inp))
+            .await;
+        match result {
+            Ok(v) => Ok(v),
+            Err(e) => {
+                Err({
+                    #[allow(unused_imports)]
+                    use ::cold_moka::{CloneArcError as _, FromArcError as _};
+                    (&&::cold_moka::ArcError::new(e)).into_error()
+                })
+            }
+        }
+    };
+    if let Ok(value) = &result {
+        if !(|v: &i32| *v > 0)(value) {
+            __COLD_MOKA_CACHE_IF.invalidate(&key).await;
+        }
+    }
+    result
+}
+#[allow(dead_code)]
+pub fn cache_if_cache_clear() {
+    __COLD_MOKA_CACHE_IF.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn cache_if_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_cache_if(&inp);
+    __COLD_MOKA_CACHE_IF.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn cache_if_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_cache_if(&inp);
+    __COLD_MOKA_CACHE_IF.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn cache_if_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_cache_if(&inp);
+    __COLD_MOKA_CACHE_IF.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn cache_if_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_cache_if(&inp);
+    __COLD_MOKA_CACHE_IF.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn cache_if_cache_entry_count() -> u64 {
+    __COLD_MOKA_CACHE_IF.entry_count()
+}
+#[allow(dead_code)]
+pub fn cache_if_cache_weighted_size() -> u64 {
+    __COLD_MOKA_CACHE_IF.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn cache_if_cache_sync() {
+    __COLD_MOKA_CACHE_IF.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn cache_if_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_cache_if(&inp);
+            if __COLD_MOKA_CACHE_IF.contains_key(&key) {
+                return true;
+            }
+            let _ = cache_if(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+    cached_key::<String>();
+};
+static __COLD_MOKA_DESTRUCTURED: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32, String), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_destructured(a: &i32, c: &String) -> (i32, String) {
+    (a.clone(), c.clone())
+}
+pub async fn destructured((a, _b): (i32, i32), c: String) -> i32 {
+    async fn __cold_moka_inner_destructured((a, _b): (i32, i32), c: String) -> i32 {
+        a + c.len() as i32
+    }
+    let key = __cold_moka_key_destructured(&a, &c);
+    __COLD_MOKA_DESTRUCTURED
+        .get_with_by_ref(&key, __cold_moka_inner_destructured((a, _b), c))
+        .await
+}
+#[allow(dead_code)]
+pub fn destructured_cache_clear() {
+    __COLD_MOKA_DESTRUCTURED.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn destructured_cache_invalidate(a: i32, c: String) {
+    let key = __cold_moka_key_destructured(&a, &c);
+    __COLD_MOKA_DESTRUCTURED.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn destructured_cache_insert(a: i32, c: String, value: i32) {
+    let key = __cold_moka_key_destructured(&a, &c);
+    __COLD_MOKA_DESTRUCTURED.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn destructured_if_cached(a: i32, c: String) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_destructured(&a, &c);
+    __COLD_MOKA_DESTRUCTURED.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn destructured_cache_contains(a: i32, c: String) -> bool {
+    let key = __cold_moka_key_destructured(&a, &c);
+    __COLD_MOKA_DESTRUCTURED.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn destructured_cache_entry_count() -> u64 {
+    __COLD_MOKA_DESTRUCTURED.entry_count()
+}
+#[allow(dead_code)]
+pub fn destructured_cache_weighted_size() -> u64 {
+    __COLD_MOKA_DESTRUCTURED.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn destructured_cache_sync() {
+    __COLD_MOKA_DESTRUCTURED.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn destructured_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = ((i32, i32), String)>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|((a, _b), c)| async move {
+            let key = __cold_moka_key_destructured(&a, &c);
+            if __COLD_MOKA_DESTRUCTURED.contains_key(&key) {
+                return true;
+            }
+            let _ = destructured((a, _b), c).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<(i32, std::time::Duration)>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_VALUE_TTL: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32), (i32, std::time::Duration)>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder()
+        .max_capacity(1000u64)
+        .expire_after(::cold_moka::ValueTtl)
+        .build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_value_ttl(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub async fn value_ttl(inp: i32) -> (i32, std::time::Duration) {
+    async fn __cold_moka_inner_value_ttl(inp: i32) -> (i32, std::time::Duration) {
+        (inp, std::time::Duration::from_secs(60))
+    }
+    let key = __cold_moka_key_value_ttl(&inp);
+    __COLD_MOKA_VALUE_TTL.get_with_by_ref(&key, __cold_moka_inner_value_ttl(inp)).await
+}
+#[allow(dead_code)]
+pub fn value_ttl_cache_clear() {
+    __COLD_MOKA_VALUE_TTL.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn value_ttl_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_value_ttl(&inp);
+    __COLD_MOKA_VALUE_TTL.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn value_ttl_cache_insert(inp: i32, value: (i32, std::time::Duration)) {
+    let key = __cold_moka_key_value_ttl(&inp);
+    __COLD_MOKA_VALUE_TTL.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn value_ttl_if_cached(
+    inp: i32,
+) -> ::std::option::Option<(i32, std::time::Duration)> {
+    let key = __cold_moka_key_value_ttl(&inp);
+    __COLD_MOKA_VALUE_TTL.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn value_ttl_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_value_ttl(&inp);
+    __COLD_MOKA_VALUE_TTL.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn value_ttl_cache_entry_count() -> u64 {
+    __COLD_MOKA_VALUE_TTL.entry_count()
+}
+#[allow(dead_code)]
+pub fn value_ttl_cache_weighted_size() -> u64 {
+    __COLD_MOKA_VALUE_TTL.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn value_ttl_cache_sync() {
+    __COLD_MOKA_VALUE_TTL.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn value_ttl_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_value_ttl(&inp);
+            if __COLD_MOKA_VALUE_TTL.contains_key(&key) {
+                return true;
+            }
+            let _ = value_ttl(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<String>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_KEY_ORDER: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(String, i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_key_order(b: &String, a: &i32) -> (String, i32) {
+    (b.clone(), a.clone())
+}
+pub async fn key_order(a: i32, b: String) -> i32 {
+    async fn __cold_moka_inner_key_order(a: i32, b: String) -> i32 {
+        a + b.len() as i32
+    }
+    let key = __cold_moka_key_key_order(&b, &a);
+    __COLD_MOKA_KEY_ORDER.get_with_by_ref(&key, __cold_moka_inner_key_order(a, b)).await
+}
+#[allow(dead_code)]
+pub fn key_order_cache_clear() {
+    __COLD_MOKA_KEY_ORDER.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn key_order_cache_invalidate(b: String, a: i32) {
+    let key = __cold_moka_key_key_order(&b, &a);
+    __COLD_MOKA_KEY_ORDER.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn key_order_cache_insert(b: String, a: i32, value: i32) {
+    let key = __cold_moka_key_key_order(&b, &a);
+    __COLD_MOKA_KEY_ORDER.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn key_order_if_cached(b: String, a: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_key_order(&b, &a);
+    __COLD_MOKA_KEY_ORDER.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn key_order_cache_contains(b: String, a: i32) -> bool {
+    let key = __cold_moka_key_key_order(&b, &a);
+    __COLD_MOKA_KEY_ORDER.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn key_order_cache_entry_count() -> u64 {
+    __COLD_MOKA_KEY_ORDER.entry_count()
+}
+#[allow(dead_code)]
+pub fn key_order_cache_weighted_size() -> u64 {
+    __COLD_MOKA_KEY_ORDER.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn key_order_cache_sync() {
+    __COLD_MOKA_KEY_ORDER.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn key_order_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (i32, String)>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|(a, b)| async move {
+            let key = __cold_moka_key_key_order(&b, &a);
+            if __COLD_MOKA_KEY_ORDER.contains_key(&key) {
+                return true;
+            }
+            let _ = key_order(a, b).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+pub static ENABLED: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(
+    true,
+);
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_ENABLED: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_enabled(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub async fn enabled(inp: i32) -> i32 {
+    async fn __cold_moka_inner_enabled(inp: i32) -> i32 {
+        inp
+    }
+    let key = __cold_moka_key_enabled(&inp);
+    if !::cold_moka::is_enabled(&ENABLED) {
+        return __cold_moka_inner_enabled(inp).await;
+    }
+    __COLD_MOKA_ENABLED.get_with_by_ref(&key, __cold_moka_inner_enabled(inp)).await
+}
+#[allow(dead_code)]
+pub fn enabled_cache_clear() {
+    __COLD_MOKA_ENABLED.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn enabled_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_enabled(&inp);
+    __COLD_MOKA_ENABLED.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn enabled_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_enabled(&inp);
+    __COLD_MOKA_ENABLED.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn enabled_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_enabled(&inp);
+    __COLD_MOKA_ENABLED.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn enabled_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_enabled(&inp);
+    __COLD_MOKA_ENABLED.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn enabled_cache_entry_count() -> u64 {
+    __COLD_MOKA_ENABLED.entry_count()
+}
+#[allow(dead_code)]
+pub fn enabled_cache_weighted_size() -> u64 {
+    __COLD_MOKA_ENABLED.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn enabled_cache_sync() {
+    __COLD_MOKA_ENABLED.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn enabled_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_enabled(&inp);
+            if __COLD_MOKA_ENABLED.contains_key(&key) {
+                return true;
+            }
+            let _ = enabled(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<u128>();
+    cached_key::<u128>();
+};
+static __COLD_MOKA_NO_HASH_1_ARG: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(u128), u128>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_no_hash_1_arg(arg: &u128) -> (u128) {
+    (arg.clone())
+}
+pub async fn no_hash_1_arg(_ctx: NoHash, arg: u128) -> u128 {
+    async fn __cold_moka_inner_no_hash_1_arg(_ctx: NoHash, arg: u128) -> u128 {
+        arg
+    }
+    let key = __cold_moka_key_no_hash_1_arg(&arg);
+    __COLD_MOKA_NO_HASH_1_ARG
+        .get_with_by_ref(&key, __cold_moka_inner_no_hash_1_arg(_ctx, arg))
+        .await
+}
+#[allow(dead_code)]
+pub fn no_hash_1_arg_cache_clear() {
+    __COLD_MOKA_NO_HASH_1_ARG.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn no_hash_1_arg_cache_invalidate(arg: u128) {
+    let key = __cold_moka_key_no_hash_1_arg(&arg);
+    __COLD_MOKA_NO_HASH_1_ARG.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn no_hash_1_arg_cache_insert(arg: u128, value: u128) {
+    let key = __cold_moka_key_no_hash_1_arg(&arg);
+    __COLD_MOKA_NO_HASH_1_ARG.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn no_hash_1_arg_if_cached(arg: u128) -> ::std::option::Option<u128> {
+    let key = __cold_moka_key_no_hash_1_arg(&arg);
+    __COLD_MOKA_NO_HASH_1_ARG.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn no_hash_1_arg_cache_contains(arg: u128) -> bool {
+    let key = __cold_moka_key_no_hash_1_arg(&arg);
+    __COLD_MOKA_NO_HASH_1_ARG.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn no_hash_1_arg_cache_entry_count() -> u64 {
+    __COLD_MOKA_NO_HASH_1_ARG.entry_count()
+}
+#[allow(dead_code)]
+pub fn no_hash_1_arg_cache_weighted_size() -> u64 {
+    __COLD_MOKA_NO_HASH_1_ARG.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn no_hash_1_arg_cache_sync() {
+    __COLD_MOKA_NO_HASH_1_ARG.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn no_hash_1_arg_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (NoHash, u128)>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|(_ctx, arg)| async move {
+            let key = __cold_moka_key_no_hash_1_arg(&arg);
+            if __COLD_MOKA_NO_HASH_1_ARG.contains_key(&key) {
+                return true;
+            }
+            let _ = no_hash_1_arg(_ctx, arg).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<u128>();
+    cached_key::<u128>();
+    cached_key::<u128>();
+};
+static __COLD_MOKA_NO_HASH_2_ARGS: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(u128, u128), u128>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_no_hash_2_args(arg1: &u128, arg2: &u128) -> (u128, u128) {
+    (arg1.clone(), arg2.clone())
+}
+pub async fn no_hash_2_args(_ctx: NoHash, arg1: u128, arg2: u128) -> u128 {
+    async fn __cold_moka_inner_no_hash_2_args(
+        _ctx: NoHash,
+        arg1: u128,
+        arg2: u128,
+    ) -> u128 {
+        arg1 + arg2
+    }
+    let key = __cold_moka_key_no_hash_2_args(&arg1, &arg2);
+    __COLD_MOKA_NO_HASH_2_ARGS
+        .get_with_by_ref(&key, __cold_moka_inner_no_hash_2_args(_ctx, arg1, arg2))
+        .await
+}
+#[allow(dead_code)]
+pub fn no_hash_2_args_cache_clear() {
+    __COLD_MOKA_NO_HASH_2_ARGS.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn no_hash_2_args_cache_invalidate(arg1: u128, arg2: u128) {
+    let key = __cold_moka_key_no_hash_2_args(&arg1, &arg2);
+    __COLD_MOKA_NO_HASH_2_ARGS.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn no_hash_2_args_cache_insert(arg1: u128, arg2: u128, value: u128) {
+    let key = __cold_moka_key_no_hash_2_args(&arg1, &arg2);
+    __COLD_MOKA_NO_HASH_2_ARGS.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn no_hash_2_args_if_cached(
+    arg1: u128,
+    arg2: u128,
+) -> ::std::option::Option<u128> {
+    let key = __cold_moka_key_no_hash_2_args(&arg1, &arg2);
+    __COLD_MOKA_NO_HASH_2_ARGS.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn no_hash_2_args_cache_contains(arg1: u128, arg2: u128) -> bool {
+    let key = __cold_moka_key_no_hash_2_args(&arg1, &arg2);
+    __COLD_MOKA_NO_HASH_2_ARGS.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn no_hash_2_args_cache_entry_count() -> u64 {
+    __COLD_MOKA_NO_HASH_2_ARGS.entry_count()
+}
+#[allow(dead_code)]
+pub fn no_hash_2_args_cache_weighted_size() -> u64 {
+    __COLD_MOKA_NO_HASH_2_ARGS.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn no_hash_2_args_cache_sync() {
+    __COLD_MOKA_NO_HASH_2_ARGS.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn no_hash_2_args_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (NoHash, u128, u128)>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|(_ctx, arg1, arg2)| async move {
+            let key = __cold_moka_key_no_hash_2_args(&arg1, &arg2);
+            if __COLD_MOKA_NO_HASH_2_ARGS.contains_key(&key) {
+                return true;
+            }
+            let _ = no_hash_2_args(_ctx, arg1, arg2).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<String>();
+    cached_key::<String>();
+};
+static __COLD_MOKA_DESTRUCTURED_SUBSET: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(String), String>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_destructured_subset(b: &String) -> (String) {
+    (b.clone())
+}
+pub async fn destructured_subset(_ctx: NoHash, (a, b): (u8, String)) -> String {
+    async fn __cold_moka_inner_destructured_subset(
+        _ctx: NoHash,
+        (a, b): (u8, String),
+    ) -> String {
+        ::alloc::__export::must_use({
+            ::alloc::fmt::format(format_args!("{0}{1}", a, b))
+        })
+    }
+    let key = __cold_moka_key_destructured_subset(&b);
+    __COLD_MOKA_DESTRUCTURED_SUBSET
+        .get_with_by_ref(&key, __cold_moka_inner_destructured_subset(_ctx, (a, b)))
+        .await
+}
+#[allow(dead_code)]
+pub fn destructured_subset_cache_clear() {
+    __COLD_MOKA_DESTRUCTURED_SUBSET.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn destructured_subset_cache_invalidate(b: String) {
+    let key = __cold_moka_key_destructured_subset(&b);
+    __COLD_MOKA_DESTRUCTURED_SUBSET.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn destructured_subset_cache_insert(b: String, value: String) {
+    let key = __cold_moka_key_destructured_subset(&b);
+    __COLD_MOKA_DESTRUCTURED_SUBSET.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn destructured_subset_if_cached(b: String) -> ::std::option::Option<String> {
+    let key = __cold_moka_key_destructured_subset(&b);
+    __COLD_MOKA_DESTRUCTURED_SUBSET.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn destructured_subset_cache_contains(b: String) -> bool {
+    let key = __cold_moka_key_destructured_subset(&b);
+    __COLD_MOKA_DESTRUCTURED_SUBSET.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn destructured_subset_cache_entry_count() -> u64 {
+    __COLD_MOKA_DESTRUCTURED_SUBSET.entry_count()
+}
+#[allow(dead_code)]
+pub fn destructured_subset_cache_weighted_size() -> u64 {
+    __COLD_MOKA_DESTRUCTURED_SUBSET.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn destructured_subset_cache_sync() {
+    __COLD_MOKA_DESTRUCTURED_SUBSET.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn destructured_subset_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (NoHash, (u8, String))>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|(_ctx, (a, b))| async move {
+            let key = __cold_moka_key_destructured_subset(&b);
+            if __COLD_MOKA_DESTRUCTURED_SUBSET.contains_key(&key) {
+                return true;
+            }
+            let _ = destructured_subset(_ctx, (a, b)).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<usize>();
+    cached_key::<::std::string::String>();
+    cached_key::<::std::vec::Vec<u8>>();
+    cached_key::<u64>();
+};
+static __COLD_MOKA_BORROWED_KEY: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<
+        (::std::string::String, ::std::vec::Vec<u8>, u64),
+        usize,
+    >,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_borrowed_key(
+    name: &&str,
+    bytes: &&[u8],
+    id: &&u64,
+) -> (::std::string::String, ::std::vec::Vec<u8>, u64) {
+    (
+        ::std::borrow::ToOwned::to_owned(&**name),
+        ::std::borrow::ToOwned::to_owned(&**bytes),
+        ::std::borrow::ToOwned::to_owned(&**id),
+    )
+}
+pub async fn borrowed_key(name: &str, bytes: &[u8], id: &u64) -> usize {
+    async fn __cold_moka_inner_borrowed_key(
+        name: &str,
+        bytes: &[u8],
+        id: &u64,
+    ) -> usize {
+        name.len() + bytes.len() + *id as usize
+    }
+    let key = __cold_moka_key_borrowed_key(&name, &bytes, &id);
+    __COLD_MOKA_BORROWED_KEY
+        .get_with_by_ref(&key, __cold_moka_inner_borrowed_key(name, bytes, id))
+        .await
+}
+#[allow(dead_code)]
+pub fn borrowed_key_cache_clear() {
+    __COLD_MOKA_BORROWED_KEY.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn borrowed_key_cache_invalidate(name: &str, bytes: &[u8], id: &u64) {
+    let key = __cold_moka_key_borrowed_key(&name, &bytes, &id);
+    __COLD_MOKA_BORROWED_KEY.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn borrowed_key_cache_insert(
+    name: &str,
+    bytes: &[u8],
+    id: &u64,
+    value: usize,
+) {
+    let key = __cold_moka_key_borrowed_key(&name, &bytes, &id);
+    __COLD_MOKA_BORROWED_KEY.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn borrowed_key_if_cached(
+    name: &str,
+    bytes: &[u8],
+    id: &u64,
+) -> ::std::option::Option<usize> {
+    let key = __cold_moka_key_borrowed_key(&name, &bytes, &id);
+    __COLD_MOKA_BORROWED_KEY.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn borrowed_key_cache_contains(name: &str, bytes: &[u8], id: &u64) -> bool {
+    let key = __cold_moka_key_borrowed_key(&name, &bytes, &id);
+    __COLD_MOKA_BORROWED_KEY.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn borrowed_key_cache_entry_count() -> u64 {
+    __COLD_MOKA_BORROWED_KEY.entry_count()
+}
+#[allow(dead_code)]
+pub fn borrowed_key_cache_weighted_size() -> u64 {
+    __COLD_MOKA_BORROWED_KEY.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn borrowed_key_cache_sync() {
+    __COLD_MOKA_BORROWED_KEY.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn borrowed_key_warm<'__cold_moka_input>(
+    inputs: impl ::std::iter::IntoIterator<
+        Item = (
+            &'__cold_moka_input str,
+            &'__cold_moka_input [u8],
+            &'__cold_moka_input u64,
+        ),
+    >,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|(name, bytes, id)| async move {
+            let key = __cold_moka_key_borrowed_key(&name, &bytes, &id);
+            if __COLD_MOKA_BORROWED_KEY.contains_key(&key) {
+                return true;
+            }
+            let _ = borrowed_key(name, bytes, id).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_NAMED_LIKE_LOCALS: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32, i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_named_like_locals(key: &i32, value: &i32) -> (i32, i32) {
+    (key.clone(), value.clone())
+}
+pub async fn named_like_locals(key: i32, value: i32) -> i32 {
+    async fn __cold_moka_inner_named_like_locals(key: i32, value: i32) -> i32 {
+        key + value
+    }
+    let key = __cold_moka_key_named_like_locals(&key, &value);
+    __COLD_MOKA_NAMED_LIKE_LOCALS
+        .get_with_by_ref(&key, __cold_moka_inner_named_like_locals(key, value))
+        .await
+}
+#[allow(dead_code)]
+pub fn named_like_locals_cache_clear() {
+    __COLD_MOKA_NAMED_LIKE_LOCALS.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn named_like_locals_cache_invalidate(key: i32, value: i32) {
+    let key = __cold_moka_key_named_like_locals(&key, &value);
+    __COLD_MOKA_NAMED_LIKE_LOCALS.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn named_like_locals_cache_insert(key: i32, value: i32, value: i32) {
+    let key = __cold_moka_key_named_like_locals(&key, &value);
+    __COLD_MOKA_NAMED_LIKE_LOCALS.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn named_like_locals_if_cached(
+    key: i32,
+    value: i32,
+) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_named_like_locals(&key, &value);
+    __COLD_MOKA_NAMED_LIKE_LOCALS.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn named_like_locals_cache_contains(key: i32, value: i32) -> bool {
+    let key = __cold_moka_key_named_like_locals(&key, &value);
+    __COLD_MOKA_NAMED_LIKE_LOCALS.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn named_like_locals_cache_entry_count() -> u64 {
+    __COLD_MOKA_NAMED_LIKE_LOCALS.entry_count()
+}
+#[allow(dead_code)]
+pub fn named_like_locals_cache_weighted_size() -> u64 {
+    __COLD_MOKA_NAMED_LIKE_LOCALS.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn named_like_locals_cache_sync() {
+    __COLD_MOKA_NAMED_LIKE_LOCALS.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn named_like_locals_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (i32, i32)>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|(key, value)| async move {
+            let key = __cold_moka_key_named_like_locals(&key, &value);
+            if __COLD_MOKA_NAMED_LIKE_LOCALS.contains_key(&key) {
+                return true;
+            }
+            let _ = named_like_locals(key, value).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<(i32, u8)>();
+};
+static __COLD_MOKA_CONVERT_BLOCK: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32, u8), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_convert_block(a: &i32, b: &u8) -> (i32, u8) {
+    #[allow(unused_variables)]
+    let a = ::std::clone::Clone::clone(a);
+    #[allow(unused_variables)]
+    let b = ::std::clone::Clone::clone(b);
+    { (a.abs(), b) }
+}
+pub async fn convert_block(a: i32, b: u8) -> i32 {
+    async fn __cold_moka_inner_convert_block(a: i32, b: u8) -> i32 {
+        a + b as i32
+    }
+    let key = __cold_moka_key_convert_block(&a, &b);
+    __COLD_MOKA_CONVERT_BLOCK
+        .get_with_by_ref(&key, __cold_moka_inner_convert_block(a, b))
+        .await
+}
+#[allow(dead_code)]
+pub fn convert_block_cache_clear() {
+    __COLD_MOKA_CONVERT_BLOCK.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn convert_block_cache_invalidate(a: i32, b: u8) {
+    let key = __cold_moka_key_convert_block(&a, &b);
+    __COLD_MOKA_CONVERT_BLOCK.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn convert_block_cache_insert(a: i32, b: u8, value: i32) {
+    let key = __cold_moka_key_convert_block(&a, &b);
+    __COLD_MOKA_CONVERT_BLOCK.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn convert_block_if_cached(a: i32, b: u8) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_convert_block(&a, &b);
+    __COLD_MOKA_CONVERT_BLOCK.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn convert_block_cache_contains(a: i32, b: u8) -> bool {
+    let key = __cold_moka_key_convert_block(&a, &b);
+    __COLD_MOKA_CONVERT_BLOCK.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn convert_block_cache_entry_count() -> u64 {
+    __COLD_MOKA_CONVERT_BLOCK.entry_count()
+}
+#[allow(dead_code)]
+pub fn convert_block_cache_weighted_size() -> u64 {
+    __COLD_MOKA_CONVERT_BLOCK.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn convert_block_cache_sync() {
+    __COLD_MOKA_CONVERT_BLOCK.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn convert_block_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (i32, u8)>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|(a, b)| async move {
+            let key = __cold_moka_key_convert_block(&a, &b);
+            if __COLD_MOKA_CONVERT_BLOCK.contains_key(&key) {
+                return true;
+            }
+            let _ = convert_block(a, b).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<(i32, u8)>();
+};
+static __COLD_MOKA_CONVERT_EXPR: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32, u8), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_convert_expr(a: &i32, b: &u8) -> (i32, u8) {
+    #[allow(unused_variables)]
+    let a = ::std::clone::Clone::clone(a);
+    #[allow(unused_variables)]
+    let b = ::std::clone::Clone::clone(b);
+    { (a.abs(), b) }
+}
+pub async fn convert_expr(a: i32, b: u8) -> i32 {
+    async fn __cold_moka_inner_convert_expr(a: i32, b: u8) -> i32 {
+        a + b as i32
+    }
+    let key = __cold_moka_key_convert_expr(&a, &b);
+    __COLD_MOKA_CONVERT_EXPR
+        .get_with_by_ref(&key, __cold_moka_inner_convert_expr(a, b))
+        .await
+}
+#[allow(dead_code)]
+pub fn convert_expr_cache_clear() {
+    __COLD_MOKA_CONVERT_EXPR.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn convert_expr_cache_invalidate(a: i32, b: u8) {
+    let key = __cold_moka_key_convert_expr(&a, &b);
+    __COLD_MOKA_CONVERT_EXPR.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn convert_expr_cache_insert(a: i32, b: u8, value: i32) {
+    let key = __cold_moka_key_convert_expr(&a, &b);
+    __COLD_MOKA_CONVERT_EXPR.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn convert_expr_if_cached(a: i32, b: u8) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_convert_expr(&a, &b);
+    __COLD_MOKA_CONVERT_EXPR.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn convert_expr_cache_contains(a: i32, b: u8) -> bool {
+    let key = __cold_moka_key_convert_expr(&a, &b);
+    __COLD_MOKA_CONVERT_EXPR.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn convert_expr_cache_entry_count() -> u64 {
+    __COLD_MOKA_CONVERT_EXPR.entry_count()
+}
+#[allow(dead_code)]
+pub fn convert_expr_cache_weighted_size() -> u64 {
+    __COLD_MOKA_CONVERT_EXPR.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn convert_expr_cache_sync() {
+    __COLD_MOKA_CONVERT_EXPR.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn convert_expr_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (i32, u8)>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|(a, b)| async move {
+            let key = __cold_moka_key_convert_expr(&a, &b);
+            if __COLD_MOKA_CONVERT_EXPR.contains_key(&key) {
+                return true;
+            }
+            let _ = convert_expr(a, b).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_KEY_EXPR: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<i32, i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_key_expr(a: &i32, b: &u8) -> i32 {
+    #[allow(unused_variables)]
+    let a = ::std::clone::Clone::clone(a);
+    #[allow(unused_variables)]
+    let b = ::std::clone::Clone::clone(b);
+    { a.abs() }
+}
+pub async fn key_expr(a: i32, b: u8) -> i32 {
+    async fn __cold_moka_inner_key_expr(a: i32, b: u8) -> i32 {
+        a + b as i32
+    }
+    let key = __cold_moka_key_key_expr(&a, &b);
+    __COLD_MOKA_KEY_EXPR.get_with_by_ref(&key, __cold_moka_inner_key_expr(a, b)).await
+}
+#[allow(dead_code)]
+pub fn key_expr_cache_clear() {
+    __COLD_MOKA_KEY_EXPR.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn key_expr_cache_invalidate(a: i32, b: u8) {
+    let key = __cold_moka_key_key_expr(&a, &b);
+    __COLD_MOKA_KEY_EXPR.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn key_expr_cache_insert(a: i32, b: u8, value: i32) {
+    let key = __cold_moka_key_key_expr(&a, &b);
+    __COLD_MOKA_KEY_EXPR.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn key_expr_if_cached(a: i32, b: u8) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_key_expr(&a, &b);
+    __COLD_MOKA_KEY_EXPR.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn key_expr_cache_contains(a: i32, b: u8) -> bool {
+    let key = __cold_moka_key_key_expr(&a, &b);
+    __COLD_MOKA_KEY_EXPR.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn key_expr_cache_entry_count() -> u64 {
+    __COLD_MOKA_KEY_EXPR.entry_count()
+}
+#[allow(dead_code)]
+pub fn key_expr_cache_weighted_size() -> u64 {
+    __COLD_MOKA_KEY_EXPR.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn key_expr_cache_sync() {
+    __COLD_MOKA_KEY_EXPR.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn key_expr_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (i32, u8)>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|(a, b)| async move {
+            let key = __cold_moka_key_key_expr(&a, &b);
+            if __COLD_MOKA_KEY_EXPR.contains_key(&key) {
+                return true;
+            }
+            let _ = key_expr(a, b).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+pub async fn digest(data: &str) -> usize {
+    data.len()
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<usize>();
+    cached_key::<(usize, u8)>();
+};
+static __COLD_MOKA_CONVERT_AWAIT: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(usize, u8), usize>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+async fn __cold_moka_key_convert_await(a: &String, b: &u8) -> (usize, u8) {
+    #[allow(unused_variables)]
+    let a = ::std::clone::Clone::clone(a);
+    #[allow(unused_variables)]
+    let b = ::std::clone::Clone::clone(b);
+    { (digest(&a).await, b) }
+}
+pub async fn convert_await(a: String, b: u8) -> usize {
+    async fn __cold_moka_inner_convert_await(a: String, b: u8) -> usize {
+        a.len() + b as usize
+    }
+    let key = __cold_moka_key_convert_await(&a, &b).await;
+    __COLD_MOKA_CONVERT_AWAIT
+        .get_with_by_ref(&key, __cold_moka_inner_convert_await(a, b))
+        .await
+}
+#[allow(dead_code)]
+pub fn convert_await_cache_clear() {
+    __COLD_MOKA_CONVERT_AWAIT.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn convert_await_cache_invalidate(a: String, b: u8) {
+    let key = __cold_moka_key_convert_await(&a, &b).await;
+    __COLD_MOKA_CONVERT_AWAIT.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn convert_await_cache_insert(a: String, b: u8, value: usize) {
+    let key = __cold_moka_key_convert_await(&a, &b).await;
+    __COLD_MOKA_CONVERT_AWAIT.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn convert_await_if_cached(a: String, b: u8) -> ::std::option::Option<usize> {
+    let key = __cold_moka_key_convert_await(&a, &b).await;
+    __COLD_MOKA_CONVERT_AWAIT.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub async fn convert_await_cache_contains(a: String, b: u8) -> bool {
+    let key = __cold_moka_key_convert_await(&a, &b).await;
+    __COLD_MOKA_CONVERT_AWAIT.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn convert_await_cache_entry_count() -> u64 {
+    __COLD_MOKA_CONVERT_AWAIT.entry_count()
+}
+#[allow(dead_code)]
+pub fn convert_await_cache_weighted_size() -> u64 {
+    __COLD_MOKA_CONVERT_AWAIT.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn convert_await_cache_sync() {
+    __COLD_MOKA_CONVERT_AWAIT.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn convert_await_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (String, u8)>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|(a, b)| async move {
+            let key = __cold_moka_key_convert_await(&a, &b).await;
+            if __COLD_MOKA_CONVERT_AWAIT.contains_key(&key) {
+                return true;
+            }
+            let _ = convert_await(a, b).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+pub struct CompositeId(i32, i32);
+#[automatically_derived]
+impl ::core::clone::Clone for CompositeId {
+    #[inline]
+    fn clone(&self) -> CompositeId {
+        CompositeId(
+            ::core::clone::Clone::clone(&self.0),
+            ::core::clone::Clone::clone(&self.1),
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::hash::Hash for CompositeId {
+    #[inline]
+    fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
+        ::core::hash::Hash::hash(&self.0, state);
+        ::core::hash::Hash::hash(&self.1, state)
+    }
+}
+#[automatically_derived]
+impl ::core::marker::StructuralPartialEq for CompositeId {}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for CompositeId {
+    #[inline]
+    fn eq(&self, other: &CompositeId) -> bool {
+        self.0 == other.0 && self.1 == other.1
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for CompositeId {
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_fields_are_eq(&self) {
+        let _: ::core::cmp::AssertParamIsEq<i32>;
+    }
+}
+impl CompositeId {
+    fn from_parts(a: &i32, b: &i32) -> Self {
+        CompositeId(*a, *b)
+    }
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<CompositeId>();
+};
+static __COLD_MOKA_KEY_FN: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<CompositeId, i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_key_fn(a: &i32, b: &i32) -> CompositeId {
+    CompositeId::from_parts(a, b)
+}
+pub async fn key_fn(_ctx: NoHash, a: i32, b: i32) -> i32 {
+    async fn __cold_moka_inner_key_fn(_ctx: NoHash, a: i32, b: i32) -> i32 {
+        a + b
+    }
+    let key = __cold_moka_key_key_fn(&a, &b);
+    __COLD_MOKA_KEY_FN.get_with_by_ref(&key, __cold_moka_inner_key_fn(_ctx, a, b)).await
+}
+#[allow(dead_code)]
+pub fn key_fn_cache_clear() {
+    __COLD_MOKA_KEY_FN.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn key_fn_cache_invalidate(a: i32, b: i32) {
+    let key = __cold_moka_key_key_fn(&a, &b);
+    __COLD_MOKA_KEY_FN.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn key_fn_cache_insert(a: i32, b: i32, value: i32) {
+    let key = __cold_moka_key_key_fn(&a, &b);
+    __COLD_MOKA_KEY_FN.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn key_fn_if_cached(a: i32, b: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_key_fn(&a, &b);
+    __COLD_MOKA_KEY_FN.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn key_fn_cache_contains(a: i32, b: i32) -> bool {
+    let key = __cold_moka_key_key_fn(&a, &b);
+    __COLD_MOKA_KEY_FN.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn key_fn_cache_entry_count() -> u64 {
+    __COLD_MOKA_KEY_FN.entry_count()
+}
+#[allow(dead_code)]
+pub fn key_fn_cache_weighted_size() -> u64 {
+    __COLD_MOKA_KEY_FN.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn key_fn_cache_sync() {
+    __COLD_MOKA_KEY_FN.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn key_fn_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (NoHash, i32, i32)>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|(_ctx, a, b)| async move {
+            let key = __cold_moka_key_key_fn(&a, &b);
+            if __COLD_MOKA_KEY_FN.contains_key(&key) {
+                return true;
+            }
+            let _ = key_fn(_ctx, a, b).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_SWAPPED: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::arc_swap::ArcSwap<::cold_moka::moka::future::Cache<(i32), i32>>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| ::cold_moka::arc_swap::ArcSwap::from_pointee(
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build(),
+));
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_swapped(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub async fn swapped(inp: i32) -> i32 {
+    async fn __cold_moka_inner_swapped(inp: i32) -> i32 {
+        inp
+    }
+    let key = __cold_moka_key_swapped(&inp);
+    __COLD_MOKA_SWAPPED
+        .load_full()
+        .get_with_by_ref(&key, __cold_moka_inner_swapped(inp))
+        .await
+}
+#[allow(dead_code)]
+pub fn swapped_cache_clear() {
+    __COLD_MOKA_SWAPPED.load_full().invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn swapped_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_swapped(&inp);
+    __COLD_MOKA_SWAPPED.load_full().invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn swapped_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_swapped(&inp);
+    __COLD_MOKA_SWAPPED.load_full().insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn swapped_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_swapped(&inp);
+    __COLD_MOKA_SWAPPED.load_full().get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn swapped_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_swapped(&inp);
+    __COLD_MOKA_SWAPPED.load_full().contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn swapped_cache_entry_count() -> u64 {
+    __COLD_MOKA_SWAPPED.load_full().entry_count()
+}
+#[allow(dead_code)]
+pub fn swapped_cache_weighted_size() -> u64 {
+    __COLD_MOKA_SWAPPED.load_full().weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn swapped_cache_sync() {
+    __COLD_MOKA_SWAPPED.load_full().run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn swapped_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_swapped(&inp);
+            if __COLD_MOKA_SWAPPED.load_full().contains_key(&key) {
+                return true;
+            }
+            let _ = swapped(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+#[allow(dead_code)]
+pub async fn swapped_cache_swap(
+    entries: impl ::std::iter::IntoIterator<Item = ((i32), i32)>,
+) {
+    let cache: ::cold_moka::moka::future::Cache<(i32), i32> = ::cold_moka::moka::future::Cache::builder()
+        .max_capacity(1000u64)
+        .build();
+    for (key, value) in entries {
+        cache.insert(key, value).await;
+    }
+    cache.run_pending_tasks().await;
+    __COLD_MOKA_SWAPPED.store(::std::sync::Arc::new(cache));
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_WITH_STATS_STATS: ::cold_moka::HitMissCounters = ::cold_moka::HitMissCounters::new();
+static __COLD_MOKA_WITH_STATS: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_with_stats(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub async fn with_stats(inp: i32) -> Result<i32, i32> {
+    async fn __cold_moka_inner_with_stats(inp: i32) -> Result<i32, i32> {
+        Ok(inp)
+    }
+    let key = __cold_moka_key_with_stats(&inp);
+    __COLD_MOKA_WITH_STATS_STATS.call();
+    let result = __COLD_MOKA_WITH_STATS
+        .try_get_with_by_ref(
+            &key,
+            async move {
+                __COLD_MOKA_WITH_STATS_STATS.miss();
+                __cold_moka_inner_with_stats(inp).await
+            },
+        )
+        .await;
+    match result {
+        Ok(v) => Ok(v),
+        Err(e) => {
+            Err({
+                #[allow(unused_imports)]
+                use ::cold_moka::{CloneArcError as _, FromArcError as _};
+                (&&::cold_moka::ArcError::new(e)).into_error()
+            })
+        }
+    }
+}
+#[allow(dead_code)]
+pub fn with_stats_cache_clear() {
+    __COLD_MOKA_WITH_STATS.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn with_stats_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_with_stats(&inp);
+    __COLD_MOKA_WITH_STATS.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn with_stats_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_with_stats(&inp);
+    __COLD_MOKA_WITH_STATS.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn with_stats_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_with_stats(&inp);
+    __COLD_MOKA_WITH_STATS.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn with_stats_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_with_stats(&inp);
+    __COLD_MOKA_WITH_STATS.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn with_stats_cache_entry_count() -> u64 {
+    __COLD_MOKA_WITH_STATS.entry_count()
+}
+#[allow(dead_code)]
+pub fn with_stats_cache_weighted_size() -> u64 {
+    __COLD_MOKA_WITH_STATS.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn with_stats_cache_sync() {
+    __COLD_MOKA_WITH_STATS.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn with_stats_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_with_stats(&inp);
+            if __COLD_MOKA_WITH_STATS.contains_key(&key) {
+                return true;
+            }
+            let _ = with_stats(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+#[allow(dead_code)]
+pub fn with_stats_cache_stats() -> ::cold_moka::CacheStats {
+    __COLD_MOKA_WITH_STATS_STATS.stats(__COLD_MOKA_WITH_STATS.entry_count())
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<Vec<u8>>();
+    cached_key::<usize>();
+};
+static __COLD_MOKA_OVERSIZE_BYPASS: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(usize), Vec<u8>>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder()
+        .max_capacity(100u64)
+        .weigher(|_, v: &Vec<u8>| v.len() as u32)
+        .build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_oversize_bypass(inp: &usize) -> (usize) {
+    (inp.clone())
+}
+pub async fn oversize_bypass(inp: usize) -> Result<Vec<u8>, i32> {
+    async fn __cold_moka_inner_oversize_bypass(inp: usize) -> Result<Vec<u8>, i32> {
+        Ok(::alloc::vec::from_elem(0, inp))
+    }
+    let key = __cold_moka_key_oversize_bypass(&inp);
+    if let Some(value) = __COLD_MOKA_OVERSIZE_BYPASS.get(&key).await {
+        return Ok(value);
+    }
+    async move {
+        let result = __cold_moka_inner_oversize_bypass(inp).await;
+        if let Ok(value) = &result {
+            let weight = (|_, v: &Vec<u8>| v.len() as u32)(&key, value);
+            if u64::from(weight) > 50u64 {} else {
+                __COLD_MOKA_OVERSIZE_BYPASS.insert(key, value.clone()).await;
+            }
+        }
+        result
+    }
+        .await
+}
+#[allow(dead_code)]
+pub fn oversize_bypass_cache_clear() {
+    __COLD_MOKA_OVERSIZE_BYPASS.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn oversize_bypass_cache_invalidate(inp: usize) {
+    let key = __cold_moka_key_oversize_bypass(&inp);
+    __COLD_MOKA_OVERSIZE_BYPASS.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn oversize_bypass_cache_insert(inp: usize, value: Vec<u8>) {
+    let key = __cold_moka_key_oversize_bypass(&inp);
+    __COLD_MOKA_OVERSIZE_BYPASS.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn oversize_bypass_if_cached(inp: usize) -> ::std::option::Option<Vec<u8>> {
+    let key = __cold_moka_key_oversize_bypass(&inp);
+    __COLD_MOKA_OVERSIZE_BYPASS.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn oversize_bypass_cache_contains(inp: usize) -> bool {
+    let key = __cold_moka_key_oversize_bypass(&inp);
+    __COLD_MOKA_OVERSIZE_BYPASS.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn oversize_bypass_cache_entry_count() -> u64 {
+    __COLD_MOKA_OVERSIZE_BYPASS.entry_count()
+}
+#[allow(dead_code)]
+pub fn oversize_bypass_cache_weighted_size() -> u64 {
+    __COLD_MOKA_OVERSIZE_BYPASS.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn oversize_bypass_cache_sync() {
+    __COLD_MOKA_OVERSIZE_BYPASS.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn oversize_bypass_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = usize>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_oversize_bypass(&inp);
+            if __COLD_MOKA_OVERSIZE_BYPASS.contains_key(&key) {
+                return true;
+            }
+            let _ = oversize_bypass(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_DUMPED: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_dumped(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub async fn dumped(inp: i32) -> i32 {
+    async fn __cold_moka_inner_dumped(inp: i32) -> i32 {
+        inp
+    }
+    let key = __cold_moka_key_dumped(&inp);
+    __COLD_MOKA_DUMPED.get_with_by_ref(&key, __cold_moka_inner_dumped(inp)).await
+}
+#[allow(dead_code)]
+pub fn dumped_cache_clear() {
+    __COLD_MOKA_DUMPED.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn dumped_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_dumped(&inp);
+    __COLD_MOKA_DUMPED.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn dumped_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_dumped(&inp);
+    __COLD_MOKA_DUMPED.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn dumped_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_dumped(&inp);
+    __COLD_MOKA_DUMPED.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn dumped_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_dumped(&inp);
+    __COLD_MOKA_DUMPED.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn dumped_cache_entry_count() -> u64 {
+    __COLD_MOKA_DUMPED.entry_count()
+}
+#[allow(dead_code)]
+pub fn dumped_cache_weighted_size() -> u64 {
+    __COLD_MOKA_DUMPED.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn dumped_cache_sync() {
+    __COLD_MOKA_DUMPED.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn dumped_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_dumped(&inp);
+            if __COLD_MOKA_DUMPED.contains_key(&key) {
+                return true;
+            }
+            let _ = dumped(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+#[allow(dead_code)]
+pub fn dumped_cache_dump(
+    limit: ::std::option::Option<usize>,
+) -> ::std::vec::Vec<((i32), i32)> {
+    __COLD_MOKA_DUMPED
+        .iter()
+        .take(limit.unwrap_or(usize::MAX))
+        .map(|(key, value)| ((*key).clone(), value))
+        .collect()
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<::std::sync::Arc<Vec<u8>>>();
+    cached_key::<usize>();
+};
+static __COLD_MOKA_ARC_VALUE: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(usize), ::std::sync::Arc<Vec<u8>>>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_arc_value(inp: &usize) -> (usize) {
+    (inp.clone())
+}
+pub async fn arc_value(inp: usize) -> ::std::sync::Arc<Vec<u8>> {
+    async fn __cold_moka_inner_arc_value(inp: usize) -> Vec<u8> {
+        ::alloc::vec::from_elem(0, inp)
+    }
+    let key = __cold_moka_key_arc_value(&inp);
+    __COLD_MOKA_ARC_VALUE
+        .get_with_by_ref(
+            &key,
+            async move { ::std::sync::Arc::new(__cold_moka_inner_arc_value(inp).await) },
+        )
+        .await
+}
+#[allow(dead_code)]
+pub fn arc_value_cache_clear() {
+    __COLD_MOKA_ARC_VALUE.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn arc_value_cache_invalidate(inp: usize) {
+    let key = __cold_moka_key_arc_value(&inp);
+    __COLD_MOKA_ARC_VALUE.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn arc_value_cache_insert(inp: usize, value: ::std::sync::Arc<Vec<u8>>) {
+    let key = __cold_moka_key_arc_value(&inp);
+    __COLD_MOKA_ARC_VALUE.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn arc_value_if_cached(
+    inp: usize,
+) -> ::std::option::Option<::std::sync::Arc<Vec<u8>>> {
+    let key = __cold_moka_key_arc_value(&inp);
+    __COLD_MOKA_ARC_VALUE.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn arc_value_cache_contains(inp: usize) -> bool {
+    let key = __cold_moka_key_arc_value(&inp);
+    __COLD_MOKA_ARC_VALUE.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn arc_value_cache_entry_count() -> u64 {
+    __COLD_MOKA_ARC_VALUE.entry_count()
+}
+#[allow(dead_code)]
+pub fn arc_value_cache_weighted_size() -> u64 {
+    __COLD_MOKA_ARC_VALUE.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn arc_value_cache_sync() {
+    __COLD_MOKA_ARC_VALUE.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn arc_value_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = usize>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_arc_value(&inp);
+            if __COLD_MOKA_ARC_VALUE.contains_key(&key) {
+                return true;
+            }
+            let _ = arc_value(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_FAILPOINTS: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_failpoints(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub async fn failpoints(inp: i32) -> i32 {
+    async fn __cold_moka_inner_failpoints(inp: i32) -> i32 {
+        inp
+    }
+    let key = __cold_moka_key_failpoints(&inp);
+    if ::cold_moka::failpoint("cold_moka::failpoints::before_get") {
+        return __cold_moka_inner_failpoints(inp).await;
+    }
+    __COLD_MOKA_FAILPOINTS
+        .get_with_by_ref(
+            &key,
+            async move {
+                let value = async move {
+                    let value = __cold_moka_inner_failpoints(inp).await;
+                    ::cold_moka::failpoint("cold_moka::failpoints::after_compute");
+                    value
+                }
+                    .await;
+                ::cold_moka::failpoint("cold_moka::failpoints::before_insert");
+                value
+            },
+        )
+        .await
+}
+#[allow(dead_code)]
+pub fn failpoints_cache_clear() {
+    __COLD_MOKA_FAILPOINTS.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn failpoints_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_failpoints(&inp);
+    __COLD_MOKA_FAILPOINTS.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn failpoints_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_failpoints(&inp);
+    __COLD_MOKA_FAILPOINTS.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn failpoints_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_failpoints(&inp);
+    __COLD_MOKA_FAILPOINTS.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn failpoints_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_failpoints(&inp);
+    __COLD_MOKA_FAILPOINTS.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn failpoints_cache_entry_count() -> u64 {
+    __COLD_MOKA_FAILPOINTS.entry_count()
+}
+#[allow(dead_code)]
+pub fn failpoints_cache_weighted_size() -> u64 {
+    __COLD_MOKA_FAILPOINTS.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn failpoints_cache_sync() {
+    __COLD_MOKA_FAILPOINTS.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn failpoints_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_failpoints(&inp);
+            if __COLD_MOKA_FAILPOINTS.contains_key(&key) {
+                return true;
+            }
+            let _ = failpoints(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<usize>();
+    cached_key::<u64>();
+};
+static __COLD_MOKA_KEY_HASHED: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<u64, usize>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_key_hashed(inp: &String, other: &(u8, u8)) -> u64 {
+    let mut hasher = <::cold_moka::KeyHasher as ::std::default::Default>::default();
+    ::std::hash::Hash::hash(inp, &mut hasher);
+    ::std::hash::Hash::hash(other, &mut hasher);
+    ::std::hash::Hasher::finish(&hasher)
+}
+pub async fn key_hashed(inp: String, other: (u8, u8)) -> usize {
+    async fn __cold_moka_inner_key_hashed(inp: String, other: (u8, u8)) -> usize {
+        inp.len() + other.0 as usize
+    }
+    let key = __cold_moka_key_key_hashed(&inp, &other);
+    __COLD_MOKA_KEY_HASHED
+        .get_with_by_ref(&key, __cold_moka_inner_key_hashed(inp, other))
+        .await
+}
+#[allow(dead_code)]
+pub fn key_hashed_cache_clear() {
+    __COLD_MOKA_KEY_HASHED.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn key_hashed_cache_invalidate(inp: String, other: (u8, u8)) {
+    let key = __cold_moka_key_key_hashed(&inp, &other);
+    __COLD_MOKA_KEY_HASHED.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn key_hashed_cache_insert(inp: String, other: (u8, u8), value: usize) {
+    let key = __cold_moka_key_key_hashed(&inp, &other);
+    __COLD_MOKA_KEY_HASHED.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn key_hashed_if_cached(
+    inp: String,
+    other: (u8, u8),
+) -> ::std::option::Option<usize> {
+    let key = __cold_moka_key_key_hashed(&inp, &other);
+    __COLD_MOKA_KEY_HASHED.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn key_hashed_cache_contains(inp: String, other: (u8, u8)) -> bool {
+    let key = __cold_moka_key_key_hashed(&inp, &other);
+    __COLD_MOKA_KEY_HASHED.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn key_hashed_cache_entry_count() -> u64 {
+    __COLD_MOKA_KEY_HASHED.entry_count()
+}
+#[allow(dead_code)]
+pub fn key_hashed_cache_weighted_size() -> u64 {
+    __COLD_MOKA_KEY_HASHED.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn key_hashed_cache_sync() {
+    __COLD_MOKA_KEY_HASHED.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn key_hashed_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (String, (u8, u8))>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|(inp, other)| async move {
+            let key = __cold_moka_key_key_hashed(&inp, &other);
+            if __COLD_MOKA_KEY_HASHED.contains_key(&key) {
+                return true;
+            }
+            let _ = key_hashed(inp, other).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+    cached_key::<u8>();
+};
+static __COLD_MOKA_MANY: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32, u8), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_many(inp: &i32, other: &u8) -> (i32, u8) {
+    (inp.clone(), other.clone())
+}
+pub async fn many(inp: i32, other: u8) -> Option<i32> {
+    async fn __cold_moka_inner_many(inp: i32, other: u8) -> Option<i32> {
+        Some(inp + other as i32)
+    }
+    let key = __cold_moka_key_many(&inp, &other);
+    __COLD_MOKA_MANY
+        .optionally_get_with_by_ref(&key, __cold_moka_inner_many(inp, other))
+        .await
+}
+#[allow(dead_code)]
+pub fn many_cache_clear() {
+    __COLD_MOKA_MANY.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn many_cache_invalidate(inp: i32, other: u8) {
+    let key = __cold_moka_key_many(&inp, &other);
+    __COLD_MOKA_MANY.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn many_cache_insert(inp: i32, other: u8, value: i32) {
+    let key = __cold_moka_key_many(&inp, &other);
+    __COLD_MOKA_MANY.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn many_if_cached(inp: i32, other: u8) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_many(&inp, &other);
+    __COLD_MOKA_MANY.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn many_cache_contains(inp: i32, other: u8) -> bool {
+    let key = __cold_moka_key_many(&inp, &other);
+    __COLD_MOKA_MANY.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn many_cache_entry_count() -> u64 {
+    __COLD_MOKA_MANY.entry_count()
+}
+#[allow(dead_code)]
+pub fn many_cache_weighted_size() -> u64 {
+    __COLD_MOKA_MANY.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn many_cache_sync() {
+    __COLD_MOKA_MANY.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn many_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (i32, u8)>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|(inp, other)| async move {
+            let key = __cold_moka_key_many(&inp, &other);
+            if __COLD_MOKA_MANY.contains_key(&key) {
+                return true;
+            }
+            let _ = many(inp, other).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+#[allow(dead_code)]
+pub async fn many_many(
+    inputs: impl ::std::iter::IntoIterator<Item = (i32, u8)>,
+    concurrency: usize,
+) -> ::std::vec::Vec<Option<i32>> {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|(inp, other)| many(inp, other))
+        .buffered(concurrency.max(1))
+        .collect()
+        .await
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<String>();
+    cached_key::<String>();
+};
+static __COLD_MOKA_KEY_BORROW: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(String), String>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_key_borrow(inp: &String) -> (String) {
+    (inp.clone())
+}
+pub async fn key_borrow(inp: String, other: u8) -> Result<String, String> {
+    async fn __cold_moka_inner_key_borrow(
+        inp: String,
+        other: u8,
+    ) -> Result<String, String> {
+        Ok(
+            ::alloc::__export::must_use({
+                ::alloc::fmt::format(format_args!("{0}{1}", inp, other))
+            }),
+        )
+    }
+    let key = (inp);
+    let key_ref = &key;
+    let result = __COLD_MOKA_KEY_BORROW
+        .try_get_with_by_ref(
+            &key,
+            async move {
+                __cold_moka_inner_key_borrow(::std::clone::Clone::clone(key_ref), other)
+                    .await
+            },
+        )
+        .await;
+    match result {
+        Ok(v) => Ok(v),
+        Err(e) => {
+            Err({
+                #[allow(unused_imports)]
+                use ::cold_moka::{CloneArcError as _, FromArcError as _};
+                (&&::cold_moka::ArcError::new(e)).into_error()
+            })
+        }
+    }
+}
+#[allow(dead_code)]
+pub fn key_borrow_cache_clear() {
+    __COLD_MOKA_KEY_BORROW.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn key_borrow_cache_invalidate(inp: String) {
+    let key = __cold_moka_key_key_borrow(&inp);
+    __COLD_MOKA_KEY_BORROW.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn key_borrow_cache_insert(inp: String, value: String) {
+    let key = __cold_moka_key_key_borrow(&inp);
+    __COLD_MOKA_KEY_BORROW.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn key_borrow_if_cached(inp: String) -> ::std::option::Option<String> {
+    let key = __cold_moka_key_key_borrow(&inp);
+    __COLD_MOKA_KEY_BORROW.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn key_borrow_cache_contains(inp: String) -> bool {
+    let key = __cold_moka_key_key_borrow(&inp);
+    __COLD_MOKA_KEY_BORROW.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn key_borrow_cache_entry_count() -> u64 {
+    __COLD_MOKA_KEY_BORROW.entry_count()
+}
+#[allow(dead_code)]
+pub fn key_borrow_cache_weighted_size() -> u64 {
+    __COLD_MOKA_KEY_BORROW.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn key_borrow_cache_sync() {
+    __COLD_MOKA_KEY_BORROW.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn key_borrow_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (String, u8)>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|(inp, other)| async move {
+            let key = __cold_moka_key_key_borrow(&inp);
+            if __COLD_MOKA_KEY_BORROW.contains_key(&key) {
+                return true;
+            }
+            let _ = key_borrow(inp, other).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_BYPASS: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_bypass(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub async fn bypass(inp: i32) -> i32 {
+    let key = __cold_moka_key_bypass(&inp);
+    __COLD_MOKA_BYPASS.get_with_by_ref(&key, __cold_moka_inner_bypass(inp)).await
+}
+#[allow(dead_code)]
+pub fn bypass_cache_clear() {
+    __COLD_MOKA_BYPASS.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn bypass_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_bypass(&inp);
+    __COLD_MOKA_BYPASS.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn bypass_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_bypass(&inp);
+    __COLD_MOKA_BYPASS.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn bypass_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_bypass(&inp);
+    __COLD_MOKA_BYPASS.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn bypass_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_bypass(&inp);
+    __COLD_MOKA_BYPASS.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn bypass_cache_entry_count() -> u64 {
+    __COLD_MOKA_BYPASS.entry_count()
+}
+#[allow(dead_code)]
+pub fn bypass_cache_weighted_size() -> u64 {
+    __COLD_MOKA_BYPASS.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn bypass_cache_sync() {
+    __COLD_MOKA_BYPASS.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn bypass_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_bypass(&inp);
+            if __COLD_MOKA_BYPASS.contains_key(&key) {
+                return true;
+            }
+            let _ = bypass(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+async fn __cold_moka_inner_bypass(inp: i32) -> i32 {
+    inp
+}
+#[allow(dead_code)]
+pub async fn fresh(inp: i32) -> i32 {
+    __cold_moka_inner_bypass(inp).await
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_IMPL_FUTURE: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_impl_future(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn impl_future(inp: i32) -> impl std::future::Future<Output = Option<i32>> {
+    let fut = ::cold_moka::typed_future::<
+        Option<i32>,
+        _,
+    >((|| { std::future::ready(Some(inp)) })());
+    let key = __cold_moka_key_impl_future(&inp);
+    (async move { __COLD_MOKA_IMPL_FUTURE.optionally_get_with_by_ref(&key, fut).await })
+}
+#[allow(dead_code)]
+pub fn impl_future_cache_clear() {
+    __COLD_MOKA_IMPL_FUTURE.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn impl_future_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_impl_future(&inp);
+    __COLD_MOKA_IMPL_FUTURE.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn impl_future_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_impl_future(&inp);
+    __COLD_MOKA_IMPL_FUTURE.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn impl_future_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_impl_future(&inp);
+    __COLD_MOKA_IMPL_FUTURE.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn impl_future_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_impl_future(&inp);
+    __COLD_MOKA_IMPL_FUTURE.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn impl_future_cache_entry_count() -> u64 {
+    __COLD_MOKA_IMPL_FUTURE.entry_count()
+}
+#[allow(dead_code)]
+pub fn impl_future_cache_weighted_size() -> u64 {
+    __COLD_MOKA_IMPL_FUTURE.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn impl_future_cache_sync() {
+    __COLD_MOKA_IMPL_FUTURE.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn impl_future_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_impl_future(&inp);
+            if __COLD_MOKA_IMPL_FUTURE.contains_key(&key) {
+                return true;
+            }
+            let _ = impl_future(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_BOX_FUTURE: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::future::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::future::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_box_future(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn box_future(
+    inp: i32,
+) -> std::pin::Pin<Box<dyn std::future::Future<Output = i32> + Send>> {
+    let fut: std::pin::Pin<Box<dyn std::future::Future<Output = i32> + Send>> = (|| {
+        Box::pin(async move { inp })
+    })();
+    let key = __cold_moka_key_box_future(&inp);
+    ::std::boxed::Box::pin(async move {
+        __COLD_MOKA_BOX_FUTURE.get_with_by_ref(&key, fut).await
+    })
+}
+#[allow(dead_code)]
+pub fn box_future_cache_clear() {
+    __COLD_MOKA_BOX_FUTURE.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub async fn box_future_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_box_future(&inp);
+    __COLD_MOKA_BOX_FUTURE.invalidate(&key).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn box_future_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_box_future(&inp);
+    __COLD_MOKA_BOX_FUTURE.insert(key, value).await;
+}
+#[allow(dead_code, unused_variables)]
+pub async fn box_future_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_box_future(&inp);
+    __COLD_MOKA_BOX_FUTURE.get(&key).await
+}
+#[allow(dead_code, unused_variables)]
+pub fn box_future_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_box_future(&inp);
+    __COLD_MOKA_BOX_FUTURE.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn box_future_cache_entry_count() -> u64 {
+    __COLD_MOKA_BOX_FUTURE.entry_count()
+}
+#[allow(dead_code)]
+pub fn box_future_cache_weighted_size() -> u64 {
+    __COLD_MOKA_BOX_FUTURE.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub async fn box_future_cache_sync() {
+    __COLD_MOKA_BOX_FUTURE.run_pending_tasks().await;
+}
+#[allow(dead_code)]
+pub async fn box_future_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+    concurrency: usize,
+) -> ::cold_moka::Warmed {
+    use ::cold_moka::futures_util::stream::StreamExt as _;
+    let mut loads = ::cold_moka::futures_util::stream::iter(inputs)
+        .map(|inp| async move {
+            let key = __cold_moka_key_box_future(&inp);
+            if __COLD_MOKA_BOX_FUTURE.contains_key(&key) {
+                return true;
+            }
+            let _ = box_future(inp).await;
+            false
+        })
+        .buffer_unordered(concurrency.max(1));
+    let mut warmed = ::cold_moka::Warmed::default();
+    while let Some(present) = loads.next().await {
+        warmed.record(present);
+    }
+    warmed
 }
@@ -59,3 +59,276 @@ pub struct Wrapper<T>(T);
 async fn destruct(Wrapper(aaaaaa): Wrapper<i32>) -> i32 {
     aaaaaa
 }
+
+#[cached(ttl = 13, pin = true)]
+pub async fn pinned(inp: i32) -> i32 {
+    inp
+}
+
+#[cached]
+pub async fn qualified_result(inp: i32) -> ::std::result::Result<i32, i32> {
+    Ok(inp)
+}
+
+#[cached]
+pub async fn std_result(inp: i32) -> std::result::Result<i32, i32> {
+    Ok(inp)
+}
+
+#[cached]
+pub async fn qualified_option(inp: i32) -> ::core::option::Option<i32> {
+    Some(inp)
+}
+
+#[cached]
+pub async fn core_option(inp: i32) -> core::option::Option<i32> {
+    Some(inp)
+}
+
+#[cached]
+pub async fn core_result(inp: i32) -> core::result::Result<i32, i32> {
+    Ok(inp)
+}
+
+#[cached]
+pub async fn std_option(inp: i32) -> ::std::option::Option<i32> {
+    Some(inp)
+}
+
+pub trait Repo {
+    fn fetch(&self, id: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = i32> + Send>>;
+}
+
+impl Repo for NoHash {
+    #[cached(boxed_future = true)]
+    fn fetch(&self, id: u64) -> std::pin::Pin<Box<dyn std::future::Future<Output = i32> + Send>> {
+        Box::pin(async move { id as i32 })
+    }
+}
+
+impl NoHash {
+    #[cached]
+    pub async fn method(&self, inp: i32) -> Result<i32, i32> {
+        Ok(inp)
+    }
+}
+
+#[cached(sync_cache = true)]
+pub async fn sync_cache(inp: i32) -> Result<i32, i32> {
+    Ok(inp)
+}
+
+#[cached(cache_errors = true, ttl = 60, negative_ttl = 5)]
+pub async fn cache_errors(inp: i32) -> Result<i32, i32> {
+    Ok(inp)
+}
+
+#[cached(blocking = true)]
+pub async fn blocking(inp: i32) -> Option<i32> {
+    Some(inp)
+}
+
+#[cached(cache_none = true)]
+pub async fn cache_none(inp: i32) -> Option<i32> {
+    Some(inp)
+}
+
+#[cached]
+pub async fn result_option(inp: i32) -> Result<Option<i32>, i32> {
+    Ok(Some(inp))
+}
+
+#[cached(wrap_return = true)]
+pub async fn wrap_return(inp: i32) -> Result<cold_moka::Return<i32>, i32> {
+    Ok(cold_moka::Return::new(inp))
+}
+
+pub fn hook(_: &i32) {}
+
+#[cached(on_hit = "hook", on_miss = "hook")]
+pub async fn lookup_hooks(inp: i32) -> i32 {
+    inp
+}
+
+#[cached]
+pub async fn returns_arc(inp: i32) -> std::sync::Arc<i32> {
+    std::sync::Arc::new(inp)
+}
+
+#[cached]
+pub async fn returns_arc_result(inp: i32) -> Result<std::sync::Arc<i32>, i32> {
+    Ok(std::sync::Arc::new(inp))
+}
+
+#[cached]
+pub async fn returns_arc_option(inp: i32) -> Option<std::sync::Arc<i32>> {
+    Some(std::sync::Arc::new(inp))
+}
+
+#[cached(ttl = 60, result_fallback = true)]
+pub async fn result_fallback(inp: i32) -> Result<i32, i32> {
+    Ok(inp)
+}
+
+#[cached(ttl = 600)]
+pub async fn debounced(inp: i32) {
+    let _ = inp;
+}
+
+#[cached(cache_if = "|v: &i32| *v > 0")]
+pub async fn cache_if(inp: i32) -> Result<i32, i32> {
+    Ok(inp)
+}
+
+#[cached(key = "a, c")]
+pub async fn destructured((a, _b): (i32, i32), c: String) -> i32 {
+    a + c.len() as i32
+}
+
+#[cached(value_ttl = true)]
+pub async fn value_ttl(inp: i32) -> (i32, std::time::Duration) {
+    (inp, std::time::Duration::from_secs(60))
+}
+
+#[cached(key = "b, a")]
+pub async fn key_order(a: i32, b: String) -> i32 {
+    a + b.len() as i32
+}
+
+pub static ENABLED: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(true);
+
+#[cached(enabled = "ENABLED")]
+pub async fn enabled(inp: i32) -> i32 {
+    inp
+}
+
+#[cached(key = "arg")]
+pub async fn no_hash_1_arg(_ctx: NoHash, arg: u128) -> u128 {
+    arg
+}
+
+#[cached(key = "arg1, arg2")]
+pub async fn no_hash_2_args(_ctx: NoHash, arg1: u128, arg2: u128) -> u128 {
+    arg1 + arg2
+}
+
+#[cached(key = "b")]
+pub async fn destructured_subset(_ctx: NoHash, (a, b): (u8, String)) -> String {
+    format!("{}{}", a, b)
+}
+
+#[cached]
+pub async fn borrowed_key(name: &str, bytes: &[u8], id: &u64) -> usize {
+    name.len() + bytes.len() + *id as usize
+}
+
+#[cached]
+pub async fn named_like_locals(key: i32, value: i32) -> i32 {
+    key + value
+}
+
+#[cached(convert = "{ (a.abs(), b) }")]
+pub async fn convert_block(a: i32, b: u8) -> i32 {
+    a + b as i32
+}
+
+#[cached(convert = "(a.abs(), b)")]
+pub async fn convert_expr(a: i32, b: u8) -> i32 {
+    a + b as i32
+}
+
+#[cached(key_expr = "a.abs()", key_type = "i32")]
+pub async fn key_expr(a: i32, b: u8) -> i32 {
+    a + b as i32
+}
+
+pub async fn digest(data: &str) -> usize {
+    data.len()
+}
+
+#[cached(convert = "(digest(&a).await, b)", key_type = "(usize, u8)")]
+pub async fn convert_await(a: String, b: u8) -> usize {
+    a.len() + b as usize
+}
+
+#[derive(Clone, Hash, PartialEq, Eq)]
+pub struct CompositeId(i32, i32);
+
+impl CompositeId {
+    fn from_parts(a: &i32, b: &i32) -> Self {
+        CompositeId(*a, *b)
+    }
+}
+
+#[cached(
+    key = "a, b",
+    key_fn = "CompositeId::from_parts",
+    key_type = "CompositeId"
+)]
+pub async fn key_fn(_ctx: NoHash, a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[cached(swap = true)]
+pub async fn swapped(inp: i32) -> i32 {
+    inp
+}
+
+#[cached(stats = true)]
+pub async fn with_stats(inp: i32) -> Result<i32, i32> {
+    Ok(inp)
+}
+
+#[cached(
+    max_weight = 100,
+    weigher = "|_, v: &Vec<u8>| v.len() as u32",
+    oversize = "bypass"
+)]
+pub async fn oversize_bypass(inp: usize) -> Result<Vec<u8>, i32> {
+    Ok(vec![0; inp])
+}
+
+#[cached(dump = true)]
+pub async fn dumped(inp: i32) -> i32 {
+    inp
+}
+
+#[cached(arc = true)]
+pub async fn arc_value(inp: usize) -> Vec<u8> {
+    vec![0; inp]
+}
+
+#[cached(failpoints = true)]
+pub async fn failpoints(inp: i32) -> i32 {
+    inp
+}
+
+#[cached(key_hash = true)]
+pub async fn key_hashed(inp: String, other: (u8, u8)) -> usize {
+    inp.len() + other.0 as usize
+}
+
+#[cached(many = true)]
+pub async fn many(inp: i32, other: u8) -> Option<i32> {
+    Some(inp + other as i32)
+}
+
+#[cached(key_borrow = true, key = "inp")]
+pub async fn key_borrow(inp: String, other: u8) -> Result<String, String> {
+    Ok(format!("{}{}", inp, other))
+}
+
+#[cached(bypass = true, bypass_name = "fresh")]
+pub async fn bypass(inp: i32) -> i32 {
+    inp
+}
+
+#[cached]
+pub fn impl_future(inp: i32) -> impl std::future::Future<Output = Option<i32>> {
+    std::future::ready(Some(inp))
+}
+
+#[cached]
+pub fn box_future(inp: i32) -> std::pin::Pin<Box<dyn std::future::Future<Output = i32> + Send>> {
+    Box::pin(async move { inp })
+}
@@ -1,74 +1,5407 @@
-use cold_moka::cached;
+use cold_moka::{cache_invalidate, cache_update, cached, once};
 fn main() {}
-#[cached]
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+};
+static __COLD_MOKA_CACHED: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_cached() -> () {
+    ()
+}
 pub fn cached() -> i32 {
-    let x = 1 + 2;
-    x
-}
-#[cached]
-pub fn cached2(mut i8: i8) -> i32 {
-    i8 += 1;
-    let x = i8 + 3;
-    x as i32
-}
-#[cached]
-pub fn cached3(mut i8: i8, _kek: u128) -> i32 {
-    i8 += 1;
-    let x = i8 + 3;
-    x as i32
-}
-#[cached(ttl = "13")]
-pub fn cached4(mut i8: i8, _kek: u128) -> i32 {
-    i8 += 1;
-    let x = i8 + 3;
-    x as i32
-}
-#[cached(ttl = "13", size = 1337)]
-pub fn cached5(mut i8: i8, _kek: u128) -> i32 {
-    i8 += 1;
-    let x = i8 + 3;
-    x as i32
+    fn __cold_moka_inner_cached() -> i32 {
+        1 + 2
+    }
+    let key = __cold_moka_key_cached();
+    __COLD_MOKA_CACHED.get_with_by_ref(&key, || __cold_moka_inner_cached())
+}
+#[allow(dead_code)]
+pub fn cached_cache_clear() {
+    __COLD_MOKA_CACHED.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn cached_cache_invalidate() {
+    let key = __cold_moka_key_cached();
+    __COLD_MOKA_CACHED.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn cached_cache_insert(value: i32) {
+    let key = __cold_moka_key_cached();
+    __COLD_MOKA_CACHED.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn cached_if_cached() -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_cached();
+    __COLD_MOKA_CACHED.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn cached_cache_contains() -> bool {
+    let key = __cold_moka_key_cached();
+    __COLD_MOKA_CACHED.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn cached_cache_entry_count() -> u64 {
+    __COLD_MOKA_CACHED.run_pending_tasks();
+    __COLD_MOKA_CACHED.entry_count()
+}
+#[allow(dead_code)]
+pub fn cached_cache_weighted_size() -> u64 {
+    __COLD_MOKA_CACHED.run_pending_tasks();
+    __COLD_MOKA_CACHED.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn cached_cache_sync() {
+    __COLD_MOKA_CACHED.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn cached_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = ()>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for () in inputs {
+        let key = __cold_moka_key_cached();
+        let present = __COLD_MOKA_CACHED.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = cached();
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i8>();
+};
+static __COLD_MOKA_CACHED2: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i8), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_cached2(i8: &i8) -> (i8) {
+    (i8.clone())
+}
+pub fn cached2(i8: i8) -> i32 {
+    fn __cold_moka_inner_cached2(mut i8: i8) -> i32 {
+        i8 += 1;
+        let x = i8 + 3;
+        x as i32
+    }
+    let key = __cold_moka_key_cached2(&i8);
+    __COLD_MOKA_CACHED2.get_with_by_ref(&key, || __cold_moka_inner_cached2(i8))
+}
+#[allow(dead_code)]
+pub fn cached2_cache_clear() {
+    __COLD_MOKA_CACHED2.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn cached2_cache_invalidate(i8: i8) {
+    let key = __cold_moka_key_cached2(&i8);
+    __COLD_MOKA_CACHED2.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn cached2_cache_insert(i8: i8, value: i32) {
+    let key = __cold_moka_key_cached2(&i8);
+    __COLD_MOKA_CACHED2.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn cached2_if_cached(i8: i8) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_cached2(&i8);
+    __COLD_MOKA_CACHED2.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn cached2_cache_contains(i8: i8) -> bool {
+    let key = __cold_moka_key_cached2(&i8);
+    __COLD_MOKA_CACHED2.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn cached2_cache_entry_count() -> u64 {
+    __COLD_MOKA_CACHED2.run_pending_tasks();
+    __COLD_MOKA_CACHED2.entry_count()
+}
+#[allow(dead_code)]
+pub fn cached2_cache_weighted_size() -> u64 {
+    __COLD_MOKA_CACHED2.run_pending_tasks();
+    __COLD_MOKA_CACHED2.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn cached2_cache_sync() {
+    __COLD_MOKA_CACHED2.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn cached2_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i8>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for i8 in inputs {
+        let key = __cold_moka_key_cached2(&i8);
+        let present = __COLD_MOKA_CACHED2.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = cached2(i8);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i8>();
+    cached_key::<u128>();
+};
+static __COLD_MOKA_CACHED3: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i8, u128), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_cached3(i8: &i8, _kek: &u128) -> (i8, u128) {
+    (i8.clone(), _kek.clone())
+}
+pub fn cached3(i8: i8, _kek: u128) -> i32 {
+    fn __cold_moka_inner_cached3(mut i8: i8, _kek: u128) -> i32 {
+        i8 += 1;
+        let x = i8 + 3;
+        x as i32
+    }
+    let key = __cold_moka_key_cached3(&i8, &_kek);
+    __COLD_MOKA_CACHED3.get_with_by_ref(&key, || __cold_moka_inner_cached3(i8, _kek))
+}
+#[allow(dead_code)]
+pub fn cached3_cache_clear() {
+    __COLD_MOKA_CACHED3.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn cached3_cache_invalidate(i8: i8, _kek: u128) {
+    let key = __cold_moka_key_cached3(&i8, &_kek);
+    __COLD_MOKA_CACHED3.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn cached3_cache_insert(i8: i8, _kek: u128, value: i32) {
+    let key = __cold_moka_key_cached3(&i8, &_kek);
+    __COLD_MOKA_CACHED3.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn cached3_if_cached(i8: i8, _kek: u128) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_cached3(&i8, &_kek);
+    __COLD_MOKA_CACHED3.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn cached3_cache_contains(i8: i8, _kek: u128) -> bool {
+    let key = __cold_moka_key_cached3(&i8, &_kek);
+    __COLD_MOKA_CACHED3.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn cached3_cache_entry_count() -> u64 {
+    __COLD_MOKA_CACHED3.run_pending_tasks();
+    __COLD_MOKA_CACHED3.entry_count()
+}
+#[allow(dead_code)]
+pub fn cached3_cache_weighted_size() -> u64 {
+    __COLD_MOKA_CACHED3.run_pending_tasks();
+    __COLD_MOKA_CACHED3.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn cached3_cache_sync() {
+    __COLD_MOKA_CACHED3.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn cached3_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (i8, u128)>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for (i8, _kek) in inputs {
+        let key = __cold_moka_key_cached3(&i8, &_kek);
+        let present = __COLD_MOKA_CACHED3.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = cached3(i8, _kek);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i8>();
+    cached_key::<u128>();
+};
+static __COLD_MOKA_CACHED4: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i8, u128), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder()
+        .max_capacity(1000u64)
+        .time_to_live(::std::time::Duration::from_secs(13u64))
+        .build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_cached4(i8: &i8, _kek: &u128) -> (i8, u128) {
+    (i8.clone(), _kek.clone())
+}
+pub fn cached4(i8: i8, _kek: u128) -> i32 {
+    fn __cold_moka_inner_cached4(mut i8: i8, _kek: u128) -> i32 {
+        i8 += 1;
+        let x = i8 + 3;
+        x as i32
+    }
+    let key = __cold_moka_key_cached4(&i8, &_kek);
+    __COLD_MOKA_CACHED4.get_with_by_ref(&key, || __cold_moka_inner_cached4(i8, _kek))
+}
+#[allow(dead_code)]
+pub fn cached4_cache_clear() {
+    __COLD_MOKA_CACHED4.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn cached4_cache_invalidate(i8: i8, _kek: u128) {
+    let key = __cold_moka_key_cached4(&i8, &_kek);
+    __COLD_MOKA_CACHED4.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn cached4_cache_insert(i8: i8, _kek: u128, value: i32) {
+    let key = __cold_moka_key_cached4(&i8, &_kek);
+    __COLD_MOKA_CACHED4.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn cached4_if_cached(i8: i8, _kek: u128) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_cached4(&i8, &_kek);
+    __COLD_MOKA_CACHED4.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn cached4_cache_contains(i8: i8, _kek: u128) -> bool {
+    let key = __cold_moka_key_cached4(&i8, &_kek);
+    __COLD_MOKA_CACHED4.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn cached4_cache_entry_count() -> u64 {
+    __COLD_MOKA_CACHED4.run_pending_tasks();
+    __COLD_MOKA_CACHED4.entry_count()
+}
+#[allow(dead_code)]
+pub fn cached4_cache_weighted_size() -> u64 {
+    __COLD_MOKA_CACHED4.run_pending_tasks();
+    __COLD_MOKA_CACHED4.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn cached4_cache_sync() {
+    __COLD_MOKA_CACHED4.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn cached4_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (i8, u128)>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for (i8, _kek) in inputs {
+        let key = __cold_moka_key_cached4(&i8, &_kek);
+        let present = __COLD_MOKA_CACHED4.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = cached4(i8, _kek);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i8>();
+    cached_key::<u128>();
+};
+static __COLD_MOKA_CACHED5: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i8, u128), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder()
+        .max_capacity(1337u64)
+        .time_to_live(::std::time::Duration::from_secs(13u64))
+        .build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_cached5(i8: &i8, _kek: &u128) -> (i8, u128) {
+    (i8.clone(), _kek.clone())
+}
+pub fn cached5(i8: i8, _kek: u128) -> i32 {
+    fn __cold_moka_inner_cached5(mut i8: i8, _kek: u128) -> i32 {
+        i8 += 1;
+        let x = i8 + 3;
+        x as i32
+    }
+    let key = __cold_moka_key_cached5(&i8, &_kek);
+    __COLD_MOKA_CACHED5.get_with_by_ref(&key, || __cold_moka_inner_cached5(i8, _kek))
+}
+#[allow(dead_code)]
+pub fn cached5_cache_clear() {
+    __COLD_MOKA_CACHED5.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn cached5_cache_invalidate(i8: i8, _kek: u128) {
+    let key = __cold_moka_key_cached5(&i8, &_kek);
+    __COLD_MOKA_CACHED5.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn cached5_cache_insert(i8: i8, _kek: u128, value: i32) {
+    let key = __cold_moka_key_cached5(&i8, &_kek);
+    __COLD_MOKA_CACHED5.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn cached5_if_cached(i8: i8, _kek: u128) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_cached5(&i8, &_kek);
+    __COLD_MOKA_CACHED5.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn cached5_cache_contains(i8: i8, _kek: u128) -> bool {
+    let key = __cold_moka_key_cached5(&i8, &_kek);
+    __COLD_MOKA_CACHED5.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn cached5_cache_entry_count() -> u64 {
+    __COLD_MOKA_CACHED5.run_pending_tasks();
+    __COLD_MOKA_CACHED5.entry_count()
+}
+#[allow(dead_code)]
+pub fn cached5_cache_weighted_size() -> u64 {
+    __COLD_MOKA_CACHED5.run_pending_tasks();
+    __COLD_MOKA_CACHED5.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn cached5_cache_sync() {
+    __COLD_MOKA_CACHED5.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn cached5_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (i8, u128)>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for (i8, _kek) in inputs {
+        let key = __cold_moka_key_cached5(&i8, &_kek);
+        let present = __COLD_MOKA_CACHED5.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = cached5(i8, _kek);
+        }
+    }
+    warmed
 }
 pub struct NoHash;
-#[cached(ttl = "13", size = 1337, convert = "{i8}", key = "i8")]
-pub fn cached6(mut i8: i8, _ctx: NoHash) -> i32 {
-    i8 += 1;
-    let x = i8 + 3;
-    x as i32
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<(i8)>();
+};
+static __COLD_MOKA_CACHED6: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i8), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder()
+        .max_capacity(1337u64)
+        .time_to_live(::std::time::Duration::from_secs(13u64))
+        .build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_cached6(i8: &i8) -> (i8) {
+    #[allow(unused_variables)]
+    let i8 = ::std::clone::Clone::clone(i8);
+    { i8 }
+}
+pub fn cached6(i8: i8, _ctx: NoHash) -> i32 {
+    fn __cold_moka_inner_cached6(mut i8: i8, _ctx: NoHash) -> i32 {
+        i8 += 1;
+        let x = i8 + 3;
+        x as i32
+    }
+    let key = __cold_moka_key_cached6(&i8);
+    __COLD_MOKA_CACHED6.get_with_by_ref(&key, || __cold_moka_inner_cached6(i8, _ctx))
+}
+#[allow(dead_code)]
+pub fn cached6_cache_clear() {
+    __COLD_MOKA_CACHED6.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn cached6_cache_invalidate(i8: i8) {
+    let key = __cold_moka_key_cached6(&i8);
+    __COLD_MOKA_CACHED6.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn cached6_cache_insert(i8: i8, value: i32) {
+    let key = __cold_moka_key_cached6(&i8);
+    __COLD_MOKA_CACHED6.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn cached6_if_cached(i8: i8) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_cached6(&i8);
+    __COLD_MOKA_CACHED6.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn cached6_cache_contains(i8: i8) -> bool {
+    let key = __cold_moka_key_cached6(&i8);
+    __COLD_MOKA_CACHED6.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn cached6_cache_entry_count() -> u64 {
+    __COLD_MOKA_CACHED6.run_pending_tasks();
+    __COLD_MOKA_CACHED6.entry_count()
+}
+#[allow(dead_code)]
+pub fn cached6_cache_weighted_size() -> u64 {
+    __COLD_MOKA_CACHED6.run_pending_tasks();
+    __COLD_MOKA_CACHED6.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn cached6_cache_sync() {
+    __COLD_MOKA_CACHED6.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn cached6_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (i8, NoHash)>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for (i8, _ctx) in inputs {
+        let key = __cold_moka_key_cached6(&i8);
+        let present = __COLD_MOKA_CACHED6.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = cached6(i8, _ctx);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<u128>();
+    cached_key::<u128>();
+};
+static __COLD_MOKA_NO_HASH_1_ARG: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(u128), u128>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder()
+        .max_capacity(228u64)
+        .time_to_live(::std::time::Duration::from_secs(13u64))
+        .build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_no_hash_1_arg(arg: &u128) -> (u128) {
+    (arg.clone())
 }
-#[cached(ttl = 13, size = 228, key = "arg")]
 pub fn no_hash_1_arg(_ctx: NoHash, arg: u128) -> u128 {
-    arg
+    fn __cold_moka_inner_no_hash_1_arg(_ctx: NoHash, arg: u128) -> u128 {
+        arg
+    }
+    let key = __cold_moka_key_no_hash_1_arg(&arg);
+    __COLD_MOKA_NO_HASH_1_ARG
+        .get_with_by_ref(&key, || __cold_moka_inner_no_hash_1_arg(_ctx, arg))
+}
+#[allow(dead_code)]
+pub fn no_hash_1_arg_cache_clear() {
+    __COLD_MOKA_NO_HASH_1_ARG.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn no_hash_1_arg_cache_invalidate(arg: u128) {
+    let key = __cold_moka_key_no_hash_1_arg(&arg);
+    __COLD_MOKA_NO_HASH_1_ARG.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn no_hash_1_arg_cache_insert(arg: u128, value: u128) {
+    let key = __cold_moka_key_no_hash_1_arg(&arg);
+    __COLD_MOKA_NO_HASH_1_ARG.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn no_hash_1_arg_if_cached(arg: u128) -> ::std::option::Option<u128> {
+    let key = __cold_moka_key_no_hash_1_arg(&arg);
+    __COLD_MOKA_NO_HASH_1_ARG.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn no_hash_1_arg_cache_contains(arg: u128) -> bool {
+    let key = __cold_moka_key_no_hash_1_arg(&arg);
+    __COLD_MOKA_NO_HASH_1_ARG.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn no_hash_1_arg_cache_entry_count() -> u64 {
+    __COLD_MOKA_NO_HASH_1_ARG.run_pending_tasks();
+    __COLD_MOKA_NO_HASH_1_ARG.entry_count()
+}
+#[allow(dead_code)]
+pub fn no_hash_1_arg_cache_weighted_size() -> u64 {
+    __COLD_MOKA_NO_HASH_1_ARG.run_pending_tasks();
+    __COLD_MOKA_NO_HASH_1_ARG.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn no_hash_1_arg_cache_sync() {
+    __COLD_MOKA_NO_HASH_1_ARG.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn no_hash_1_arg_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (NoHash, u128)>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for (_ctx, arg) in inputs {
+        let key = __cold_moka_key_no_hash_1_arg(&arg);
+        let present = __COLD_MOKA_NO_HASH_1_ARG.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = no_hash_1_arg(_ctx, arg);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<u128>();
+    cached_key::<u128>();
+    cached_key::<u128>();
+};
+static __COLD_MOKA_NO_HASH_2_ARGS: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(u128, u128), u128>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder()
+        .max_capacity(228u64)
+        .time_to_live(::std::time::Duration::from_secs(13u64))
+        .build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_no_hash_2_args(arg1: &u128, arg2: &u128) -> (u128, u128) {
+    (arg1.clone(), arg2.clone())
+}
+pub fn no_hash_2_args(_ctx: NoHash, arg1: u128, arg2: u128) -> u128 {
+    fn __cold_moka_inner_no_hash_2_args(
+        mut _ctx: NoHash,
+        arg1: u128,
+        arg2: u128,
+    ) -> u128 {
+        arg1 + arg2
+    }
+    let key = __cold_moka_key_no_hash_2_args(&arg1, &arg2);
+    __COLD_MOKA_NO_HASH_2_ARGS
+        .get_with_by_ref(&key, || __cold_moka_inner_no_hash_2_args(_ctx, arg1, arg2))
+}
+#[allow(dead_code)]
+pub fn no_hash_2_args_cache_clear() {
+    __COLD_MOKA_NO_HASH_2_ARGS.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn no_hash_2_args_cache_invalidate(arg1: u128, arg2: u128) {
+    let key = __cold_moka_key_no_hash_2_args(&arg1, &arg2);
+    __COLD_MOKA_NO_HASH_2_ARGS.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn no_hash_2_args_cache_insert(arg1: u128, arg2: u128, value: u128) {
+    let key = __cold_moka_key_no_hash_2_args(&arg1, &arg2);
+    __COLD_MOKA_NO_HASH_2_ARGS.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn no_hash_2_args_if_cached(arg1: u128, arg2: u128) -> ::std::option::Option<u128> {
+    let key = __cold_moka_key_no_hash_2_args(&arg1, &arg2);
+    __COLD_MOKA_NO_HASH_2_ARGS.get(&key)
 }
-#[cached(ttl = 13, size = 228, key = "arg1, arg2")]
-pub fn no_hash_2_args(mut _ctx: NoHash, arg1: u128, arg2: u128) -> u128 {
-    arg1 + arg2
+#[allow(dead_code, unused_variables)]
+pub fn no_hash_2_args_cache_contains(arg1: u128, arg2: u128) -> bool {
+    let key = __cold_moka_key_no_hash_2_args(&arg1, &arg2);
+    __COLD_MOKA_NO_HASH_2_ARGS.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn no_hash_2_args_cache_entry_count() -> u64 {
+    __COLD_MOKA_NO_HASH_2_ARGS.run_pending_tasks();
+    __COLD_MOKA_NO_HASH_2_ARGS.entry_count()
+}
+#[allow(dead_code)]
+pub fn no_hash_2_args_cache_weighted_size() -> u64 {
+    __COLD_MOKA_NO_HASH_2_ARGS.run_pending_tasks();
+    __COLD_MOKA_NO_HASH_2_ARGS.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn no_hash_2_args_cache_sync() {
+    __COLD_MOKA_NO_HASH_2_ARGS.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn no_hash_2_args_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (NoHash, u128, u128)>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for (_ctx, arg1, arg2) in inputs {
+        let key = __cold_moka_key_no_hash_2_args(&arg1, &arg2);
+        let present = __COLD_MOKA_NO_HASH_2_ARGS.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = no_hash_2_args(_ctx, arg1, arg2);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_RESULT: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_result(inp: &i32) -> (i32) {
+    (inp.clone())
 }
-#[cached]
 pub fn result(inp: i32) -> Result<i32, i32> {
-    Ok(inp)
+    fn __cold_moka_inner_result(inp: i32) -> Result<i32, i32> {
+        Ok(inp)
+    }
+    let key = __cold_moka_key_result(&inp);
+    let result = __COLD_MOKA_RESULT
+        .try_get_with_by_ref(&key, || __cold_moka_inner_result(inp));
+    match result {
+        Ok(v) => Ok(v),
+        Err(e) => {
+            Err({
+                #[allow(unused_imports)]
+                use ::cold_moka::{CloneArcError as _, FromArcError as _};
+                (&&::cold_moka::ArcError::new(e)).into_error()
+            })
+        }
+    }
+}
+#[allow(dead_code)]
+pub fn result_cache_clear() {
+    __COLD_MOKA_RESULT.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn result_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_result(&inp);
+    __COLD_MOKA_RESULT.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn result_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_result(&inp);
+    __COLD_MOKA_RESULT.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn result_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_result(&inp);
+    __COLD_MOKA_RESULT.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn result_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_result(&inp);
+    __COLD_MOKA_RESULT.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn result_cache_entry_count() -> u64 {
+    __COLD_MOKA_RESULT.run_pending_tasks();
+    __COLD_MOKA_RESULT.entry_count()
+}
+#[allow(dead_code)]
+pub fn result_cache_weighted_size() -> u64 {
+    __COLD_MOKA_RESULT.run_pending_tasks();
+    __COLD_MOKA_RESULT.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn result_cache_sync() {
+    __COLD_MOKA_RESULT.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn result_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_result(&inp);
+        let present = __COLD_MOKA_RESULT.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = result(inp);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_OPTION: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_option(inp: &i32) -> (i32) {
+    (inp.clone())
 }
-#[cached]
 fn option(inp: i32) -> Option<i32> {
-    Some(inp)
+    fn __cold_moka_inner_option(inp: i32) -> Option<i32> {
+        Some(inp)
+    }
+    let key = __cold_moka_key_option(&inp);
+    __COLD_MOKA_OPTION.optionally_get_with_by_ref(&key, || __cold_moka_inner_option(inp))
+}
+#[allow(dead_code)]
+fn option_cache_clear() {
+    __COLD_MOKA_OPTION.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+fn option_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_option(&inp);
+    __COLD_MOKA_OPTION.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+fn option_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_option(&inp);
+    __COLD_MOKA_OPTION.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+fn option_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_option(&inp);
+    __COLD_MOKA_OPTION.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+fn option_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_option(&inp);
+    __COLD_MOKA_OPTION.contains_key(&key)
+}
+#[allow(dead_code)]
+fn option_cache_entry_count() -> u64 {
+    __COLD_MOKA_OPTION.run_pending_tasks();
+    __COLD_MOKA_OPTION.entry_count()
+}
+#[allow(dead_code)]
+fn option_cache_weighted_size() -> u64 {
+    __COLD_MOKA_OPTION.run_pending_tasks();
+    __COLD_MOKA_OPTION.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+fn option_cache_sync() {
+    __COLD_MOKA_OPTION.run_pending_tasks();
+}
+#[allow(dead_code)]
+fn option_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_option(&inp);
+        let present = __COLD_MOKA_OPTION.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = option(inp);
+        }
+    }
+    warmed
 }
 pub struct Wrapper<T>(T);
-#[cached]
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_DESTRUCT: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_destruct(aaaaaa: &i32) -> (i32) {
+    (aaaaaa.clone())
+}
 fn destruct(Wrapper(aaaaaa): Wrapper<i32>) -> i32 {
-    aaaaaa
+    fn __cold_moka_inner_destruct(Wrapper(aaaaaa): Wrapper<i32>) -> i32 {
+        aaaaaa
+    }
+    let key = __cold_moka_key_destruct(&aaaaaa);
+    __COLD_MOKA_DESTRUCT
+        .get_with_by_ref(&key, || __cold_moka_inner_destruct(Wrapper(aaaaaa)))
+}
+#[allow(dead_code)]
+fn destruct_cache_clear() {
+    __COLD_MOKA_DESTRUCT.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+fn destruct_cache_invalidate(aaaaaa: i32) {
+    let key = __cold_moka_key_destruct(&aaaaaa);
+    __COLD_MOKA_DESTRUCT.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+fn destruct_cache_insert(aaaaaa: i32, value: i32) {
+    let key = __cold_moka_key_destruct(&aaaaaa);
+    __COLD_MOKA_DESTRUCT.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+fn destruct_if_cached(aaaaaa: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_destruct(&aaaaaa);
+    __COLD_MOKA_DESTRUCT.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+fn destruct_cache_contains(aaaaaa: i32) -> bool {
+    let key = __cold_moka_key_destruct(&aaaaaa);
+    __COLD_MOKA_DESTRUCT.contains_key(&key)
+}
+#[allow(dead_code)]
+fn destruct_cache_entry_count() -> u64 {
+    __COLD_MOKA_DESTRUCT.run_pending_tasks();
+    __COLD_MOKA_DESTRUCT.entry_count()
+}
+#[allow(dead_code)]
+fn destruct_cache_weighted_size() -> u64 {
+    __COLD_MOKA_DESTRUCT.run_pending_tasks();
+    __COLD_MOKA_DESTRUCT.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+fn destruct_cache_sync() {
+    __COLD_MOKA_DESTRUCT.run_pending_tasks();
+}
+#[allow(dead_code)]
+fn destruct_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = Wrapper<i32>>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for Wrapper(aaaaaa) in inputs {
+        let key = __cold_moka_key_destruct(&aaaaaa);
+        let present = __COLD_MOKA_DESTRUCT.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = destruct(Wrapper(aaaaaa));
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_DESTRUCT_MULTIPLE: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32, i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_destruct_multiple(aaaaaa: &i32, bbbbbb: &i32) -> (i32, i32) {
+    (aaaaaa.clone(), bbbbbb.clone())
 }
-#[cached]
 fn destruct_multiple(
     Wrapper(aaaaaa): Wrapper<i32>,
     Wrapper(bbbbbb): Wrapper<i32>,
 ) -> i32 {
-    aaaaaa + bbbbbb
+    fn __cold_moka_inner_destruct_multiple(
+        Wrapper(aaaaaa): Wrapper<i32>,
+        Wrapper(bbbbbb): Wrapper<i32>,
+    ) -> i32 {
+        aaaaaa + bbbbbb
+    }
+    let key = __cold_moka_key_destruct_multiple(&aaaaaa, &bbbbbb);
+    __COLD_MOKA_DESTRUCT_MULTIPLE
+        .get_with_by_ref(
+            &key,
+            || __cold_moka_inner_destruct_multiple(Wrapper(aaaaaa), Wrapper(bbbbbb)),
+        )
+}
+#[allow(dead_code)]
+fn destruct_multiple_cache_clear() {
+    __COLD_MOKA_DESTRUCT_MULTIPLE.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+fn destruct_multiple_cache_invalidate(aaaaaa: i32, bbbbbb: i32) {
+    let key = __cold_moka_key_destruct_multiple(&aaaaaa, &bbbbbb);
+    __COLD_MOKA_DESTRUCT_MULTIPLE.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+fn destruct_multiple_cache_insert(aaaaaa: i32, bbbbbb: i32, value: i32) {
+    let key = __cold_moka_key_destruct_multiple(&aaaaaa, &bbbbbb);
+    __COLD_MOKA_DESTRUCT_MULTIPLE.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+fn destruct_multiple_if_cached(aaaaaa: i32, bbbbbb: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_destruct_multiple(&aaaaaa, &bbbbbb);
+    __COLD_MOKA_DESTRUCT_MULTIPLE.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+fn destruct_multiple_cache_contains(aaaaaa: i32, bbbbbb: i32) -> bool {
+    let key = __cold_moka_key_destruct_multiple(&aaaaaa, &bbbbbb);
+    __COLD_MOKA_DESTRUCT_MULTIPLE.contains_key(&key)
+}
+#[allow(dead_code)]
+fn destruct_multiple_cache_entry_count() -> u64 {
+    __COLD_MOKA_DESTRUCT_MULTIPLE.run_pending_tasks();
+    __COLD_MOKA_DESTRUCT_MULTIPLE.entry_count()
+}
+#[allow(dead_code)]
+fn destruct_multiple_cache_weighted_size() -> u64 {
+    __COLD_MOKA_DESTRUCT_MULTIPLE.run_pending_tasks();
+    __COLD_MOKA_DESTRUCT_MULTIPLE.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+fn destruct_multiple_cache_sync() {
+    __COLD_MOKA_DESTRUCT_MULTIPLE.run_pending_tasks();
+}
+#[allow(dead_code)]
+fn destruct_multiple_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (Wrapper<i32>, Wrapper<i32>)>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for (Wrapper(aaaaaa), Wrapper(bbbbbb)) in inputs {
+        let key = __cold_moka_key_destruct_multiple(&aaaaaa, &bbbbbb);
+        let present = __COLD_MOKA_DESTRUCT_MULTIPLE.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = destruct_multiple(Wrapper(aaaaaa), Wrapper(bbbbbb));
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_DESTRUCT_MULTIPLE2: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32, i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_destruct_multiple2(aaaaaa: &i32, bbbbbb: &i32) -> (i32, i32) {
+    (aaaaaa.clone(), bbbbbb.clone())
 }
-#[cached(key = "aaaaaa, bbbbbb")]
 fn destruct_multiple2(
     Wrapper(aaaaaa): Wrapper<i32>,
     Wrapper(bbbbbb): Wrapper<i32>,
     Wrapper(ccccccc): Wrapper<i32>,
 ) -> i32 {
-    aaaaaa + bbbbbb + ccccccc
+    fn __cold_moka_inner_destruct_multiple2(
+        Wrapper(aaaaaa): Wrapper<i32>,
+        Wrapper(bbbbbb): Wrapper<i32>,
+        Wrapper(ccccccc): Wrapper<i32>,
+    ) -> i32 {
+        aaaaaa + bbbbbb + ccccccc
+    }
+    let key = __cold_moka_key_destruct_multiple2(&aaaaaa, &bbbbbb);
+    __COLD_MOKA_DESTRUCT_MULTIPLE2
+        .get_with_by_ref(
+            &key,
+            || __cold_moka_inner_destruct_multiple2(
+                Wrapper(aaaaaa),
+                Wrapper(bbbbbb),
+                Wrapper(ccccccc),
+            ),
+        )
+}
+#[allow(dead_code)]
+fn destruct_multiple2_cache_clear() {
+    __COLD_MOKA_DESTRUCT_MULTIPLE2.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+fn destruct_multiple2_cache_invalidate(aaaaaa: i32, bbbbbb: i32) {
+    let key = __cold_moka_key_destruct_multiple2(&aaaaaa, &bbbbbb);
+    __COLD_MOKA_DESTRUCT_MULTIPLE2.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+fn destruct_multiple2_cache_insert(aaaaaa: i32, bbbbbb: i32, value: i32) {
+    let key = __cold_moka_key_destruct_multiple2(&aaaaaa, &bbbbbb);
+    __COLD_MOKA_DESTRUCT_MULTIPLE2.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+fn destruct_multiple2_if_cached(aaaaaa: i32, bbbbbb: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_destruct_multiple2(&aaaaaa, &bbbbbb);
+    __COLD_MOKA_DESTRUCT_MULTIPLE2.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+fn destruct_multiple2_cache_contains(aaaaaa: i32, bbbbbb: i32) -> bool {
+    let key = __cold_moka_key_destruct_multiple2(&aaaaaa, &bbbbbb);
+    __COLD_MOKA_DESTRUCT_MULTIPLE2.contains_key(&key)
+}
+#[allow(dead_code)]
+fn destruct_multiple2_cache_entry_count() -> u64 {
+    __COLD_MOKA_DESTRUCT_MULTIPLE2.run_pending_tasks();
+    __COLD_MOKA_DESTRUCT_MULTIPLE2.entry_count()
+}
+#[allow(dead_code)]
+fn destruct_multiple2_cache_weighted_size() -> u64 {
+    __COLD_MOKA_DESTRUCT_MULTIPLE2.run_pending_tasks();
+    __COLD_MOKA_DESTRUCT_MULTIPLE2.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+fn destruct_multiple2_cache_sync() {
+    __COLD_MOKA_DESTRUCT_MULTIPLE2.run_pending_tasks();
+}
+#[allow(dead_code)]
+fn destruct_multiple2_warm(
+    inputs: impl ::std::iter::IntoIterator<
+        Item = (Wrapper<i32>, Wrapper<i32>, Wrapper<i32>),
+    >,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for (Wrapper(aaaaaa), Wrapper(bbbbbb), Wrapper(ccccccc)) in inputs {
+        let key = __cold_moka_key_destruct_multiple2(&aaaaaa, &bbbbbb);
+        let present = __COLD_MOKA_DESTRUCT_MULTIPLE2.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = destruct_multiple2(
+                Wrapper(aaaaaa),
+                Wrapper(bbbbbb),
+                Wrapper(ccccccc),
+            );
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_PINNED_PINNED: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::PinnedKeys<(i32)>,
+> = ::cold_moka::once_cell::sync::Lazy::new(::std::default::Default::default);
+static __COLD_MOKA_PINNED: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder()
+        .max_capacity(1000u64)
+        .expire_after(
+            ::cold_moka::PinnedExpiry::new(
+                &__COLD_MOKA_PINNED_PINNED,
+                ::std::time::Duration::from_secs(13u64),
+            ),
+        )
+        .build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_pinned(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn pinned(inp: i32) -> i32 {
+    fn __cold_moka_inner_pinned(inp: i32) -> i32 {
+        inp
+    }
+    let key = __cold_moka_key_pinned(&inp);
+    __COLD_MOKA_PINNED.get_with_by_ref(&key, || __cold_moka_inner_pinned(inp))
+}
+#[allow(dead_code)]
+pub fn pinned_pin(inp: i32) -> i32 {
+    __COLD_MOKA_PINNED_PINNED.pin(__cold_moka_key_pinned(&inp));
+    pinned(inp)
+}
+#[allow(dead_code)]
+pub fn pinned_cache_clear() {
+    __COLD_MOKA_PINNED.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn pinned_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_pinned(&inp);
+    __COLD_MOKA_PINNED.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn pinned_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_pinned(&inp);
+    __COLD_MOKA_PINNED.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn pinned_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_pinned(&inp);
+    __COLD_MOKA_PINNED.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn pinned_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_pinned(&inp);
+    __COLD_MOKA_PINNED.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn pinned_cache_entry_count() -> u64 {
+    __COLD_MOKA_PINNED.run_pending_tasks();
+    __COLD_MOKA_PINNED.entry_count()
+}
+#[allow(dead_code)]
+pub fn pinned_cache_weighted_size() -> u64 {
+    __COLD_MOKA_PINNED.run_pending_tasks();
+    __COLD_MOKA_PINNED.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn pinned_cache_sync() {
+    __COLD_MOKA_PINNED.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn pinned_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_pinned(&inp);
+        let present = __COLD_MOKA_PINNED.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = pinned(inp);
+        }
+    }
+    warmed
+}
+pub struct CompositeId(i32, i32);
+#[automatically_derived]
+impl ::core::clone::Clone for CompositeId {
+    #[inline]
+    fn clone(&self) -> CompositeId {
+        CompositeId(
+            ::core::clone::Clone::clone(&self.0),
+            ::core::clone::Clone::clone(&self.1),
+        )
+    }
+}
+#[automatically_derived]
+impl ::core::hash::Hash for CompositeId {
+    #[inline]
+    fn hash<__H: ::core::hash::Hasher>(&self, state: &mut __H) {
+        ::core::hash::Hash::hash(&self.0, state);
+        ::core::hash::Hash::hash(&self.1, state)
+    }
+}
+#[automatically_derived]
+impl ::core::marker::StructuralPartialEq for CompositeId {}
+#[automatically_derived]
+impl ::core::cmp::PartialEq for CompositeId {
+    #[inline]
+    fn eq(&self, other: &CompositeId) -> bool {
+        self.0 == other.0 && self.1 == other.1
+    }
+}
+#[automatically_derived]
+impl ::core::cmp::Eq for CompositeId {
+    #[doc(hidden)]
+    #[coverage(off)]
+    fn assert_fields_are_eq(&self) {
+        let _: ::core::cmp::AssertParamIsEq<i32>;
+    }
+}
+impl CompositeId {
+    fn from_parts(a: &i32, b: &i32) -> Self {
+        CompositeId(*a, *b)
+    }
+}
+impl CompositeId {
+    pub fn method(&mut self, inp: i32) -> i32 {
+        const _: fn() = || {
+            fn cached_value<T: ::cold_moka::CacheValue>() {}
+            fn cached_key<T: ::cold_moka::CacheKey>() {}
+            cached_value::<i32>();
+            cached_key::<i32>();
+        };
+        static __COLD_MOKA_METHOD: ::cold_moka::once_cell::sync::Lazy<
+            ::cold_moka::moka::sync::Cache<(i32), i32>,
+        > = ::cold_moka::once_cell::sync::Lazy::new(|| {
+            ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+        });
+        #[allow(clippy::ptr_arg, clippy::unused_unit)]
+        fn __cold_moka_key_method(inp: &i32) -> (i32) {
+            (inp.clone())
+        }
+        let key = __cold_moka_key_method(&inp);
+        __COLD_MOKA_METHOD
+            .get_with_by_ref(
+                &key,
+                || (|| -> i32 {
+                    self.0 += inp;
+                    self.0
+                })(),
+            )
+    }
+    pub fn associated(inp: i32) -> i32 {
+        const _: fn() = || {
+            fn cached_value<T: ::cold_moka::CacheValue>() {}
+            fn cached_key<T: ::cold_moka::CacheKey>() {}
+            cached_value::<i32>();
+            cached_key::<i32>();
+        };
+        static __COLD_MOKA_ASSOCIATED: ::cold_moka::once_cell::sync::Lazy<
+            ::cold_moka::moka::sync::Cache<(i32), i32>,
+        > = ::cold_moka::once_cell::sync::Lazy::new(|| {
+            ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+        });
+        #[allow(clippy::ptr_arg, clippy::unused_unit)]
+        fn __cold_moka_key_associated(inp: &i32) -> (i32) {
+            (inp.clone())
+        }
+        let key = __cold_moka_key_associated(&inp);
+        __COLD_MOKA_ASSOCIATED.get_with_by_ref(&key, || (|| -> i32 { inp })())
+    }
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<CompositeId>();
+};
+static __COLD_MOKA_KEY_FN: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<CompositeId, i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_key_fn(a: &i32, b: &i32) -> CompositeId {
+    CompositeId::from_parts(a, b)
+}
+pub fn key_fn(_ctx: NoHash, a: i32, b: i32) -> i32 {
+    fn __cold_moka_inner_key_fn(_ctx: NoHash, a: i32, b: i32) -> i32 {
+        a + b
+    }
+    let key = __cold_moka_key_key_fn(&a, &b);
+    __COLD_MOKA_KEY_FN.get_with_by_ref(&key, || __cold_moka_inner_key_fn(_ctx, a, b))
+}
+#[allow(dead_code)]
+pub fn key_fn_cache_clear() {
+    __COLD_MOKA_KEY_FN.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn key_fn_cache_invalidate(a: i32, b: i32) {
+    let key = __cold_moka_key_key_fn(&a, &b);
+    __COLD_MOKA_KEY_FN.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn key_fn_cache_insert(a: i32, b: i32, value: i32) {
+    let key = __cold_moka_key_key_fn(&a, &b);
+    __COLD_MOKA_KEY_FN.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn key_fn_if_cached(a: i32, b: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_key_fn(&a, &b);
+    __COLD_MOKA_KEY_FN.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn key_fn_cache_contains(a: i32, b: i32) -> bool {
+    let key = __cold_moka_key_key_fn(&a, &b);
+    __COLD_MOKA_KEY_FN.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn key_fn_cache_entry_count() -> u64 {
+    __COLD_MOKA_KEY_FN.run_pending_tasks();
+    __COLD_MOKA_KEY_FN.entry_count()
+}
+#[allow(dead_code)]
+pub fn key_fn_cache_weighted_size() -> u64 {
+    __COLD_MOKA_KEY_FN.run_pending_tasks();
+    __COLD_MOKA_KEY_FN.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn key_fn_cache_sync() {
+    __COLD_MOKA_KEY_FN.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn key_fn_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (NoHash, i32, i32)>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for (_ctx, a, b) in inputs {
+        let key = __cold_moka_key_key_fn(&a, &b);
+        let present = __COLD_MOKA_KEY_FN.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = key_fn(_ctx, a, b);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_QUALIFIED_RESULT: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_qualified_result(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn qualified_result(inp: i32) -> ::std::result::Result<i32, i32> {
+    fn __cold_moka_inner_qualified_result(inp: i32) -> ::std::result::Result<i32, i32> {
+        Ok(inp)
+    }
+    let key = __cold_moka_key_qualified_result(&inp);
+    let result = __COLD_MOKA_QUALIFIED_RESULT
+        .try_get_with_by_ref(&key, || __cold_moka_inner_qualified_result(inp));
+    match result {
+        Ok(v) => Ok(v),
+        Err(e) => {
+            Err({
+                #[allow(unused_imports)]
+                use ::cold_moka::{CloneArcError as _, FromArcError as _};
+                (&&::cold_moka::ArcError::new(e)).into_error()
+            })
+        }
+    }
+}
+#[allow(dead_code)]
+pub fn qualified_result_cache_clear() {
+    __COLD_MOKA_QUALIFIED_RESULT.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn qualified_result_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_qualified_result(&inp);
+    __COLD_MOKA_QUALIFIED_RESULT.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn qualified_result_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_qualified_result(&inp);
+    __COLD_MOKA_QUALIFIED_RESULT.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn qualified_result_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_qualified_result(&inp);
+    __COLD_MOKA_QUALIFIED_RESULT.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn qualified_result_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_qualified_result(&inp);
+    __COLD_MOKA_QUALIFIED_RESULT.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn qualified_result_cache_entry_count() -> u64 {
+    __COLD_MOKA_QUALIFIED_RESULT.run_pending_tasks();
+    __COLD_MOKA_QUALIFIED_RESULT.entry_count()
+}
+#[allow(dead_code)]
+pub fn qualified_result_cache_weighted_size() -> u64 {
+    __COLD_MOKA_QUALIFIED_RESULT.run_pending_tasks();
+    __COLD_MOKA_QUALIFIED_RESULT.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn qualified_result_cache_sync() {
+    __COLD_MOKA_QUALIFIED_RESULT.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn qualified_result_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_qualified_result(&inp);
+        let present = __COLD_MOKA_QUALIFIED_RESULT.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = qualified_result(inp);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_STD_RESULT: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_std_result(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn std_result(inp: i32) -> std::result::Result<i32, i32> {
+    fn __cold_moka_inner_std_result(inp: i32) -> std::result::Result<i32, i32> {
+        Ok(inp)
+    }
+    let key = __cold_moka_key_std_result(&inp);
+    let result = __COLD_MOKA_STD_RESULT
+        .try_get_with_by_ref(&key, || __cold_moka_inner_std_result(inp));
+    match result {
+        Ok(v) => Ok(v),
+        Err(e) => {
+            Err({
+                #[allow(unused_imports)]
+                use ::cold_moka::{CloneArcError as _, FromArcError as _};
+                (&&::cold_moka::ArcError::new(e)).into_error()
+            })
+        }
+    }
+}
+#[allow(dead_code)]
+pub fn std_result_cache_clear() {
+    __COLD_MOKA_STD_RESULT.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn std_result_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_std_result(&inp);
+    __COLD_MOKA_STD_RESULT.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn std_result_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_std_result(&inp);
+    __COLD_MOKA_STD_RESULT.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn std_result_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_std_result(&inp);
+    __COLD_MOKA_STD_RESULT.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn std_result_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_std_result(&inp);
+    __COLD_MOKA_STD_RESULT.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn std_result_cache_entry_count() -> u64 {
+    __COLD_MOKA_STD_RESULT.run_pending_tasks();
+    __COLD_MOKA_STD_RESULT.entry_count()
+}
+#[allow(dead_code)]
+pub fn std_result_cache_weighted_size() -> u64 {
+    __COLD_MOKA_STD_RESULT.run_pending_tasks();
+    __COLD_MOKA_STD_RESULT.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn std_result_cache_sync() {
+    __COLD_MOKA_STD_RESULT.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn std_result_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_std_result(&inp);
+        let present = __COLD_MOKA_STD_RESULT.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = std_result(inp);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_QUALIFIED_OPTION: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_qualified_option(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn qualified_option(inp: i32) -> ::core::option::Option<i32> {
+    fn __cold_moka_inner_qualified_option(inp: i32) -> ::core::option::Option<i32> {
+        Some(inp)
+    }
+    let key = __cold_moka_key_qualified_option(&inp);
+    __COLD_MOKA_QUALIFIED_OPTION
+        .optionally_get_with_by_ref(&key, || __cold_moka_inner_qualified_option(inp))
+}
+#[allow(dead_code)]
+pub fn qualified_option_cache_clear() {
+    __COLD_MOKA_QUALIFIED_OPTION.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn qualified_option_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_qualified_option(&inp);
+    __COLD_MOKA_QUALIFIED_OPTION.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn qualified_option_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_qualified_option(&inp);
+    __COLD_MOKA_QUALIFIED_OPTION.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn qualified_option_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_qualified_option(&inp);
+    __COLD_MOKA_QUALIFIED_OPTION.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn qualified_option_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_qualified_option(&inp);
+    __COLD_MOKA_QUALIFIED_OPTION.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn qualified_option_cache_entry_count() -> u64 {
+    __COLD_MOKA_QUALIFIED_OPTION.run_pending_tasks();
+    __COLD_MOKA_QUALIFIED_OPTION.entry_count()
+}
+#[allow(dead_code)]
+pub fn qualified_option_cache_weighted_size() -> u64 {
+    __COLD_MOKA_QUALIFIED_OPTION.run_pending_tasks();
+    __COLD_MOKA_QUALIFIED_OPTION.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn qualified_option_cache_sync() {
+    __COLD_MOKA_QUALIFIED_OPTION.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn qualified_option_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_qualified_option(&inp);
+        let present = __COLD_MOKA_QUALIFIED_OPTION.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = qualified_option(inp);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_CORE_OPTION: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_core_option(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn core_option(inp: i32) -> core::option::Option<i32> {
+    fn __cold_moka_inner_core_option(inp: i32) -> core::option::Option<i32> {
+        Some(inp)
+    }
+    let key = __cold_moka_key_core_option(&inp);
+    __COLD_MOKA_CORE_OPTION
+        .optionally_get_with_by_ref(&key, || __cold_moka_inner_core_option(inp))
+}
+#[allow(dead_code)]
+pub fn core_option_cache_clear() {
+    __COLD_MOKA_CORE_OPTION.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn core_option_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_core_option(&inp);
+    __COLD_MOKA_CORE_OPTION.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn core_option_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_core_option(&inp);
+    __COLD_MOKA_CORE_OPTION.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn core_option_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_core_option(&inp);
+    __COLD_MOKA_CORE_OPTION.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn core_option_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_core_option(&inp);
+    __COLD_MOKA_CORE_OPTION.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn core_option_cache_entry_count() -> u64 {
+    __COLD_MOKA_CORE_OPTION.run_pending_tasks();
+    __COLD_MOKA_CORE_OPTION.entry_count()
+}
+#[allow(dead_code)]
+pub fn core_option_cache_weighted_size() -> u64 {
+    __COLD_MOKA_CORE_OPTION.run_pending_tasks();
+    __COLD_MOKA_CORE_OPTION.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn core_option_cache_sync() {
+    __COLD_MOKA_CORE_OPTION.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn core_option_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_core_option(&inp);
+        let present = __COLD_MOKA_CORE_OPTION.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = core_option(inp);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_CORE_RESULT: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_core_result(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn core_result(inp: i32) -> core::result::Result<i32, i32> {
+    fn __cold_moka_inner_core_result(inp: i32) -> core::result::Result<i32, i32> {
+        Ok(inp)
+    }
+    let key = __cold_moka_key_core_result(&inp);
+    let result = __COLD_MOKA_CORE_RESULT
+        .try_get_with_by_ref(&key, || __cold_moka_inner_core_result(inp));
+    match result {
+        Ok(v) => Ok(v),
+        Err(e) => {
+            Err({
+                #[allow(unused_imports)]
+                use ::cold_moka::{CloneArcError as _, FromArcError as _};
+                (&&::cold_moka::ArcError::new(e)).into_error()
+            })
+        }
+    }
+}
+#[allow(dead_code)]
+pub fn core_result_cache_clear() {
+    __COLD_MOKA_CORE_RESULT.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn core_result_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_core_result(&inp);
+    __COLD_MOKA_CORE_RESULT.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn core_result_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_core_result(&inp);
+    __COLD_MOKA_CORE_RESULT.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn core_result_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_core_result(&inp);
+    __COLD_MOKA_CORE_RESULT.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn core_result_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_core_result(&inp);
+    __COLD_MOKA_CORE_RESULT.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn core_result_cache_entry_count() -> u64 {
+    __COLD_MOKA_CORE_RESULT.run_pending_tasks();
+    __COLD_MOKA_CORE_RESULT.entry_count()
+}
+#[allow(dead_code)]
+pub fn core_result_cache_weighted_size() -> u64 {
+    __COLD_MOKA_CORE_RESULT.run_pending_tasks();
+    __COLD_MOKA_CORE_RESULT.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn core_result_cache_sync() {
+    __COLD_MOKA_CORE_RESULT.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn core_result_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_core_result(&inp);
+        let present = __COLD_MOKA_CORE_RESULT.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = core_result(inp);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_STD_OPTION: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_std_option(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn std_option(inp: i32) -> ::std::option::Option<i32> {
+    fn __cold_moka_inner_std_option(inp: i32) -> ::std::option::Option<i32> {
+        Some(inp)
+    }
+    let key = __cold_moka_key_std_option(&inp);
+    __COLD_MOKA_STD_OPTION
+        .optionally_get_with_by_ref(&key, || __cold_moka_inner_std_option(inp))
+}
+#[allow(dead_code)]
+pub fn std_option_cache_clear() {
+    __COLD_MOKA_STD_OPTION.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn std_option_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_std_option(&inp);
+    __COLD_MOKA_STD_OPTION.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn std_option_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_std_option(&inp);
+    __COLD_MOKA_STD_OPTION.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn std_option_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_std_option(&inp);
+    __COLD_MOKA_STD_OPTION.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn std_option_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_std_option(&inp);
+    __COLD_MOKA_STD_OPTION.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn std_option_cache_entry_count() -> u64 {
+    __COLD_MOKA_STD_OPTION.run_pending_tasks();
+    __COLD_MOKA_STD_OPTION.entry_count()
+}
+#[allow(dead_code)]
+pub fn std_option_cache_weighted_size() -> u64 {
+    __COLD_MOKA_STD_OPTION.run_pending_tasks();
+    __COLD_MOKA_STD_OPTION.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn std_option_cache_sync() {
+    __COLD_MOKA_STD_OPTION.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn std_option_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_std_option(&inp);
+        let present = __COLD_MOKA_STD_OPTION.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = std_option(inp);
+        }
+    }
+    warmed
+}
+pub type ApiResult<T> = Result<T, i32>;
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_RESULT_ALIAS: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_result_alias(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn result_alias(inp: i32) -> ApiResult<i32> {
+    fn __cold_moka_inner_result_alias(inp: i32) -> ApiResult<i32> {
+        Ok(inp)
+    }
+    let key = __cold_moka_key_result_alias(&inp);
+    let result = __COLD_MOKA_RESULT_ALIAS
+        .try_get_with_by_ref(&key, || __cold_moka_inner_result_alias(inp));
+    match result {
+        Ok(v) => Ok(v),
+        Err(e) => {
+            Err({
+                #[allow(unused_imports)]
+                use ::cold_moka::{CloneArcError as _, FromArcError as _};
+                (&&::cold_moka::ArcError::new(e)).into_error()
+            })
+        }
+    }
+}
+#[allow(dead_code)]
+pub fn result_alias_cache_clear() {
+    __COLD_MOKA_RESULT_ALIAS.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn result_alias_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_result_alias(&inp);
+    __COLD_MOKA_RESULT_ALIAS.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn result_alias_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_result_alias(&inp);
+    __COLD_MOKA_RESULT_ALIAS.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn result_alias_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_result_alias(&inp);
+    __COLD_MOKA_RESULT_ALIAS.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn result_alias_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_result_alias(&inp);
+    __COLD_MOKA_RESULT_ALIAS.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn result_alias_cache_entry_count() -> u64 {
+    __COLD_MOKA_RESULT_ALIAS.run_pending_tasks();
+    __COLD_MOKA_RESULT_ALIAS.entry_count()
+}
+#[allow(dead_code)]
+pub fn result_alias_cache_weighted_size() -> u64 {
+    __COLD_MOKA_RESULT_ALIAS.run_pending_tasks();
+    __COLD_MOKA_RESULT_ALIAS.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn result_alias_cache_sync() {
+    __COLD_MOKA_RESULT_ALIAS.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn result_alias_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_result_alias(&inp);
+        let present = __COLD_MOKA_RESULT_ALIAS.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = result_alias(inp);
+        }
+    }
+    warmed
+}
+pub type Lookup<T> = Option<T>;
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_OPTION_ALIAS: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_option_alias(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn option_alias(inp: i32) -> Lookup<i32> {
+    fn __cold_moka_inner_option_alias(inp: i32) -> Lookup<i32> {
+        Some(inp)
+    }
+    let key = __cold_moka_key_option_alias(&inp);
+    __COLD_MOKA_OPTION_ALIAS
+        .optionally_get_with_by_ref(&key, || __cold_moka_inner_option_alias(inp))
+}
+#[allow(dead_code)]
+pub fn option_alias_cache_clear() {
+    __COLD_MOKA_OPTION_ALIAS.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn option_alias_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_option_alias(&inp);
+    __COLD_MOKA_OPTION_ALIAS.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn option_alias_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_option_alias(&inp);
+    __COLD_MOKA_OPTION_ALIAS.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn option_alias_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_option_alias(&inp);
+    __COLD_MOKA_OPTION_ALIAS.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn option_alias_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_option_alias(&inp);
+    __COLD_MOKA_OPTION_ALIAS.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn option_alias_cache_entry_count() -> u64 {
+    __COLD_MOKA_OPTION_ALIAS.run_pending_tasks();
+    __COLD_MOKA_OPTION_ALIAS.entry_count()
+}
+#[allow(dead_code)]
+pub fn option_alias_cache_weighted_size() -> u64 {
+    __COLD_MOKA_OPTION_ALIAS.run_pending_tasks();
+    __COLD_MOKA_OPTION_ALIAS.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn option_alias_cache_sync() {
+    __COLD_MOKA_OPTION_ALIAS.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn option_alias_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_option_alias(&inp);
+        let present = __COLD_MOKA_OPTION_ALIAS.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = option_alias(inp);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<Option<i32>>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_OPTION_WHOLE: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), Option<i32>>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_option_whole(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn option_whole(inp: i32) -> Option<i32> {
+    fn __cold_moka_inner_option_whole(inp: i32) -> Option<i32> {
+        Some(inp)
+    }
+    let key = __cold_moka_key_option_whole(&inp);
+    __COLD_MOKA_OPTION_WHOLE
+        .get_with_by_ref(&key, || __cold_moka_inner_option_whole(inp))
+}
+#[allow(dead_code)]
+pub fn option_whole_cache_clear() {
+    __COLD_MOKA_OPTION_WHOLE.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn option_whole_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_option_whole(&inp);
+    __COLD_MOKA_OPTION_WHOLE.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn option_whole_cache_insert(inp: i32, value: Option<i32>) {
+    let key = __cold_moka_key_option_whole(&inp);
+    __COLD_MOKA_OPTION_WHOLE.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn option_whole_if_cached(inp: i32) -> ::std::option::Option<Option<i32>> {
+    let key = __cold_moka_key_option_whole(&inp);
+    __COLD_MOKA_OPTION_WHOLE.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn option_whole_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_option_whole(&inp);
+    __COLD_MOKA_OPTION_WHOLE.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn option_whole_cache_entry_count() -> u64 {
+    __COLD_MOKA_OPTION_WHOLE.run_pending_tasks();
+    __COLD_MOKA_OPTION_WHOLE.entry_count()
+}
+#[allow(dead_code)]
+pub fn option_whole_cache_weighted_size() -> u64 {
+    __COLD_MOKA_OPTION_WHOLE.run_pending_tasks();
+    __COLD_MOKA_OPTION_WHOLE.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn option_whole_cache_sync() {
+    __COLD_MOKA_OPTION_WHOLE.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn option_whole_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_option_whole(&inp);
+        let present = __COLD_MOKA_OPTION_WHOLE.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = option_whole(inp);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_ERROR_FROM_ARC: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_error_from_arc(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn error_from_arc(inp: i32) -> Result<i32, i32> {
+    fn __cold_moka_inner_error_from_arc(inp: i32) -> Result<i32, i32> {
+        Ok(inp)
+    }
+    let key = __cold_moka_key_error_from_arc(&inp);
+    let result = __COLD_MOKA_ERROR_FROM_ARC
+        .try_get_with_by_ref(&key, || __cold_moka_inner_error_from_arc(inp));
+    match result {
+        Ok(v) => Ok(v),
+        Err(e) => Err((|e: std::sync::Arc<i32>| *e + 1)(e)),
+    }
+}
+#[allow(dead_code)]
+pub fn error_from_arc_cache_clear() {
+    __COLD_MOKA_ERROR_FROM_ARC.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn error_from_arc_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_error_from_arc(&inp);
+    __COLD_MOKA_ERROR_FROM_ARC.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn error_from_arc_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_error_from_arc(&inp);
+    __COLD_MOKA_ERROR_FROM_ARC.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn error_from_arc_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_error_from_arc(&inp);
+    __COLD_MOKA_ERROR_FROM_ARC.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn error_from_arc_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_error_from_arc(&inp);
+    __COLD_MOKA_ERROR_FROM_ARC.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn error_from_arc_cache_entry_count() -> u64 {
+    __COLD_MOKA_ERROR_FROM_ARC.run_pending_tasks();
+    __COLD_MOKA_ERROR_FROM_ARC.entry_count()
+}
+#[allow(dead_code)]
+pub fn error_from_arc_cache_weighted_size() -> u64 {
+    __COLD_MOKA_ERROR_FROM_ARC.run_pending_tasks();
+    __COLD_MOKA_ERROR_FROM_ARC.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn error_from_arc_cache_sync() {
+    __COLD_MOKA_ERROR_FROM_ARC.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn error_from_arc_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_error_from_arc(&inp);
+        let present = __COLD_MOKA_ERROR_FROM_ARC.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = error_from_arc(inp);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<String>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_WHERE_BOUND: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), String>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_where_bound(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn where_bound<T>(inp: i32, label: T) -> String
+where
+    T: std::fmt::Display,
+{
+    fn __cold_moka_inner_where_bound<T>(inp: i32, label: T) -> String
+    where
+        T: std::fmt::Display,
+    {
+        ::alloc::__export::must_use({
+            ::alloc::fmt::format(format_args!("{0}{1}", label, inp))
+        })
+    }
+    let key = __cold_moka_key_where_bound(&inp);
+    __COLD_MOKA_WHERE_BOUND
+        .get_with_by_ref(&key, || __cold_moka_inner_where_bound(inp, label))
+}
+#[allow(dead_code)]
+pub fn where_bound_cache_clear() {
+    __COLD_MOKA_WHERE_BOUND.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn where_bound_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_where_bound(&inp);
+    __COLD_MOKA_WHERE_BOUND.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn where_bound_cache_insert(inp: i32, value: String) {
+    let key = __cold_moka_key_where_bound(&inp);
+    __COLD_MOKA_WHERE_BOUND.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn where_bound_if_cached(inp: i32) -> ::std::option::Option<String> {
+    let key = __cold_moka_key_where_bound(&inp);
+    __COLD_MOKA_WHERE_BOUND.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn where_bound_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_where_bound(&inp);
+    __COLD_MOKA_WHERE_BOUND.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn where_bound_cache_entry_count() -> u64 {
+    __COLD_MOKA_WHERE_BOUND.run_pending_tasks();
+    __COLD_MOKA_WHERE_BOUND.entry_count()
+}
+#[allow(dead_code)]
+pub fn where_bound_cache_weighted_size() -> u64 {
+    __COLD_MOKA_WHERE_BOUND.run_pending_tasks();
+    __COLD_MOKA_WHERE_BOUND.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn where_bound_cache_sync() {
+    __COLD_MOKA_WHERE_BOUND.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn where_bound_warm<T>(
+    inputs: impl ::std::iter::IntoIterator<Item = (i32, T)>,
+) -> ::cold_moka::Warmed
+where
+    T: std::fmt::Display,
+{
+    let mut warmed = ::cold_moka::Warmed::default();
+    for (inp, label) in inputs {
+        let key = __cold_moka_key_where_bound(&inp);
+        let present = __COLD_MOKA_WHERE_BOUND.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = where_bound(inp, label);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<Result<i32, i32>>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_CACHE_ERRORS: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), Result<i32, i32>>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder()
+        .max_capacity(1000u64)
+        .expire_after(
+            ::cold_moka::ErrorExpiry::new(
+                ::std::option::Option::Some(::std::time::Duration::from_secs(60u64)),
+                ::std::time::Duration::from_secs(5u64),
+                |value: &Result<i32, i32>| value.is_err(),
+            ),
+        )
+        .build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_cache_errors(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn cache_errors(inp: i32) -> Result<i32, i32> {
+    fn __cold_moka_inner_cache_errors(inp: i32) -> Result<i32, i32> {
+        Ok(inp)
+    }
+    let key = __cold_moka_key_cache_errors(&inp);
+    __COLD_MOKA_CACHE_ERRORS
+        .get_with_by_ref(&key, || __cold_moka_inner_cache_errors(inp))
+}
+#[allow(dead_code)]
+pub fn cache_errors_cache_clear() {
+    __COLD_MOKA_CACHE_ERRORS.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn cache_errors_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_cache_errors(&inp);
+    __COLD_MOKA_CACHE_ERRORS.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn cache_errors_cache_insert(inp: i32, value: Result<i32, i32>) {
+    let key = __cold_moka_key_cache_errors(&inp);
+    __COLD_MOKA_CACHE_ERRORS.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn cache_errors_if_cached(inp: i32) -> ::std::option::Option<Result<i32, i32>> {
+    let key = __cold_moka_key_cache_errors(&inp);
+    __COLD_MOKA_CACHE_ERRORS.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn cache_errors_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_cache_errors(&inp);
+    __COLD_MOKA_CACHE_ERRORS.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn cache_errors_cache_entry_count() -> u64 {
+    __COLD_MOKA_CACHE_ERRORS.run_pending_tasks();
+    __COLD_MOKA_CACHE_ERRORS.entry_count()
+}
+#[allow(dead_code)]
+pub fn cache_errors_cache_weighted_size() -> u64 {
+    __COLD_MOKA_CACHE_ERRORS.run_pending_tasks();
+    __COLD_MOKA_CACHE_ERRORS.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn cache_errors_cache_sync() {
+    __COLD_MOKA_CACHE_ERRORS.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn cache_errors_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_cache_errors(&inp);
+        let present = __COLD_MOKA_CACHE_ERRORS.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = cache_errors(inp);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<Option<i32>>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_CACHE_NONE: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), Option<i32>>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_cache_none(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn cache_none(inp: i32) -> Option<i32> {
+    fn __cold_moka_inner_cache_none(inp: i32) -> Option<i32> {
+        Some(inp)
+    }
+    let key = __cold_moka_key_cache_none(&inp);
+    __COLD_MOKA_CACHE_NONE.get_with_by_ref(&key, || __cold_moka_inner_cache_none(inp))
+}
+#[allow(dead_code)]
+pub fn cache_none_cache_clear() {
+    __COLD_MOKA_CACHE_NONE.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn cache_none_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_cache_none(&inp);
+    __COLD_MOKA_CACHE_NONE.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn cache_none_cache_insert(inp: i32, value: Option<i32>) {
+    let key = __cold_moka_key_cache_none(&inp);
+    __COLD_MOKA_CACHE_NONE.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn cache_none_if_cached(inp: i32) -> ::std::option::Option<Option<i32>> {
+    let key = __cold_moka_key_cache_none(&inp);
+    __COLD_MOKA_CACHE_NONE.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn cache_none_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_cache_none(&inp);
+    __COLD_MOKA_CACHE_NONE.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn cache_none_cache_entry_count() -> u64 {
+    __COLD_MOKA_CACHE_NONE.run_pending_tasks();
+    __COLD_MOKA_CACHE_NONE.entry_count()
+}
+#[allow(dead_code)]
+pub fn cache_none_cache_weighted_size() -> u64 {
+    __COLD_MOKA_CACHE_NONE.run_pending_tasks();
+    __COLD_MOKA_CACHE_NONE.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn cache_none_cache_sync() {
+    __COLD_MOKA_CACHE_NONE.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn cache_none_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_cache_none(&inp);
+        let present = __COLD_MOKA_CACHE_NONE.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = cache_none(inp);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_RESULT_OPTION: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_result_option(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn result_option(inp: i32) -> Result<Option<i32>, i32> {
+    fn __cold_moka_inner_result_option(inp: i32) -> Result<Option<i32>, i32> {
+        Ok(Some(inp))
+    }
+    let key = __cold_moka_key_result_option(&inp);
+    match __COLD_MOKA_RESULT_OPTION
+        .try_get_with_by_ref(
+            &key,
+            || match __cold_moka_inner_result_option(inp) {
+                Ok(Some(value)) => Ok(value),
+                Ok(None) => Err(None),
+                Err(e) => Err(Some(::std::sync::Arc::new(e))),
+            },
+        )
+    {
+        Ok(v) => Ok(Some(v)),
+        Err(shared) => {
+            match &*shared {
+                None => Ok(None),
+                Some(e) => {
+                    let e = ::std::sync::Arc::clone(e);
+                    Err({
+                        #[allow(unused_imports)]
+                        use ::cold_moka::{CloneArcError as _, FromArcError as _};
+                        (&&::cold_moka::ArcError::new(e)).into_error()
+                    })
+                }
+            }
+        }
+    }
+}
+#[allow(dead_code)]
+pub fn result_option_cache_clear() {
+    __COLD_MOKA_RESULT_OPTION.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn result_option_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_result_option(&inp);
+    __COLD_MOKA_RESULT_OPTION.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn result_option_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_result_option(&inp);
+    __COLD_MOKA_RESULT_OPTION.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn result_option_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_result_option(&inp);
+    __COLD_MOKA_RESULT_OPTION.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn result_option_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_result_option(&inp);
+    __COLD_MOKA_RESULT_OPTION.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn result_option_cache_entry_count() -> u64 {
+    __COLD_MOKA_RESULT_OPTION.run_pending_tasks();
+    __COLD_MOKA_RESULT_OPTION.entry_count()
+}
+#[allow(dead_code)]
+pub fn result_option_cache_weighted_size() -> u64 {
+    __COLD_MOKA_RESULT_OPTION.run_pending_tasks();
+    __COLD_MOKA_RESULT_OPTION.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn result_option_cache_sync() {
+    __COLD_MOKA_RESULT_OPTION.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn result_option_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_result_option(&inp);
+        let present = __COLD_MOKA_RESULT_OPTION.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = result_option(inp);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_WRAP_RETURN: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_wrap_return(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn wrap_return(inp: i32) -> Result<cold_moka::Return<i32>, i32> {
+    fn __cold_moka_inner_wrap_return(inp: i32) -> Result<cold_moka::Return<i32>, i32> {
+        Ok(cold_moka::Return::new(inp))
+    }
+    let key = __cold_moka_key_wrap_return(&inp);
+    let was_cached = ::std::cell::Cell::new(false);
+    let result = {
+        match __COLD_MOKA_WRAP_RETURN
+            .entry_by_ref(&key)
+            .or_try_insert_with(|| {
+                __cold_moka_inner_wrap_return(inp).map(|value| value.value)
+            })
+        {
+            Ok(entry) => {
+                let fresh = entry.is_fresh();
+                was_cached.set(!fresh);
+                Ok(entry.into_value())
+            }
+            Err(e) => {
+                let fresh = true;
+                was_cached.set(!fresh);
+                Err({
+                    #[allow(unused_imports)]
+                    use ::cold_moka::{CloneArcError as _, FromArcError as _};
+                    (&&::cold_moka::ArcError::new(e)).into_error()
+                })
+            }
+        }
+    };
+    result
+        .map(|value| ::cold_moka::Return {
+            value,
+            was_cached: was_cached.get(),
+        })
+}
+#[allow(dead_code)]
+pub fn wrap_return_cache_clear() {
+    __COLD_MOKA_WRAP_RETURN.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn wrap_return_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_wrap_return(&inp);
+    __COLD_MOKA_WRAP_RETURN.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn wrap_return_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_wrap_return(&inp);
+    __COLD_MOKA_WRAP_RETURN.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn wrap_return_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_wrap_return(&inp);
+    __COLD_MOKA_WRAP_RETURN.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn wrap_return_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_wrap_return(&inp);
+    __COLD_MOKA_WRAP_RETURN.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn wrap_return_cache_entry_count() -> u64 {
+    __COLD_MOKA_WRAP_RETURN.run_pending_tasks();
+    __COLD_MOKA_WRAP_RETURN.entry_count()
+}
+#[allow(dead_code)]
+pub fn wrap_return_cache_weighted_size() -> u64 {
+    __COLD_MOKA_WRAP_RETURN.run_pending_tasks();
+    __COLD_MOKA_WRAP_RETURN.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn wrap_return_cache_sync() {
+    __COLD_MOKA_WRAP_RETURN.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn wrap_return_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_wrap_return(&inp);
+        let present = __COLD_MOKA_WRAP_RETURN.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = wrap_return(inp);
+        }
+    }
+    warmed
+}
+pub fn hook(_: &i32) {}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_LOOKUP_HOOKS: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_lookup_hooks(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn lookup_hooks(inp: i32) -> i32 {
+    fn __cold_moka_inner_lookup_hooks(inp: i32) -> i32 {
+        inp
+    }
+    let key = __cold_moka_key_lookup_hooks(&inp);
+    let entry = __COLD_MOKA_LOOKUP_HOOKS
+        .entry_by_ref(&key)
+        .or_insert_with(|| __cold_moka_inner_lookup_hooks(inp));
+    let fresh = entry.is_fresh();
+    if fresh {
+        hook(&key);
+    } else {
+        hook(&key);
+    }
+    entry.into_value()
+}
+#[allow(dead_code)]
+pub fn lookup_hooks_cache_clear() {
+    __COLD_MOKA_LOOKUP_HOOKS.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn lookup_hooks_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_lookup_hooks(&inp);
+    __COLD_MOKA_LOOKUP_HOOKS.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn lookup_hooks_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_lookup_hooks(&inp);
+    __COLD_MOKA_LOOKUP_HOOKS.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn lookup_hooks_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_lookup_hooks(&inp);
+    __COLD_MOKA_LOOKUP_HOOKS.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn lookup_hooks_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_lookup_hooks(&inp);
+    __COLD_MOKA_LOOKUP_HOOKS.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn lookup_hooks_cache_entry_count() -> u64 {
+    __COLD_MOKA_LOOKUP_HOOKS.run_pending_tasks();
+    __COLD_MOKA_LOOKUP_HOOKS.entry_count()
+}
+#[allow(dead_code)]
+pub fn lookup_hooks_cache_weighted_size() -> u64 {
+    __COLD_MOKA_LOOKUP_HOOKS.run_pending_tasks();
+    __COLD_MOKA_LOOKUP_HOOKS.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn lookup_hooks_cache_sync() {
+    __COLD_MOKA_LOOKUP_HOOKS.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn lookup_hooks_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_lookup_hooks(&inp);
+        let present = __COLD_MOKA_LOOKUP_HOOKS.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = lookup_hooks(inp);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<std::sync::Arc<i32>>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_RETURNS_ARC: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), std::sync::Arc<i32>>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_returns_arc(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn returns_arc(inp: i32) -> std::sync::Arc<i32> {
+    fn __cold_moka_inner_returns_arc(inp: i32) -> std::sync::Arc<i32> {
+        std::sync::Arc::new(inp)
+    }
+    let key = __cold_moka_key_returns_arc(&inp);
+    __COLD_MOKA_RETURNS_ARC.get_with_by_ref(&key, || __cold_moka_inner_returns_arc(inp))
+}
+#[allow(dead_code)]
+pub fn returns_arc_cache_clear() {
+    __COLD_MOKA_RETURNS_ARC.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn returns_arc_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_returns_arc(&inp);
+    __COLD_MOKA_RETURNS_ARC.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn returns_arc_cache_insert(inp: i32, value: std::sync::Arc<i32>) {
+    let key = __cold_moka_key_returns_arc(&inp);
+    __COLD_MOKA_RETURNS_ARC.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn returns_arc_if_cached(inp: i32) -> ::std::option::Option<std::sync::Arc<i32>> {
+    let key = __cold_moka_key_returns_arc(&inp);
+    __COLD_MOKA_RETURNS_ARC.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn returns_arc_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_returns_arc(&inp);
+    __COLD_MOKA_RETURNS_ARC.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn returns_arc_cache_entry_count() -> u64 {
+    __COLD_MOKA_RETURNS_ARC.run_pending_tasks();
+    __COLD_MOKA_RETURNS_ARC.entry_count()
+}
+#[allow(dead_code)]
+pub fn returns_arc_cache_weighted_size() -> u64 {
+    __COLD_MOKA_RETURNS_ARC.run_pending_tasks();
+    __COLD_MOKA_RETURNS_ARC.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn returns_arc_cache_sync() {
+    __COLD_MOKA_RETURNS_ARC.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn returns_arc_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_returns_arc(&inp);
+        let present = __COLD_MOKA_RETURNS_ARC.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = returns_arc(inp);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<std::sync::Arc<i32>>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_RETURNS_ARC_RESULT: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), std::sync::Arc<i32>>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_returns_arc_result(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn returns_arc_result(inp: i32) -> Result<std::sync::Arc<i32>, i32> {
+    fn __cold_moka_inner_returns_arc_result(
+        inp: i32,
+    ) -> Result<std::sync::Arc<i32>, i32> {
+        Ok(std::sync::Arc::new(inp))
+    }
+    let key = __cold_moka_key_returns_arc_result(&inp);
+    let result = __COLD_MOKA_RETURNS_ARC_RESULT
+        .try_get_with_by_ref(&key, || __cold_moka_inner_returns_arc_result(inp));
+    match result {
+        Ok(v) => Ok(v),
+        Err(e) => {
+            Err({
+                #[allow(unused_imports)]
+                use ::cold_moka::{CloneArcError as _, FromArcError as _};
+                (&&::cold_moka::ArcError::new(e)).into_error()
+            })
+        }
+    }
+}
+#[allow(dead_code)]
+pub fn returns_arc_result_cache_clear() {
+    __COLD_MOKA_RETURNS_ARC_RESULT.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn returns_arc_result_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_returns_arc_result(&inp);
+    __COLD_MOKA_RETURNS_ARC_RESULT.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn returns_arc_result_cache_insert(inp: i32, value: std::sync::Arc<i32>) {
+    let key = __cold_moka_key_returns_arc_result(&inp);
+    __COLD_MOKA_RETURNS_ARC_RESULT.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn returns_arc_result_if_cached(
+    inp: i32,
+) -> ::std::option::Option<std::sync::Arc<i32>> {
+    let key = __cold_moka_key_returns_arc_result(&inp);
+    __COLD_MOKA_RETURNS_ARC_RESULT.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn returns_arc_result_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_returns_arc_result(&inp);
+    __COLD_MOKA_RETURNS_ARC_RESULT.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn returns_arc_result_cache_entry_count() -> u64 {
+    __COLD_MOKA_RETURNS_ARC_RESULT.run_pending_tasks();
+    __COLD_MOKA_RETURNS_ARC_RESULT.entry_count()
+}
+#[allow(dead_code)]
+pub fn returns_arc_result_cache_weighted_size() -> u64 {
+    __COLD_MOKA_RETURNS_ARC_RESULT.run_pending_tasks();
+    __COLD_MOKA_RETURNS_ARC_RESULT.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn returns_arc_result_cache_sync() {
+    __COLD_MOKA_RETURNS_ARC_RESULT.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn returns_arc_result_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_returns_arc_result(&inp);
+        let present = __COLD_MOKA_RETURNS_ARC_RESULT.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = returns_arc_result(inp);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<std::sync::Arc<i32>>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_RETURNS_ARC_OPTION: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), std::sync::Arc<i32>>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_returns_arc_option(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn returns_arc_option(inp: i32) -> Option<std::sync::Arc<i32>> {
+    fn __cold_moka_inner_returns_arc_option(inp: i32) -> Option<std::sync::Arc<i32>> {
+        Some(std::sync::Arc::new(inp))
+    }
+    let key = __cold_moka_key_returns_arc_option(&inp);
+    __COLD_MOKA_RETURNS_ARC_OPTION
+        .optionally_get_with_by_ref(&key, || __cold_moka_inner_returns_arc_option(inp))
+}
+#[allow(dead_code)]
+pub fn returns_arc_option_cache_clear() {
+    __COLD_MOKA_RETURNS_ARC_OPTION.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn returns_arc_option_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_returns_arc_option(&inp);
+    __COLD_MOKA_RETURNS_ARC_OPTION.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn returns_arc_option_cache_insert(inp: i32, value: std::sync::Arc<i32>) {
+    let key = __cold_moka_key_returns_arc_option(&inp);
+    __COLD_MOKA_RETURNS_ARC_OPTION.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn returns_arc_option_if_cached(
+    inp: i32,
+) -> ::std::option::Option<std::sync::Arc<i32>> {
+    let key = __cold_moka_key_returns_arc_option(&inp);
+    __COLD_MOKA_RETURNS_ARC_OPTION.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn returns_arc_option_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_returns_arc_option(&inp);
+    __COLD_MOKA_RETURNS_ARC_OPTION.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn returns_arc_option_cache_entry_count() -> u64 {
+    __COLD_MOKA_RETURNS_ARC_OPTION.run_pending_tasks();
+    __COLD_MOKA_RETURNS_ARC_OPTION.entry_count()
+}
+#[allow(dead_code)]
+pub fn returns_arc_option_cache_weighted_size() -> u64 {
+    __COLD_MOKA_RETURNS_ARC_OPTION.run_pending_tasks();
+    __COLD_MOKA_RETURNS_ARC_OPTION.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn returns_arc_option_cache_sync() {
+    __COLD_MOKA_RETURNS_ARC_OPTION.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn returns_arc_option_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_returns_arc_option(&inp);
+        let present = __COLD_MOKA_RETURNS_ARC_OPTION.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = returns_arc_option(inp);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_RESULT_FALLBACK_STALE: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::StaleValues<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| ::cold_moka::StaleValues::new(1000usize));
+static __COLD_MOKA_RESULT_FALLBACK: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder()
+        .max_capacity(1000u64)
+        .time_to_live(::std::time::Duration::from_secs(60u64))
+        .eviction_listener(|
+            key: ::std::sync::Arc<(i32)>,
+            value: i32,
+            cause: ::cold_moka::moka::notification::RemovalCause|
+        {
+            match cause {
+                ::cold_moka::moka::notification::RemovalCause::Expired
+                | ::cold_moka::moka::notification::RemovalCause::Size => {
+                    __COLD_MOKA_RESULT_FALLBACK_STALE.keep((*key).clone(), value)
+                }
+                ::cold_moka::moka::notification::RemovalCause::Explicit => {
+                    __COLD_MOKA_RESULT_FALLBACK_STALE.forget(&key)
+                }
+                ::cold_moka::moka::notification::RemovalCause::Replaced => {}
+            }
+        })
+        .build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_result_fallback(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn result_fallback(inp: i32) -> Result<i32, i32> {
+    fn __cold_moka_inner_result_fallback(inp: i32) -> Result<i32, i32> {
+        Ok(inp)
+    }
+    let key = __cold_moka_key_result_fallback(&inp);
+    match {
+        let result = __COLD_MOKA_RESULT_FALLBACK
+            .try_get_with_by_ref(&key, || __cold_moka_inner_result_fallback(inp));
+        match result {
+            Ok(v) => Ok(v),
+            Err(e) => {
+                Err({
+                    #[allow(unused_imports)]
+                    use ::cold_moka::{CloneArcError as _, FromArcError as _};
+                    (&&::cold_moka::ArcError::new(e)).into_error()
+                })
+            }
+        }
+    } {
+        Err(e) => {
+            match __COLD_MOKA_RESULT_FALLBACK
+                .get(&key)
+                .or_else(|| __COLD_MOKA_RESULT_FALLBACK_STALE.get(&key))
+            {
+                Some(value) => Ok(value),
+                None => Err(e),
+            }
+        }
+        result => result,
+    }
+}
+#[allow(dead_code)]
+pub fn result_fallback_cache_clear() {
+    __COLD_MOKA_RESULT_FALLBACK.invalidate_all();
+    __COLD_MOKA_RESULT_FALLBACK_STALE.clear();
+}
+#[allow(dead_code, unused_variables)]
+pub fn result_fallback_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_result_fallback(&inp);
+    __COLD_MOKA_RESULT_FALLBACK_STALE.forget(&key);
+    __COLD_MOKA_RESULT_FALLBACK.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn result_fallback_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_result_fallback(&inp);
+    __COLD_MOKA_RESULT_FALLBACK.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn result_fallback_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_result_fallback(&inp);
+    __COLD_MOKA_RESULT_FALLBACK.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn result_fallback_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_result_fallback(&inp);
+    __COLD_MOKA_RESULT_FALLBACK.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn result_fallback_cache_entry_count() -> u64 {
+    __COLD_MOKA_RESULT_FALLBACK.run_pending_tasks();
+    __COLD_MOKA_RESULT_FALLBACK.entry_count()
+}
+#[allow(dead_code)]
+pub fn result_fallback_cache_weighted_size() -> u64 {
+    __COLD_MOKA_RESULT_FALLBACK.run_pending_tasks();
+    __COLD_MOKA_RESULT_FALLBACK.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn result_fallback_cache_sync() {
+    __COLD_MOKA_RESULT_FALLBACK.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn result_fallback_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_result_fallback(&inp);
+        let present = __COLD_MOKA_RESULT_FALLBACK.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = result_fallback(inp);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_CACHE_IF: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_cache_if(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn cache_if(inp: i32) -> Result<i32, i32> {
+    fn __cold_moka_inner_cache_if(inp: i32) -> Result<i32, i32> {
+        Ok(inp)
+    }
+    let key = __cold_moka_key_cache_if(&inp);
+    let result = {
+        let result = __COLD_MOKA_CACHE_IF
+            .try_get_with_by_ref(&key, || __cold_moka_inner_cache_if(inp));
+        match result {
+            Ok(v) => Ok(v),
+            Err(e) => {
+                Err({
+                    #[allow(unused_imports)]
+                    use ::cold_moka::{CloneArcError as _, FromArcError as _};
+                    (&&::cold_moka::ArcError::new(e)).into_error()
+                })
+            }
+        }
+    };
+    if let Ok(value) = &result {
+        if !(|v: &i32| *v > 0)(value) {
+            __COLD_MOKA_CACHE_IF.invalidate(&key);
+        }
+    }
+    result
+}
+#[allow(dead_code)]
+pub fn cache_if_cache_clear() {
+    __COLD_MOKA_CACHE_IF.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn cache_if_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_cache_if(&inp);
+    __COLD_MOKA_CACHE_IF.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn cache_if_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_cache_if(&inp);
+    __COLD_MOKA_CACHE_IF.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn cache_if_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_cache_if(&inp);
+    __COLD_MOKA_CACHE_IF.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn cache_if_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_cache_if(&inp);
+    __COLD_MOKA_CACHE_IF.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn cache_if_cache_entry_count() -> u64 {
+    __COLD_MOKA_CACHE_IF.run_pending_tasks();
+    __COLD_MOKA_CACHE_IF.entry_count()
+}
+#[allow(dead_code)]
+pub fn cache_if_cache_weighted_size() -> u64 {
+    __COLD_MOKA_CACHE_IF.run_pending_tasks();
+    __COLD_MOKA_CACHE_IF.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn cache_if_cache_sync() {
+    __COLD_MOKA_CACHE_IF.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn cache_if_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_cache_if(&inp);
+        let present = __COLD_MOKA_CACHE_IF.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = cache_if(inp);
+        }
+    }
+    warmed
+}
+pub type IntLookup = Result<i32, i32>;
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_VALUE_TYPE: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_value_type(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn value_type(inp: i32) -> IntLookup {
+    fn __cold_moka_inner_value_type(inp: i32) -> IntLookup {
+        Ok(inp)
+    }
+    let key = __cold_moka_key_value_type(&inp);
+    let result = __COLD_MOKA_VALUE_TYPE
+        .try_get_with_by_ref(&key, || __cold_moka_inner_value_type(inp));
+    match result {
+        Ok(v) => Ok(v),
+        Err(e) => {
+            Err({
+                #[allow(unused_imports)]
+                use ::cold_moka::{CloneArcError as _, FromArcError as _};
+                (&&::cold_moka::ArcError::new(e)).into_error()
+            })
+        }
+    }
+}
+#[allow(dead_code)]
+pub fn value_type_cache_clear() {
+    __COLD_MOKA_VALUE_TYPE.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn value_type_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_value_type(&inp);
+    __COLD_MOKA_VALUE_TYPE.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn value_type_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_value_type(&inp);
+    __COLD_MOKA_VALUE_TYPE.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn value_type_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_value_type(&inp);
+    __COLD_MOKA_VALUE_TYPE.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn value_type_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_value_type(&inp);
+    __COLD_MOKA_VALUE_TYPE.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn value_type_cache_entry_count() -> u64 {
+    __COLD_MOKA_VALUE_TYPE.run_pending_tasks();
+    __COLD_MOKA_VALUE_TYPE.entry_count()
+}
+#[allow(dead_code)]
+pub fn value_type_cache_weighted_size() -> u64 {
+    __COLD_MOKA_VALUE_TYPE.run_pending_tasks();
+    __COLD_MOKA_VALUE_TYPE.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn value_type_cache_sync() {
+    __COLD_MOKA_VALUE_TYPE.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn value_type_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_value_type(&inp);
+        let present = __COLD_MOKA_VALUE_TYPE.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = value_type(inp);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+    cached_key::<String>();
+};
+static __COLD_MOKA_DESTRUCTURED: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32, String), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_destructured(a: &i32, c: &String) -> (i32, String) {
+    (a.clone(), c.clone())
+}
+pub fn destructured((a, _b): (i32, i32), c: String) -> i32 {
+    fn __cold_moka_inner_destructured((a, _b): (i32, i32), c: String) -> i32 {
+        a + c.len() as i32
+    }
+    let key = __cold_moka_key_destructured(&a, &c);
+    __COLD_MOKA_DESTRUCTURED
+        .get_with_by_ref(&key, || __cold_moka_inner_destructured((a, _b), c))
+}
+#[allow(dead_code)]
+pub fn destructured_cache_clear() {
+    __COLD_MOKA_DESTRUCTURED.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn destructured_cache_invalidate(a: i32, c: String) {
+    let key = __cold_moka_key_destructured(&a, &c);
+    __COLD_MOKA_DESTRUCTURED.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn destructured_cache_insert(a: i32, c: String, value: i32) {
+    let key = __cold_moka_key_destructured(&a, &c);
+    __COLD_MOKA_DESTRUCTURED.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn destructured_if_cached(a: i32, c: String) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_destructured(&a, &c);
+    __COLD_MOKA_DESTRUCTURED.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn destructured_cache_contains(a: i32, c: String) -> bool {
+    let key = __cold_moka_key_destructured(&a, &c);
+    __COLD_MOKA_DESTRUCTURED.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn destructured_cache_entry_count() -> u64 {
+    __COLD_MOKA_DESTRUCTURED.run_pending_tasks();
+    __COLD_MOKA_DESTRUCTURED.entry_count()
+}
+#[allow(dead_code)]
+pub fn destructured_cache_weighted_size() -> u64 {
+    __COLD_MOKA_DESTRUCTURED.run_pending_tasks();
+    __COLD_MOKA_DESTRUCTURED.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn destructured_cache_sync() {
+    __COLD_MOKA_DESTRUCTURED.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn destructured_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = ((i32, i32), String)>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for ((a, _b), c) in inputs {
+        let key = __cold_moka_key_destructured(&a, &c);
+        let present = __COLD_MOKA_DESTRUCTURED.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = destructured((a, _b), c);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<(i32, std::time::Duration)>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_VALUE_TTL: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), (i32, std::time::Duration)>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder()
+        .max_capacity(1000u64)
+        .expire_after(::cold_moka::ValueTtl)
+        .build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_value_ttl(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn value_ttl(inp: i32) -> (i32, std::time::Duration) {
+    fn __cold_moka_inner_value_ttl(inp: i32) -> (i32, std::time::Duration) {
+        (inp, std::time::Duration::from_secs(60))
+    }
+    let key = __cold_moka_key_value_ttl(&inp);
+    __COLD_MOKA_VALUE_TTL.get_with_by_ref(&key, || __cold_moka_inner_value_ttl(inp))
+}
+#[allow(dead_code)]
+pub fn value_ttl_cache_clear() {
+    __COLD_MOKA_VALUE_TTL.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn value_ttl_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_value_ttl(&inp);
+    __COLD_MOKA_VALUE_TTL.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn value_ttl_cache_insert(inp: i32, value: (i32, std::time::Duration)) {
+    let key = __cold_moka_key_value_ttl(&inp);
+    __COLD_MOKA_VALUE_TTL.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn value_ttl_if_cached(
+    inp: i32,
+) -> ::std::option::Option<(i32, std::time::Duration)> {
+    let key = __cold_moka_key_value_ttl(&inp);
+    __COLD_MOKA_VALUE_TTL.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn value_ttl_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_value_ttl(&inp);
+    __COLD_MOKA_VALUE_TTL.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn value_ttl_cache_entry_count() -> u64 {
+    __COLD_MOKA_VALUE_TTL.run_pending_tasks();
+    __COLD_MOKA_VALUE_TTL.entry_count()
+}
+#[allow(dead_code)]
+pub fn value_ttl_cache_weighted_size() -> u64 {
+    __COLD_MOKA_VALUE_TTL.run_pending_tasks();
+    __COLD_MOKA_VALUE_TTL.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn value_ttl_cache_sync() {
+    __COLD_MOKA_VALUE_TTL.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn value_ttl_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_value_ttl(&inp);
+        let present = __COLD_MOKA_VALUE_TTL.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = value_ttl(inp);
+        }
+    }
+    warmed
+}
+pub static ENABLED: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(
+    true,
+);
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_ENABLED: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_enabled(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn enabled(inp: i32) -> i32 {
+    fn __cold_moka_inner_enabled(inp: i32) -> i32 {
+        inp
+    }
+    let key = __cold_moka_key_enabled(&inp);
+    if !::cold_moka::is_enabled(&ENABLED) {
+        return __cold_moka_inner_enabled(inp);
+    }
+    __COLD_MOKA_ENABLED.get_with_by_ref(&key, || __cold_moka_inner_enabled(inp))
+}
+#[allow(dead_code)]
+pub fn enabled_cache_clear() {
+    __COLD_MOKA_ENABLED.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn enabled_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_enabled(&inp);
+    __COLD_MOKA_ENABLED.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn enabled_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_enabled(&inp);
+    __COLD_MOKA_ENABLED.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn enabled_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_enabled(&inp);
+    __COLD_MOKA_ENABLED.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn enabled_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_enabled(&inp);
+    __COLD_MOKA_ENABLED.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn enabled_cache_entry_count() -> u64 {
+    __COLD_MOKA_ENABLED.run_pending_tasks();
+    __COLD_MOKA_ENABLED.entry_count()
+}
+#[allow(dead_code)]
+pub fn enabled_cache_weighted_size() -> u64 {
+    __COLD_MOKA_ENABLED.run_pending_tasks();
+    __COLD_MOKA_ENABLED.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn enabled_cache_sync() {
+    __COLD_MOKA_ENABLED.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn enabled_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_enabled(&inp);
+        let present = __COLD_MOKA_ENABLED.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = enabled(inp);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<usize>();
+    cached_key::<::std::string::String>();
+    cached_key::<::std::vec::Vec<u8>>();
+    cached_key::<u64>();
+};
+static __COLD_MOKA_BORROWED_KEY: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<
+        (::std::string::String, ::std::vec::Vec<u8>, u64),
+        usize,
+    >,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_borrowed_key(
+    name: &&str,
+    bytes: &&[u8],
+    id: &&u64,
+) -> (::std::string::String, ::std::vec::Vec<u8>, u64) {
+    (
+        ::std::borrow::ToOwned::to_owned(&**name),
+        ::std::borrow::ToOwned::to_owned(&**bytes),
+        ::std::borrow::ToOwned::to_owned(&**id),
+    )
+}
+pub fn borrowed_key(name: &str, bytes: &[u8], id: &u64) -> usize {
+    fn __cold_moka_inner_borrowed_key(name: &str, bytes: &[u8], id: &u64) -> usize {
+        name.len() + bytes.len() + *id as usize
+    }
+    let key = __cold_moka_key_borrowed_key(&name, &bytes, &id);
+    __COLD_MOKA_BORROWED_KEY
+        .get_with_by_ref(&key, || __cold_moka_inner_borrowed_key(name, bytes, id))
+}
+#[allow(dead_code)]
+pub fn borrowed_key_cache_clear() {
+    __COLD_MOKA_BORROWED_KEY.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn borrowed_key_cache_invalidate(name: &str, bytes: &[u8], id: &u64) {
+    let key = __cold_moka_key_borrowed_key(&name, &bytes, &id);
+    __COLD_MOKA_BORROWED_KEY.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn borrowed_key_cache_insert(name: &str, bytes: &[u8], id: &u64, value: usize) {
+    let key = __cold_moka_key_borrowed_key(&name, &bytes, &id);
+    __COLD_MOKA_BORROWED_KEY.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn borrowed_key_if_cached(
+    name: &str,
+    bytes: &[u8],
+    id: &u64,
+) -> ::std::option::Option<usize> {
+    let key = __cold_moka_key_borrowed_key(&name, &bytes, &id);
+    __COLD_MOKA_BORROWED_KEY.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn borrowed_key_cache_contains(name: &str, bytes: &[u8], id: &u64) -> bool {
+    let key = __cold_moka_key_borrowed_key(&name, &bytes, &id);
+    __COLD_MOKA_BORROWED_KEY.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn borrowed_key_cache_entry_count() -> u64 {
+    __COLD_MOKA_BORROWED_KEY.run_pending_tasks();
+    __COLD_MOKA_BORROWED_KEY.entry_count()
+}
+#[allow(dead_code)]
+pub fn borrowed_key_cache_weighted_size() -> u64 {
+    __COLD_MOKA_BORROWED_KEY.run_pending_tasks();
+    __COLD_MOKA_BORROWED_KEY.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn borrowed_key_cache_sync() {
+    __COLD_MOKA_BORROWED_KEY.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn borrowed_key_warm<'__cold_moka_input>(
+    inputs: impl ::std::iter::IntoIterator<
+        Item = (
+            &'__cold_moka_input str,
+            &'__cold_moka_input [u8],
+            &'__cold_moka_input u64,
+        ),
+    >,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for (name, bytes, id) in inputs {
+        let key = __cold_moka_key_borrowed_key(&name, &bytes, &id);
+        let present = __COLD_MOKA_BORROWED_KEY.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = borrowed_key(name, bytes, id);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_NAMED_LIKE_LOCALS: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32, i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_named_like_locals(key: &i32, value: &i32) -> (i32, i32) {
+    (key.clone(), value.clone())
+}
+pub fn named_like_locals(key: i32, value: i32) -> i32 {
+    fn __cold_moka_inner_named_like_locals(key: i32, value: i32) -> i32 {
+        key + value
+    }
+    let key = __cold_moka_key_named_like_locals(&key, &value);
+    __COLD_MOKA_NAMED_LIKE_LOCALS
+        .get_with_by_ref(&key, || __cold_moka_inner_named_like_locals(key, value))
+}
+#[allow(dead_code)]
+pub fn named_like_locals_cache_clear() {
+    __COLD_MOKA_NAMED_LIKE_LOCALS.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn named_like_locals_cache_invalidate(key: i32, value: i32) {
+    let key = __cold_moka_key_named_like_locals(&key, &value);
+    __COLD_MOKA_NAMED_LIKE_LOCALS.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn named_like_locals_cache_insert(key: i32, value: i32, value: i32) {
+    let key = __cold_moka_key_named_like_locals(&key, &value);
+    __COLD_MOKA_NAMED_LIKE_LOCALS.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn named_like_locals_if_cached(key: i32, value: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_named_like_locals(&key, &value);
+    __COLD_MOKA_NAMED_LIKE_LOCALS.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn named_like_locals_cache_contains(key: i32, value: i32) -> bool {
+    let key = __cold_moka_key_named_like_locals(&key, &value);
+    __COLD_MOKA_NAMED_LIKE_LOCALS.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn named_like_locals_cache_entry_count() -> u64 {
+    __COLD_MOKA_NAMED_LIKE_LOCALS.run_pending_tasks();
+    __COLD_MOKA_NAMED_LIKE_LOCALS.entry_count()
+}
+#[allow(dead_code)]
+pub fn named_like_locals_cache_weighted_size() -> u64 {
+    __COLD_MOKA_NAMED_LIKE_LOCALS.run_pending_tasks();
+    __COLD_MOKA_NAMED_LIKE_LOCALS.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn named_like_locals_cache_sync() {
+    __COLD_MOKA_NAMED_LIKE_LOCALS.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn named_like_locals_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (i32, i32)>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for (key, value) in inputs {
+        let key = __cold_moka_key_named_like_locals(&key, &value);
+        let present = __COLD_MOKA_NAMED_LIKE_LOCALS.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = named_like_locals(key, value);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<(i32, u8)>();
+};
+static __COLD_MOKA_CONVERT_BLOCK: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32, u8), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_convert_block(a: &i32, b: &u8) -> (i32, u8) {
+    #[allow(unused_variables)]
+    let a = ::std::clone::Clone::clone(a);
+    #[allow(unused_variables)]
+    let b = ::std::clone::Clone::clone(b);
+    { (a.abs(), b) }
+}
+pub fn convert_block(a: i32, b: u8) -> i32 {
+    fn __cold_moka_inner_convert_block(a: i32, b: u8) -> i32 {
+        a + b as i32
+    }
+    let key = __cold_moka_key_convert_block(&a, &b);
+    __COLD_MOKA_CONVERT_BLOCK
+        .get_with_by_ref(&key, || __cold_moka_inner_convert_block(a, b))
+}
+#[allow(dead_code)]
+pub fn convert_block_cache_clear() {
+    __COLD_MOKA_CONVERT_BLOCK.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn convert_block_cache_invalidate(a: i32, b: u8) {
+    let key = __cold_moka_key_convert_block(&a, &b);
+    __COLD_MOKA_CONVERT_BLOCK.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn convert_block_cache_insert(a: i32, b: u8, value: i32) {
+    let key = __cold_moka_key_convert_block(&a, &b);
+    __COLD_MOKA_CONVERT_BLOCK.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn convert_block_if_cached(a: i32, b: u8) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_convert_block(&a, &b);
+    __COLD_MOKA_CONVERT_BLOCK.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn convert_block_cache_contains(a: i32, b: u8) -> bool {
+    let key = __cold_moka_key_convert_block(&a, &b);
+    __COLD_MOKA_CONVERT_BLOCK.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn convert_block_cache_entry_count() -> u64 {
+    __COLD_MOKA_CONVERT_BLOCK.run_pending_tasks();
+    __COLD_MOKA_CONVERT_BLOCK.entry_count()
+}
+#[allow(dead_code)]
+pub fn convert_block_cache_weighted_size() -> u64 {
+    __COLD_MOKA_CONVERT_BLOCK.run_pending_tasks();
+    __COLD_MOKA_CONVERT_BLOCK.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn convert_block_cache_sync() {
+    __COLD_MOKA_CONVERT_BLOCK.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn convert_block_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (i32, u8)>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for (a, b) in inputs {
+        let key = __cold_moka_key_convert_block(&a, &b);
+        let present = __COLD_MOKA_CONVERT_BLOCK.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = convert_block(a, b);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<(i32, u8)>();
+};
+static __COLD_MOKA_CONVERT_EXPR: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32, u8), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_convert_expr(a: &i32, b: &u8) -> (i32, u8) {
+    #[allow(unused_variables)]
+    let a = ::std::clone::Clone::clone(a);
+    #[allow(unused_variables)]
+    let b = ::std::clone::Clone::clone(b);
+    { (a.abs(), b) }
+}
+pub fn convert_expr(a: i32, b: u8) -> i32 {
+    fn __cold_moka_inner_convert_expr(a: i32, b: u8) -> i32 {
+        a + b as i32
+    }
+    let key = __cold_moka_key_convert_expr(&a, &b);
+    __COLD_MOKA_CONVERT_EXPR
+        .get_with_by_ref(&key, || __cold_moka_inner_convert_expr(a, b))
+}
+#[allow(dead_code)]
+pub fn convert_expr_cache_clear() {
+    __COLD_MOKA_CONVERT_EXPR.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn convert_expr_cache_invalidate(a: i32, b: u8) {
+    let key = __cold_moka_key_convert_expr(&a, &b);
+    __COLD_MOKA_CONVERT_EXPR.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn convert_expr_cache_insert(a: i32, b: u8, value: i32) {
+    let key = __cold_moka_key_convert_expr(&a, &b);
+    __COLD_MOKA_CONVERT_EXPR.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn convert_expr_if_cached(a: i32, b: u8) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_convert_expr(&a, &b);
+    __COLD_MOKA_CONVERT_EXPR.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn convert_expr_cache_contains(a: i32, b: u8) -> bool {
+    let key = __cold_moka_key_convert_expr(&a, &b);
+    __COLD_MOKA_CONVERT_EXPR.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn convert_expr_cache_entry_count() -> u64 {
+    __COLD_MOKA_CONVERT_EXPR.run_pending_tasks();
+    __COLD_MOKA_CONVERT_EXPR.entry_count()
+}
+#[allow(dead_code)]
+pub fn convert_expr_cache_weighted_size() -> u64 {
+    __COLD_MOKA_CONVERT_EXPR.run_pending_tasks();
+    __COLD_MOKA_CONVERT_EXPR.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn convert_expr_cache_sync() {
+    __COLD_MOKA_CONVERT_EXPR.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn convert_expr_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (i32, u8)>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for (a, b) in inputs {
+        let key = __cold_moka_key_convert_expr(&a, &b);
+        let present = __COLD_MOKA_CONVERT_EXPR.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = convert_expr(a, b);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_KEY_EXPR: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<i32, i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_key_expr(a: &i32, b: &u8) -> i32 {
+    #[allow(unused_variables)]
+    let a = ::std::clone::Clone::clone(a);
+    #[allow(unused_variables)]
+    let b = ::std::clone::Clone::clone(b);
+    { a.abs() }
+}
+pub fn key_expr(a: i32, b: u8) -> i32 {
+    fn __cold_moka_inner_key_expr(a: i32, b: u8) -> i32 {
+        a + b as i32
+    }
+    let key = __cold_moka_key_key_expr(&a, &b);
+    __COLD_MOKA_KEY_EXPR.get_with_by_ref(&key, || __cold_moka_inner_key_expr(a, b))
+}
+#[allow(dead_code)]
+pub fn key_expr_cache_clear() {
+    __COLD_MOKA_KEY_EXPR.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn key_expr_cache_invalidate(a: i32, b: u8) {
+    let key = __cold_moka_key_key_expr(&a, &b);
+    __COLD_MOKA_KEY_EXPR.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn key_expr_cache_insert(a: i32, b: u8, value: i32) {
+    let key = __cold_moka_key_key_expr(&a, &b);
+    __COLD_MOKA_KEY_EXPR.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn key_expr_if_cached(a: i32, b: u8) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_key_expr(&a, &b);
+    __COLD_MOKA_KEY_EXPR.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn key_expr_cache_contains(a: i32, b: u8) -> bool {
+    let key = __cold_moka_key_key_expr(&a, &b);
+    __COLD_MOKA_KEY_EXPR.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn key_expr_cache_entry_count() -> u64 {
+    __COLD_MOKA_KEY_EXPR.run_pending_tasks();
+    __COLD_MOKA_KEY_EXPR.entry_count()
+}
+#[allow(dead_code)]
+pub fn key_expr_cache_weighted_size() -> u64 {
+    __COLD_MOKA_KEY_EXPR.run_pending_tasks();
+    __COLD_MOKA_KEY_EXPR.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn key_expr_cache_sync() {
+    __COLD_MOKA_KEY_EXPR.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn key_expr_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (i32, u8)>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for (a, b) in inputs {
+        let key = __cold_moka_key_key_expr(&a, &b);
+        let present = __COLD_MOKA_KEY_EXPR.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = key_expr(a, b);
+        }
+    }
+    warmed
+}
+static __COLD_MOKA_ONCE_VALUE: ::cold_moka::once_cell::sync::OnceCell<String> = ::cold_moka::once_cell::sync::OnceCell::new();
+pub fn once_value() -> Result<String, i32> {
+    fn __cold_moka_inner_once_value() -> Result<String, i32> {
+        Ok("once".to_owned())
+    }
+    __COLD_MOKA_ONCE_VALUE
+        .get_or_try_init(|| __cold_moka_inner_once_value())
+        .map(::std::clone::Clone::clone)
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<String>();
+    cached_key::<::std::string::String>();
+};
+static __COLD_MOKA_DEBUG_KEY: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<::std::string::String, String>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_debug_key(a: &f64, b: &String) -> ::std::string::String {
+    ::alloc::__export::must_use({ ::alloc::fmt::format(format_args!("{0:?}", (a, b))) })
+}
+pub fn debug_key(_ctx: NoHash, a: f64, b: String) -> String {
+    fn __cold_moka_inner_debug_key(_ctx: NoHash, a: f64, b: String) -> String {
+        ::alloc::__export::must_use({
+            ::alloc::fmt::format(format_args!("{0}{1}", a, b))
+        })
+    }
+    let key = __cold_moka_key_debug_key(&a, &b);
+    __COLD_MOKA_DEBUG_KEY
+        .get_with_by_ref(&key, || __cold_moka_inner_debug_key(_ctx, a, b))
+}
+#[allow(dead_code)]
+pub fn debug_key_cache_clear() {
+    __COLD_MOKA_DEBUG_KEY.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn debug_key_cache_invalidate(a: f64, b: String) {
+    let key = __cold_moka_key_debug_key(&a, &b);
+    __COLD_MOKA_DEBUG_KEY.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn debug_key_cache_insert(a: f64, b: String, value: String) {
+    let key = __cold_moka_key_debug_key(&a, &b);
+    __COLD_MOKA_DEBUG_KEY.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn debug_key_if_cached(a: f64, b: String) -> ::std::option::Option<String> {
+    let key = __cold_moka_key_debug_key(&a, &b);
+    __COLD_MOKA_DEBUG_KEY.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn debug_key_cache_contains(a: f64, b: String) -> bool {
+    let key = __cold_moka_key_debug_key(&a, &b);
+    __COLD_MOKA_DEBUG_KEY.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn debug_key_cache_entry_count() -> u64 {
+    __COLD_MOKA_DEBUG_KEY.run_pending_tasks();
+    __COLD_MOKA_DEBUG_KEY.entry_count()
+}
+#[allow(dead_code)]
+pub fn debug_key_cache_weighted_size() -> u64 {
+    __COLD_MOKA_DEBUG_KEY.run_pending_tasks();
+    __COLD_MOKA_DEBUG_KEY.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn debug_key_cache_sync() {
+    __COLD_MOKA_DEBUG_KEY.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn debug_key_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (NoHash, f64, String)>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for (_ctx, a, b) in inputs {
+        let key = __cold_moka_key_debug_key(&a, &b);
+        let present = __COLD_MOKA_DEBUG_KEY.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = debug_key(_ctx, a, b);
+        }
+    }
+    warmed
+}
+pub static GROUP: cold_moka::once_cell::sync::Lazy<
+    cold_moka::moka::sync::Cache<i32, i32>,
+> = cold_moka::once_cell::sync::Lazy::new(|| cold_moka::moka::sync::Cache::new(10));
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+const _: fn() = || {
+    let _: &::cold_moka::moka::sync::Cache<(i32), i32> = ::std::ops::Deref::deref(
+        &GROUP,
+    );
+};
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_grouped(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn grouped(inp: i32) -> i32 {
+    fn __cold_moka_inner_grouped(inp: i32) -> i32 {
+        inp
+    }
+    let key = __cold_moka_key_grouped(&inp);
+    GROUP.get_with_by_ref(&key, || __cold_moka_inner_grouped(inp))
+}
+#[allow(dead_code)]
+pub fn grouped_cache_clear() {
+    GROUP.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn grouped_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_grouped(&inp);
+    GROUP.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn grouped_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_grouped(&inp);
+    GROUP.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn grouped_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_grouped(&inp);
+    GROUP.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn grouped_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_grouped(&inp);
+    GROUP.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn grouped_cache_entry_count() -> u64 {
+    GROUP.run_pending_tasks();
+    GROUP.entry_count()
+}
+#[allow(dead_code)]
+pub fn grouped_cache_weighted_size() -> u64 {
+    GROUP.run_pending_tasks();
+    GROUP.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn grouped_cache_sync() {
+    GROUP.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn grouped_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_grouped(&inp);
+        let present = GROUP.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = grouped(inp);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_SWAPPED: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::arc_swap::ArcSwap<::cold_moka::moka::sync::Cache<(i32), i32>>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| ::cold_moka::arc_swap::ArcSwap::from_pointee(
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build(),
+));
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_swapped(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn swapped(inp: i32) -> i32 {
+    fn __cold_moka_inner_swapped(inp: i32) -> i32 {
+        inp
+    }
+    let key = __cold_moka_key_swapped(&inp);
+    __COLD_MOKA_SWAPPED.load().get_with_by_ref(&key, || __cold_moka_inner_swapped(inp))
+}
+#[allow(dead_code)]
+pub fn swapped_cache_clear() {
+    __COLD_MOKA_SWAPPED.load().invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn swapped_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_swapped(&inp);
+    __COLD_MOKA_SWAPPED.load().invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn swapped_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_swapped(&inp);
+    __COLD_MOKA_SWAPPED.load().insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn swapped_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_swapped(&inp);
+    __COLD_MOKA_SWAPPED.load().get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn swapped_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_swapped(&inp);
+    __COLD_MOKA_SWAPPED.load().contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn swapped_cache_entry_count() -> u64 {
+    __COLD_MOKA_SWAPPED.load().run_pending_tasks();
+    __COLD_MOKA_SWAPPED.load().entry_count()
+}
+#[allow(dead_code)]
+pub fn swapped_cache_weighted_size() -> u64 {
+    __COLD_MOKA_SWAPPED.load().run_pending_tasks();
+    __COLD_MOKA_SWAPPED.load().weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn swapped_cache_sync() {
+    __COLD_MOKA_SWAPPED.load().run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn swapped_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_swapped(&inp);
+        let present = __COLD_MOKA_SWAPPED.load().contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = swapped(inp);
+        }
+    }
+    warmed
+}
+#[allow(dead_code)]
+pub fn swapped_cache_swap(entries: impl ::std::iter::IntoIterator<Item = ((i32), i32)>) {
+    let cache: ::cold_moka::moka::sync::Cache<(i32), i32> = ::cold_moka::moka::sync::Cache::builder()
+        .max_capacity(1000u64)
+        .build();
+    for (key, value) in entries {
+        cache.insert(key, value);
+    }
+    cache.run_pending_tasks();
+    __COLD_MOKA_SWAPPED.store(::std::sync::Arc::new(cache));
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_BOXED_ERROR: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_boxed_error(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn boxed_error(inp: i32) -> Result<i32, Box<dyn std::error::Error + Send + Sync>> {
+    fn __cold_moka_inner_boxed_error(
+        inp: i32,
+    ) -> Result<i32, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(inp)
+    }
+    let key = __cold_moka_key_boxed_error(&inp);
+    let result = __COLD_MOKA_BOXED_ERROR
+        .try_get_with_by_ref(&key, || __cold_moka_inner_boxed_error(inp));
+    match result {
+        Ok(v) => Ok(v),
+        Err(e) => Err(::std::convert::From::from(::cold_moka::SharedError::from(e))),
+    }
+}
+#[allow(dead_code)]
+pub fn boxed_error_cache_clear() {
+    __COLD_MOKA_BOXED_ERROR.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn boxed_error_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_boxed_error(&inp);
+    __COLD_MOKA_BOXED_ERROR.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn boxed_error_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_boxed_error(&inp);
+    __COLD_MOKA_BOXED_ERROR.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn boxed_error_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_boxed_error(&inp);
+    __COLD_MOKA_BOXED_ERROR.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn boxed_error_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_boxed_error(&inp);
+    __COLD_MOKA_BOXED_ERROR.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn boxed_error_cache_entry_count() -> u64 {
+    __COLD_MOKA_BOXED_ERROR.run_pending_tasks();
+    __COLD_MOKA_BOXED_ERROR.entry_count()
+}
+#[allow(dead_code)]
+pub fn boxed_error_cache_weighted_size() -> u64 {
+    __COLD_MOKA_BOXED_ERROR.run_pending_tasks();
+    __COLD_MOKA_BOXED_ERROR.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn boxed_error_cache_sync() {
+    __COLD_MOKA_BOXED_ERROR.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn boxed_error_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_boxed_error(&inp);
+        let present = __COLD_MOKA_BOXED_ERROR.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = boxed_error(inp);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<(i32, Vec<u8>)>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_TUPLE_RETURN: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), (i32, Vec<u8>)>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_tuple_return(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn tuple_return(inp: i32) -> (i32, Vec<u8>) {
+    fn __cold_moka_inner_tuple_return(inp: i32) -> (i32, Vec<u8>) {
+        (inp, Vec::new())
+    }
+    let key = __cold_moka_key_tuple_return(&inp);
+    __COLD_MOKA_TUPLE_RETURN
+        .get_with_by_ref(&key, || __cold_moka_inner_tuple_return(inp))
+}
+#[allow(dead_code)]
+pub fn tuple_return_cache_clear() {
+    __COLD_MOKA_TUPLE_RETURN.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn tuple_return_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_tuple_return(&inp);
+    __COLD_MOKA_TUPLE_RETURN.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn tuple_return_cache_insert(inp: i32, value: (i32, Vec<u8>)) {
+    let key = __cold_moka_key_tuple_return(&inp);
+    __COLD_MOKA_TUPLE_RETURN.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn tuple_return_if_cached(inp: i32) -> ::std::option::Option<(i32, Vec<u8>)> {
+    let key = __cold_moka_key_tuple_return(&inp);
+    __COLD_MOKA_TUPLE_RETURN.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn tuple_return_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_tuple_return(&inp);
+    __COLD_MOKA_TUPLE_RETURN.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn tuple_return_cache_entry_count() -> u64 {
+    __COLD_MOKA_TUPLE_RETURN.run_pending_tasks();
+    __COLD_MOKA_TUPLE_RETURN.entry_count()
+}
+#[allow(dead_code)]
+pub fn tuple_return_cache_weighted_size() -> u64 {
+    __COLD_MOKA_TUPLE_RETURN.run_pending_tasks();
+    __COLD_MOKA_TUPLE_RETURN.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn tuple_return_cache_sync() {
+    __COLD_MOKA_TUPLE_RETURN.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn tuple_return_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_tuple_return(&inp);
+        let present = __COLD_MOKA_TUPLE_RETURN.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = tuple_return(inp);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<&'static str>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_STATIC_REF_RETURN: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), &'static str>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_static_ref_return(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn static_ref_return(inp: i32) -> &'static str {
+    fn __cold_moka_inner_static_ref_return(inp: i32) -> &'static str {
+        if inp > 0 { "positive" } else { "other" }
+    }
+    let key = __cold_moka_key_static_ref_return(&inp);
+    __COLD_MOKA_STATIC_REF_RETURN
+        .get_with_by_ref(&key, || __cold_moka_inner_static_ref_return(inp))
+}
+#[allow(dead_code)]
+pub fn static_ref_return_cache_clear() {
+    __COLD_MOKA_STATIC_REF_RETURN.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn static_ref_return_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_static_ref_return(&inp);
+    __COLD_MOKA_STATIC_REF_RETURN.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn static_ref_return_cache_insert(inp: i32, value: &'static str) {
+    let key = __cold_moka_key_static_ref_return(&inp);
+    __COLD_MOKA_STATIC_REF_RETURN.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn static_ref_return_if_cached(inp: i32) -> ::std::option::Option<&'static str> {
+    let key = __cold_moka_key_static_ref_return(&inp);
+    __COLD_MOKA_STATIC_REF_RETURN.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn static_ref_return_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_static_ref_return(&inp);
+    __COLD_MOKA_STATIC_REF_RETURN.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn static_ref_return_cache_entry_count() -> u64 {
+    __COLD_MOKA_STATIC_REF_RETURN.run_pending_tasks();
+    __COLD_MOKA_STATIC_REF_RETURN.entry_count()
+}
+#[allow(dead_code)]
+pub fn static_ref_return_cache_weighted_size() -> u64 {
+    __COLD_MOKA_STATIC_REF_RETURN.run_pending_tasks();
+    __COLD_MOKA_STATIC_REF_RETURN.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn static_ref_return_cache_sync() {
+    __COLD_MOKA_STATIC_REF_RETURN.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn static_ref_return_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_static_ref_return(&inp);
+        let present = __COLD_MOKA_STATIC_REF_RETURN.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = static_ref_return(inp);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_WITH_STATS_STATS: ::cold_moka::HitMissCounters = ::cold_moka::HitMissCounters::new();
+static __COLD_MOKA_WITH_STATS: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_with_stats(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn with_stats(inp: i32) -> Option<i32> {
+    fn __cold_moka_inner_with_stats(inp: i32) -> Option<i32> {
+        Some(inp)
+    }
+    let key = __cold_moka_key_with_stats(&inp);
+    __COLD_MOKA_WITH_STATS_STATS.call();
+    __COLD_MOKA_WITH_STATS
+        .optionally_get_with_by_ref(
+            &key,
+            || {
+                __COLD_MOKA_WITH_STATS_STATS.miss();
+                __cold_moka_inner_with_stats(inp)
+            },
+        )
+}
+#[allow(dead_code)]
+pub fn with_stats_cache_clear() {
+    __COLD_MOKA_WITH_STATS.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn with_stats_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_with_stats(&inp);
+    __COLD_MOKA_WITH_STATS.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn with_stats_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_with_stats(&inp);
+    __COLD_MOKA_WITH_STATS.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn with_stats_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_with_stats(&inp);
+    __COLD_MOKA_WITH_STATS.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn with_stats_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_with_stats(&inp);
+    __COLD_MOKA_WITH_STATS.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn with_stats_cache_entry_count() -> u64 {
+    __COLD_MOKA_WITH_STATS.run_pending_tasks();
+    __COLD_MOKA_WITH_STATS.entry_count()
+}
+#[allow(dead_code)]
+pub fn with_stats_cache_weighted_size() -> u64 {
+    __COLD_MOKA_WITH_STATS.run_pending_tasks();
+    __COLD_MOKA_WITH_STATS.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn with_stats_cache_sync() {
+    __COLD_MOKA_WITH_STATS.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn with_stats_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_with_stats(&inp);
+        let present = __COLD_MOKA_WITH_STATS.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = with_stats(inp);
+        }
+    }
+    warmed
+}
+#[allow(dead_code)]
+pub fn with_stats_cache_stats() -> ::cold_moka::CacheStats {
+    __COLD_MOKA_WITH_STATS_STATS.stats(__COLD_MOKA_WITH_STATS.entry_count())
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<Vec<u8>>();
+    cached_key::<usize>();
+};
+static __COLD_MOKA_OVERSIZE_DEDICATED_OVERSIZE: ::cold_moka::OversizeSlot<
+    (usize),
+    Vec<u8>,
+> = ::cold_moka::OversizeSlot::new();
+static __COLD_MOKA_OVERSIZE_DEDICATED: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(usize), Vec<u8>>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder()
+        .max_capacity(100u64)
+        .weigher(|_, v: &Vec<u8>| v.len() as u32)
+        .build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_oversize_dedicated(inp: &usize) -> (usize) {
+    (inp.clone())
+}
+pub fn oversize_dedicated(inp: usize) -> Vec<u8> {
+    fn __cold_moka_inner_oversize_dedicated(inp: usize) -> Vec<u8> {
+        ::alloc::vec::from_elem(0, inp)
+    }
+    let key = __cold_moka_key_oversize_dedicated(&inp);
+    if let Some(value) = __COLD_MOKA_OVERSIZE_DEDICATED.get(&key) {
+        return value;
+    }
+    if let Some(value) = __COLD_MOKA_OVERSIZE_DEDICATED_OVERSIZE.get(&key) {
+        return value;
+    }
+    {
+        let result = __cold_moka_inner_oversize_dedicated(inp);
+        let value = &result;
+        let weight = (|_, v: &Vec<u8>| v.len() as u32)(&key, value);
+        if u64::from(weight) > 50u64 {
+            __COLD_MOKA_OVERSIZE_DEDICATED_OVERSIZE.store(key, value.clone());
+        } else {
+            __COLD_MOKA_OVERSIZE_DEDICATED.insert(key, value.clone());
+        }
+        result
+    }
+}
+#[allow(dead_code)]
+pub fn oversize_dedicated_cache_clear() {
+    __COLD_MOKA_OVERSIZE_DEDICATED.invalidate_all();
+    __COLD_MOKA_OVERSIZE_DEDICATED_OVERSIZE.clear();
+}
+#[allow(dead_code, unused_variables)]
+pub fn oversize_dedicated_cache_invalidate(inp: usize) {
+    let key = __cold_moka_key_oversize_dedicated(&inp);
+    __COLD_MOKA_OVERSIZE_DEDICATED_OVERSIZE.remove(&key);
+    __COLD_MOKA_OVERSIZE_DEDICATED.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn oversize_dedicated_cache_insert(inp: usize, value: Vec<u8>) {
+    let key = __cold_moka_key_oversize_dedicated(&inp);
+    __COLD_MOKA_OVERSIZE_DEDICATED.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn oversize_dedicated_if_cached(inp: usize) -> ::std::option::Option<Vec<u8>> {
+    let key = __cold_moka_key_oversize_dedicated(&inp);
+    __COLD_MOKA_OVERSIZE_DEDICATED.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn oversize_dedicated_cache_contains(inp: usize) -> bool {
+    let key = __cold_moka_key_oversize_dedicated(&inp);
+    __COLD_MOKA_OVERSIZE_DEDICATED.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn oversize_dedicated_cache_entry_count() -> u64 {
+    __COLD_MOKA_OVERSIZE_DEDICATED.run_pending_tasks();
+    __COLD_MOKA_OVERSIZE_DEDICATED.entry_count()
+}
+#[allow(dead_code)]
+pub fn oversize_dedicated_cache_weighted_size() -> u64 {
+    __COLD_MOKA_OVERSIZE_DEDICATED.run_pending_tasks();
+    __COLD_MOKA_OVERSIZE_DEDICATED.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn oversize_dedicated_cache_sync() {
+    __COLD_MOKA_OVERSIZE_DEDICATED.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn oversize_dedicated_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = usize>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_oversize_dedicated(&inp);
+        let present = __COLD_MOKA_OVERSIZE_DEDICATED.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = oversize_dedicated(inp);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<::std::sync::Arc<Vec<u8>>>();
+    cached_key::<usize>();
+};
+static __COLD_MOKA_ARC_VALUE: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(usize), ::std::sync::Arc<Vec<u8>>>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_arc_value(inp: &usize) -> (usize) {
+    (inp.clone())
+}
+pub fn arc_value(inp: usize) -> Option<::std::sync::Arc<Vec<u8>>> {
+    fn __cold_moka_inner_arc_value(inp: usize) -> Option<Vec<u8>> {
+        Some(::alloc::vec::from_elem(0, inp))
+    }
+    let key = __cold_moka_key_arc_value(&inp);
+    __COLD_MOKA_ARC_VALUE
+        .optionally_get_with_by_ref(
+            &key,
+            || __cold_moka_inner_arc_value(inp).map(::std::sync::Arc::new),
+        )
+}
+#[allow(dead_code)]
+pub fn arc_value_cache_clear() {
+    __COLD_MOKA_ARC_VALUE.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn arc_value_cache_invalidate(inp: usize) {
+    let key = __cold_moka_key_arc_value(&inp);
+    __COLD_MOKA_ARC_VALUE.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn arc_value_cache_insert(inp: usize, value: ::std::sync::Arc<Vec<u8>>) {
+    let key = __cold_moka_key_arc_value(&inp);
+    __COLD_MOKA_ARC_VALUE.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn arc_value_if_cached(
+    inp: usize,
+) -> ::std::option::Option<::std::sync::Arc<Vec<u8>>> {
+    let key = __cold_moka_key_arc_value(&inp);
+    __COLD_MOKA_ARC_VALUE.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn arc_value_cache_contains(inp: usize) -> bool {
+    let key = __cold_moka_key_arc_value(&inp);
+    __COLD_MOKA_ARC_VALUE.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn arc_value_cache_entry_count() -> u64 {
+    __COLD_MOKA_ARC_VALUE.run_pending_tasks();
+    __COLD_MOKA_ARC_VALUE.entry_count()
+}
+#[allow(dead_code)]
+pub fn arc_value_cache_weighted_size() -> u64 {
+    __COLD_MOKA_ARC_VALUE.run_pending_tasks();
+    __COLD_MOKA_ARC_VALUE.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn arc_value_cache_sync() {
+    __COLD_MOKA_ARC_VALUE.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn arc_value_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = usize>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_arc_value(&inp);
+        let present = __COLD_MOKA_ARC_VALUE.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = arc_value(inp);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_FAILPOINTS: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_failpoints(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn failpoints(inp: i32) -> Result<i32, String> {
+    fn __cold_moka_inner_failpoints(inp: i32) -> Result<i32, String> {
+        Ok(inp)
+    }
+    let key = __cold_moka_key_failpoints(&inp);
+    if ::cold_moka::failpoint("cold_moka::failpoints::before_get") {
+        return __cold_moka_inner_failpoints(inp);
+    }
+    let result = __COLD_MOKA_FAILPOINTS
+        .try_get_with_by_ref(
+            &key,
+            || {
+                let value = {
+                    let value = __cold_moka_inner_failpoints(inp);
+                    ::cold_moka::failpoint("cold_moka::failpoints::after_compute");
+                    value
+                };
+                ::cold_moka::failpoint("cold_moka::failpoints::before_insert");
+                value
+            },
+        );
+    match result {
+        Ok(v) => Ok(v),
+        Err(e) => {
+            Err({
+                #[allow(unused_imports)]
+                use ::cold_moka::{CloneArcError as _, FromArcError as _};
+                (&&::cold_moka::ArcError::new(e)).into_error()
+            })
+        }
+    }
+}
+#[allow(dead_code)]
+pub fn failpoints_cache_clear() {
+    __COLD_MOKA_FAILPOINTS.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn failpoints_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_failpoints(&inp);
+    __COLD_MOKA_FAILPOINTS.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn failpoints_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_failpoints(&inp);
+    __COLD_MOKA_FAILPOINTS.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn failpoints_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_failpoints(&inp);
+    __COLD_MOKA_FAILPOINTS.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn failpoints_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_failpoints(&inp);
+    __COLD_MOKA_FAILPOINTS.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn failpoints_cache_entry_count() -> u64 {
+    __COLD_MOKA_FAILPOINTS.run_pending_tasks();
+    __COLD_MOKA_FAILPOINTS.entry_count()
+}
+#[allow(dead_code)]
+pub fn failpoints_cache_weighted_size() -> u64 {
+    __COLD_MOKA_FAILPOINTS.run_pending_tasks();
+    __COLD_MOKA_FAILPOINTS.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn failpoints_cache_sync() {
+    __COLD_MOKA_FAILPOINTS.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn failpoints_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_failpoints(&inp);
+        let present = __COLD_MOKA_FAILPOINTS.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = failpoints(inp);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_INVALIDATED: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder()
+        .max_capacity(1000u64)
+        .support_invalidation_closures()
+        .build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_invalidated(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn invalidated(inp: i32) -> i32 {
+    fn __cold_moka_inner_invalidated(inp: i32) -> i32 {
+        inp
+    }
+    let key = __cold_moka_key_invalidated(&inp);
+    __COLD_MOKA_INVALIDATED.get_with_by_ref(&key, || __cold_moka_inner_invalidated(inp))
+}
+#[allow(dead_code)]
+pub fn invalidated_cache_clear() {
+    __COLD_MOKA_INVALIDATED.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn invalidated_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_invalidated(&inp);
+    __COLD_MOKA_INVALIDATED.invalidate(&key);
+}
+#[allow(dead_code)]
+pub fn invalidated_cache_invalidate_if(
+    predicate: impl Fn(&(i32), &i32) -> bool + Send + Sync + 'static,
+) -> ::std::result::Result<(), ::cold_moka::moka::PredicateError> {
+    __COLD_MOKA_INVALIDATED.invalidate_entries_if(predicate).map(|_| ())
+}
+#[allow(dead_code, unused_variables)]
+pub fn invalidated_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_invalidated(&inp);
+    __COLD_MOKA_INVALIDATED.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn invalidated_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_invalidated(&inp);
+    __COLD_MOKA_INVALIDATED.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn invalidated_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_invalidated(&inp);
+    __COLD_MOKA_INVALIDATED.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn invalidated_cache_entry_count() -> u64 {
+    __COLD_MOKA_INVALIDATED.run_pending_tasks();
+    __COLD_MOKA_INVALIDATED.entry_count()
+}
+#[allow(dead_code)]
+pub fn invalidated_cache_weighted_size() -> u64 {
+    __COLD_MOKA_INVALIDATED.run_pending_tasks();
+    __COLD_MOKA_INVALIDATED.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn invalidated_cache_sync() {
+    __COLD_MOKA_INVALIDATED.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn invalidated_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_invalidated(&inp);
+        let present = __COLD_MOKA_INVALIDATED.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = invalidated(inp);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<String>();
+    cached_key::<bool>();
+    cached_key::<u8>();
+    cached_key::<String>();
+};
+static __COLD_MOKA_KEY_ORDER: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(bool, u8, String), String>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_key_order(c: &bool, a: &u8, b: &String) -> (bool, u8, String) {
+    (c.clone(), a.clone(), b.clone())
+}
+pub fn key_order(a: u8, skipped: u64, b: String, c: bool) -> String {
+    fn __cold_moka_inner_key_order(a: u8, skipped: u64, b: String, c: bool) -> String {
+        ::alloc::__export::must_use({
+            ::alloc::fmt::format(format_args!("{0}{1}{2}{3}", a, skipped, b, c))
+        })
+    }
+    let key = __cold_moka_key_key_order(&c, &a, &b);
+    __COLD_MOKA_KEY_ORDER
+        .get_with_by_ref(&key, || __cold_moka_inner_key_order(a, skipped, b, c))
+}
+#[allow(dead_code)]
+pub fn key_order_cache_clear() {
+    __COLD_MOKA_KEY_ORDER.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn key_order_cache_invalidate(c: bool, a: u8, b: String) {
+    let key = __cold_moka_key_key_order(&c, &a, &b);
+    __COLD_MOKA_KEY_ORDER.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn key_order_cache_insert(c: bool, a: u8, b: String, value: String) {
+    let key = __cold_moka_key_key_order(&c, &a, &b);
+    __COLD_MOKA_KEY_ORDER.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn key_order_if_cached(c: bool, a: u8, b: String) -> ::std::option::Option<String> {
+    let key = __cold_moka_key_key_order(&c, &a, &b);
+    __COLD_MOKA_KEY_ORDER.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn key_order_cache_contains(c: bool, a: u8, b: String) -> bool {
+    let key = __cold_moka_key_key_order(&c, &a, &b);
+    __COLD_MOKA_KEY_ORDER.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn key_order_cache_entry_count() -> u64 {
+    __COLD_MOKA_KEY_ORDER.run_pending_tasks();
+    __COLD_MOKA_KEY_ORDER.entry_count()
+}
+#[allow(dead_code)]
+pub fn key_order_cache_weighted_size() -> u64 {
+    __COLD_MOKA_KEY_ORDER.run_pending_tasks();
+    __COLD_MOKA_KEY_ORDER.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn key_order_cache_sync() {
+    __COLD_MOKA_KEY_ORDER.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn key_order_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (u8, u64, String, bool)>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for (a, skipped, b, c) in inputs {
+        let key = __cold_moka_key_key_order(&c, &a, &b);
+        let present = __COLD_MOKA_KEY_ORDER.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = key_order(a, skipped, b, c);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_IGNORED: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_ignored(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn ignored(inp: i32, skipped: u64) -> i32 {
+    fn __cold_moka_inner_ignored(inp: i32, skipped: u64) -> i32 {
+        inp + skipped as i32
+    }
+    let key = __cold_moka_key_ignored(&inp);
+    __COLD_MOKA_IGNORED.get_with_by_ref(&key, || __cold_moka_inner_ignored(inp, skipped))
+}
+#[allow(dead_code)]
+pub fn ignored_cache_clear() {
+    __COLD_MOKA_IGNORED.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn ignored_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_ignored(&inp);
+    __COLD_MOKA_IGNORED.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn ignored_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_ignored(&inp);
+    __COLD_MOKA_IGNORED.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn ignored_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_ignored(&inp);
+    __COLD_MOKA_IGNORED.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn ignored_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_ignored(&inp);
+    __COLD_MOKA_IGNORED.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn ignored_cache_entry_count() -> u64 {
+    __COLD_MOKA_IGNORED.run_pending_tasks();
+    __COLD_MOKA_IGNORED.entry_count()
+}
+#[allow(dead_code)]
+pub fn ignored_cache_weighted_size() -> u64 {
+    __COLD_MOKA_IGNORED.run_pending_tasks();
+    __COLD_MOKA_IGNORED.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn ignored_cache_sync() {
+    __COLD_MOKA_IGNORED.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn ignored_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (i32, u64)>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for (inp, skipped) in inputs {
+        let key = __cold_moka_key_ignored(&inp);
+        let present = __COLD_MOKA_IGNORED.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = ignored(inp, skipped);
+        }
+    }
+    warmed
+}
+pub fn ignored_update(inp: i32, value: u64) -> u64 {
+    fn __cold_moka_inner_ignored_update(inp: i32, mut value: u64) -> u64 {
+        value += inp as u64;
+        value
+    }
+    let __cold_moka_copy_inp = ::std::clone::Clone::clone(&inp);
+    let result = __cold_moka_inner_ignored_update(inp, value);
+    let (): () = ignored_cache_invalidate(__cold_moka_copy_inp);
+    result
+}
+pub fn ignored_save(inp: i32) -> Option<i32> {
+    fn __cold_moka_inner_ignored_save(inp: i32) -> Option<i32> {
+        Some(inp)
+    }
+    let __cold_moka_copy_inp = ::std::clone::Clone::clone(&inp);
+    let result = __cold_moka_inner_ignored_save(inp);
+    if let Some(value) = &result {
+        let value: i32 = ::std::clone::Clone::clone(value);
+        let (): () = ignored_cache_insert(__cold_moka_copy_inp, value);
+    }
+    result
+}
+pub struct Ctx {
+    pub id: u64,
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<u64>();
+};
+static __COLD_MOKA_FIELD_KEY: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<u64, i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_field_key(ctx: &Ctx) -> u64 {
+    (::std::clone::Clone::clone(&ctx.id))
+}
+pub fn field_key(ctx: Ctx, inp: i32) -> i32 {
+    fn __cold_moka_inner_field_key(ctx: Ctx, inp: i32) -> i32 {
+        inp + ctx.id as i32
+    }
+    let key = __cold_moka_key_field_key(&ctx);
+    __COLD_MOKA_FIELD_KEY.get_with_by_ref(&key, || __cold_moka_inner_field_key(ctx, inp))
+}
+#[allow(dead_code)]
+pub fn field_key_cache_clear() {
+    __COLD_MOKA_FIELD_KEY.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn field_key_cache_invalidate(ctx: Ctx) {
+    let key = __cold_moka_key_field_key(&ctx);
+    __COLD_MOKA_FIELD_KEY.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn field_key_cache_insert(ctx: Ctx, value: i32) {
+    let key = __cold_moka_key_field_key(&ctx);
+    __COLD_MOKA_FIELD_KEY.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn field_key_if_cached(ctx: Ctx) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_field_key(&ctx);
+    __COLD_MOKA_FIELD_KEY.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn field_key_cache_contains(ctx: Ctx) -> bool {
+    let key = __cold_moka_key_field_key(&ctx);
+    __COLD_MOKA_FIELD_KEY.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn field_key_cache_entry_count() -> u64 {
+    __COLD_MOKA_FIELD_KEY.run_pending_tasks();
+    __COLD_MOKA_FIELD_KEY.entry_count()
+}
+#[allow(dead_code)]
+pub fn field_key_cache_weighted_size() -> u64 {
+    __COLD_MOKA_FIELD_KEY.run_pending_tasks();
+    __COLD_MOKA_FIELD_KEY.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn field_key_cache_sync() {
+    __COLD_MOKA_FIELD_KEY.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn field_key_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (Ctx, i32)>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for (ctx, inp) in inputs {
+        let key = __cold_moka_key_field_key(&ctx);
+        let present = __COLD_MOKA_FIELD_KEY.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = field_key(ctx, inp);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<::std::sync::Arc<String>>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_MANY: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), ::std::sync::Arc<String>>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_many(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn many(inp: i32) -> ::std::sync::Arc<String> {
+    fn __cold_moka_inner_many(inp: i32) -> String {
+        inp.to_string()
+    }
+    let key = __cold_moka_key_many(&inp);
+    __COLD_MOKA_MANY
+        .get_with_by_ref(&key, || ::std::sync::Arc::new(__cold_moka_inner_many(inp)))
+}
+#[allow(dead_code)]
+pub fn many_cache_clear() {
+    __COLD_MOKA_MANY.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn many_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_many(&inp);
+    __COLD_MOKA_MANY.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn many_cache_insert(inp: i32, value: ::std::sync::Arc<String>) {
+    let key = __cold_moka_key_many(&inp);
+    __COLD_MOKA_MANY.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn many_if_cached(inp: i32) -> ::std::option::Option<::std::sync::Arc<String>> {
+    let key = __cold_moka_key_many(&inp);
+    __COLD_MOKA_MANY.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn many_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_many(&inp);
+    __COLD_MOKA_MANY.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn many_cache_entry_count() -> u64 {
+    __COLD_MOKA_MANY.run_pending_tasks();
+    __COLD_MOKA_MANY.entry_count()
+}
+#[allow(dead_code)]
+pub fn many_cache_weighted_size() -> u64 {
+    __COLD_MOKA_MANY.run_pending_tasks();
+    __COLD_MOKA_MANY.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn many_cache_sync() {
+    __COLD_MOKA_MANY.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn many_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_many(&inp);
+        let present = __COLD_MOKA_MANY.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = many(inp);
+        }
+    }
+    warmed
+}
+#[allow(dead_code)]
+pub fn many_many(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::std::vec::Vec<::std::sync::Arc<String>> {
+    inputs.into_iter().map(|inp| many(inp)).collect()
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<String>();
+    cached_key::<String>();
+    cached_key::<u8>();
+};
+static __COLD_MOKA_KEY_BORROW: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(String, u8), String>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_key_borrow(inp: &String, other: &u8) -> (String, u8) {
+    (inp.clone(), other.clone())
+}
+pub fn key_borrow(inp: String, other: u8) -> String {
+    fn __cold_moka_inner_key_borrow(inp: String, other: u8) -> String {
+        ::alloc::__export::must_use({
+            ::alloc::fmt::format(format_args!("{0}{1}", inp, other))
+        })
+    }
+    let key = (inp, other);
+    let key_ref = &key;
+    __COLD_MOKA_KEY_BORROW
+        .get_with_by_ref(
+            &key,
+            || __cold_moka_inner_key_borrow(
+                ::std::clone::Clone::clone(&key_ref.0),
+                ::std::clone::Clone::clone(&key_ref.1),
+            ),
+        )
+}
+#[allow(dead_code)]
+pub fn key_borrow_cache_clear() {
+    __COLD_MOKA_KEY_BORROW.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn key_borrow_cache_invalidate(inp: String, other: u8) {
+    let key = __cold_moka_key_key_borrow(&inp, &other);
+    __COLD_MOKA_KEY_BORROW.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn key_borrow_cache_insert(inp: String, other: u8, value: String) {
+    let key = __cold_moka_key_key_borrow(&inp, &other);
+    __COLD_MOKA_KEY_BORROW.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn key_borrow_if_cached(inp: String, other: u8) -> ::std::option::Option<String> {
+    let key = __cold_moka_key_key_borrow(&inp, &other);
+    __COLD_MOKA_KEY_BORROW.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn key_borrow_cache_contains(inp: String, other: u8) -> bool {
+    let key = __cold_moka_key_key_borrow(&inp, &other);
+    __COLD_MOKA_KEY_BORROW.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn key_borrow_cache_entry_count() -> u64 {
+    __COLD_MOKA_KEY_BORROW.run_pending_tasks();
+    __COLD_MOKA_KEY_BORROW.entry_count()
+}
+#[allow(dead_code)]
+pub fn key_borrow_cache_weighted_size() -> u64 {
+    __COLD_MOKA_KEY_BORROW.run_pending_tasks();
+    __COLD_MOKA_KEY_BORROW.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn key_borrow_cache_sync() {
+    __COLD_MOKA_KEY_BORROW.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn key_borrow_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = (String, u8)>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for (inp, other) in inputs {
+        let key = __cold_moka_key_key_borrow(&inp, &other);
+        let present = __COLD_MOKA_KEY_BORROW.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = key_borrow(inp, other);
+        }
+    }
+    warmed
+}
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+static __COLD_MOKA_BYPASS: ::cold_moka::once_cell::sync::Lazy<
+    ::cold_moka::moka::sync::Cache<(i32), i32>,
+> = ::cold_moka::once_cell::sync::Lazy::new(|| {
+    ::cold_moka::moka::sync::Cache::builder().max_capacity(1000u64).build()
+});
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_bypass(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+#[allow(unused_mut)]
+pub fn bypass(inp: i32) -> i32 {
+    let key = __cold_moka_key_bypass(&inp);
+    __COLD_MOKA_BYPASS.get_with_by_ref(&key, || __cold_moka_inner_bypass(inp))
+}
+#[allow(dead_code)]
+pub fn bypass_cache_clear() {
+    __COLD_MOKA_BYPASS.invalidate_all();
+}
+#[allow(dead_code, unused_variables)]
+pub fn bypass_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_bypass(&inp);
+    __COLD_MOKA_BYPASS.invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn bypass_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_bypass(&inp);
+    __COLD_MOKA_BYPASS.insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn bypass_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_bypass(&inp);
+    __COLD_MOKA_BYPASS.get(&key)
+}
+#[allow(dead_code, unused_variables)]
+pub fn bypass_cache_contains(inp: i32) -> bool {
+    let key = __cold_moka_key_bypass(&inp);
+    __COLD_MOKA_BYPASS.contains_key(&key)
+}
+#[allow(dead_code)]
+pub fn bypass_cache_entry_count() -> u64 {
+    __COLD_MOKA_BYPASS.run_pending_tasks();
+    __COLD_MOKA_BYPASS.entry_count()
+}
+#[allow(dead_code)]
+pub fn bypass_cache_weighted_size() -> u64 {
+    __COLD_MOKA_BYPASS.run_pending_tasks();
+    __COLD_MOKA_BYPASS.weighted_size()
+}
+#[doc(hidden)]
+#[allow(dead_code)]
+pub fn bypass_cache_sync() {
+    __COLD_MOKA_BYPASS.run_pending_tasks();
+}
+#[allow(dead_code)]
+pub fn bypass_warm(
+    inputs: impl ::std::iter::IntoIterator<Item = i32>,
+) -> ::cold_moka::Warmed {
+    let mut warmed = ::cold_moka::Warmed::default();
+    for inp in inputs {
+        let key = __cold_moka_key_bypass(&inp);
+        let present = __COLD_MOKA_BYPASS.contains_key(&key);
+        warmed.record(present);
+        if !present {
+            let _ = bypass(inp);
+        }
+    }
+    warmed
+}
+#[allow(unused_mut)]
+fn __cold_moka_inner_bypass(mut inp: i32) -> i32 {
+    inp
+}
+#[allow(unused_mut)]
+#[allow(dead_code)]
+pub fn bypass_uncached(inp: i32) -> i32 {
+    __cold_moka_inner_bypass(inp)
+}
+pub static BACKEND: cold_moka::once_cell::sync::Lazy<
+    cold_moka::testing::RecordingBackend<i32, i32>,
+> = cold_moka::once_cell::sync::Lazy::new(cold_moka::testing::RecordingBackend::new);
+const _: fn() = || {
+    fn cached_value<T: ::cold_moka::CacheValue>() {}
+    fn cached_key<T: ::cold_moka::CacheKey>() {}
+    cached_value::<i32>();
+    cached_key::<i32>();
+};
+fn __cold_moka_backend_backend() -> ::cold_moka::BackendRef<'static, (i32), i32> {
+    ::cold_moka::BackendRef::new(::std::ops::Deref::deref(&BACKEND))
+}
+#[allow(clippy::ptr_arg, clippy::unused_unit)]
+fn __cold_moka_key_backend(inp: &i32) -> (i32) {
+    (inp.clone())
+}
+pub fn backend(inp: i32) -> Result<i32, String> {
+    fn __cold_moka_inner_backend(inp: i32) -> Result<i32, String> {
+        Ok(inp)
+    }
+    let key = __cold_moka_key_backend(&inp);
+    if let Some(value) = __cold_moka_backend_backend().get(&key) {
+        return Ok(value);
+    }
+    {
+        let result = __cold_moka_inner_backend(inp);
+        if let Ok(value) = &result {
+            __cold_moka_backend_backend().insert(key, value.clone());
+        }
+        result
+    }
+}
+#[allow(dead_code, unused_variables)]
+pub fn backend_cache_invalidate(inp: i32) {
+    let key = __cold_moka_key_backend(&inp);
+    __cold_moka_backend_backend().invalidate(&key);
+}
+#[allow(dead_code, unused_variables)]
+pub fn backend_cache_insert(inp: i32, value: i32) {
+    let key = __cold_moka_key_backend(&inp);
+    __cold_moka_backend_backend().insert(key, value);
+}
+#[allow(dead_code, unused_variables)]
+pub fn backend_if_cached(inp: i32) -> ::std::option::Option<i32> {
+    let key = __cold_moka_key_backend(&inp);
+    __cold_moka_backend_backend().get(&key)
 }
@@ -1,10 +1,9 @@
-use cold_moka::cached;
+use cold_moka::{cache_invalidate, cache_update, cached, once};
 fn main() {}
 
 #[cached]
 pub fn cached() -> i32 {
-    let x = 1 + 2;
-    x
+    1 + 2
 }
 
 #[cached]
@@ -84,3 +83,334 @@ fn destruct_multiple2(
 ) -> i32 {
     aaaaaa + bbbbbb + ccccccc
 }
+
+#[cached(ttl = 13, pin = true)]
+pub fn pinned(inp: i32) -> i32 {
+    inp
+}
+
+#[derive(Clone, Hash, PartialEq, Eq)]
+pub struct CompositeId(i32, i32);
+
+impl CompositeId {
+    fn from_parts(a: &i32, b: &i32) -> Self {
+        CompositeId(*a, *b)
+    }
+}
+
+impl CompositeId {
+    #[cached]
+    pub fn method(&mut self, inp: i32) -> i32 {
+        self.0 += inp;
+        self.0
+    }
+
+    #[cached(associated = true)]
+    pub fn associated(inp: i32) -> i32 {
+        inp
+    }
+}
+
+#[cached(
+    key = "a, b",
+    key_fn = "CompositeId::from_parts",
+    key_type = "CompositeId"
+)]
+pub fn key_fn(_ctx: NoHash, a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[cached]
+pub fn qualified_result(inp: i32) -> ::std::result::Result<i32, i32> {
+    Ok(inp)
+}
+
+#[cached]
+pub fn std_result(inp: i32) -> std::result::Result<i32, i32> {
+    Ok(inp)
+}
+
+#[cached]
+pub fn qualified_option(inp: i32) -> ::core::option::Option<i32> {
+    Some(inp)
+}
+
+#[cached]
+pub fn core_option(inp: i32) -> core::option::Option<i32> {
+    Some(inp)
+}
+
+#[cached]
+pub fn core_result(inp: i32) -> core::result::Result<i32, i32> {
+    Ok(inp)
+}
+
+#[cached]
+pub fn std_option(inp: i32) -> ::std::option::Option<i32> {
+    Some(inp)
+}
+
+pub type ApiResult<T> = Result<T, i32>;
+
+#[cached(result = true)]
+pub fn result_alias(inp: i32) -> ApiResult<i32> {
+    Ok(inp)
+}
+
+pub type Lookup<T> = Option<T>;
+
+#[cached(option = true)]
+pub fn option_alias(inp: i32) -> Lookup<i32> {
+    Some(inp)
+}
+
+#[cached(option = false)]
+pub fn option_whole(inp: i32) -> Option<i32> {
+    Some(inp)
+}
+
+#[cached(error = "arc", from_arc = "|e: std::sync::Arc<i32>| *e + 1")]
+pub fn error_from_arc(inp: i32) -> Result<i32, i32> {
+    Ok(inp)
+}
+
+#[cached(key = "inp")]
+pub fn where_bound<T>(inp: i32, label: T) -> String
+where
+    T: std::fmt::Display,
+{
+    format!("{}{}", label, inp)
+}
+
+#[cached(cache_errors = true, ttl = 60, negative_ttl = 5)]
+pub fn cache_errors(inp: i32) -> Result<i32, i32> {
+    Ok(inp)
+}
+
+#[cached(cache_none = true)]
+pub fn cache_none(inp: i32) -> Option<i32> {
+    Some(inp)
+}
+
+#[cached]
+pub fn result_option(inp: i32) -> Result<Option<i32>, i32> {
+    Ok(Some(inp))
+}
+
+#[cached(wrap_return = true)]
+pub fn wrap_return(inp: i32) -> Result<cold_moka::Return<i32>, i32> {
+    Ok(cold_moka::Return::new(inp))
+}
+
+pub fn hook(_: &i32) {}
+
+#[cached(on_hit = "hook", on_miss = "hook")]
+pub fn lookup_hooks(inp: i32) -> i32 {
+    inp
+}
+
+#[cached]
+pub fn returns_arc(inp: i32) -> std::sync::Arc<i32> {
+    std::sync::Arc::new(inp)
+}
+
+#[cached]
+pub fn returns_arc_result(inp: i32) -> Result<std::sync::Arc<i32>, i32> {
+    Ok(std::sync::Arc::new(inp))
+}
+
+#[cached]
+pub fn returns_arc_option(inp: i32) -> Option<std::sync::Arc<i32>> {
+    Some(std::sync::Arc::new(inp))
+}
+
+#[cached(ttl = 60, result_fallback = true)]
+pub fn result_fallback(inp: i32) -> Result<i32, i32> {
+    Ok(inp)
+}
+
+#[cached(cache_if = "|v: &i32| *v > 0")]
+pub fn cache_if(inp: i32) -> Result<i32, i32> {
+    Ok(inp)
+}
+
+pub type IntLookup = Result<i32, i32>;
+
+#[cached(result = true, value_type = "i32")]
+pub fn value_type(inp: i32) -> IntLookup {
+    Ok(inp)
+}
+
+#[cached(key = "a, c")]
+pub fn destructured((a, _b): (i32, i32), c: String) -> i32 {
+    a + c.len() as i32
+}
+
+#[cached(value_ttl = true)]
+pub fn value_ttl(inp: i32) -> (i32, std::time::Duration) {
+    (inp, std::time::Duration::from_secs(60))
+}
+
+pub static ENABLED: std::sync::atomic::AtomicBool = std::sync::atomic::AtomicBool::new(true);
+
+#[cached(enabled = "ENABLED")]
+pub fn enabled(inp: i32) -> i32 {
+    inp
+}
+
+#[cached]
+pub fn borrowed_key(name: &str, bytes: &[u8], id: &u64) -> usize {
+    name.len() + bytes.len() + *id as usize
+}
+
+#[cached]
+pub fn named_like_locals(key: i32, value: i32) -> i32 {
+    key + value
+}
+
+#[cached(convert = "{ (a.abs(), b) }")]
+pub fn convert_block(a: i32, b: u8) -> i32 {
+    a + b as i32
+}
+
+#[cached(convert = "(a.abs(), b)")]
+pub fn convert_expr(a: i32, b: u8) -> i32 {
+    a + b as i32
+}
+
+#[cached(key_expr = "a.abs()", key_type = "i32")]
+pub fn key_expr(a: i32, b: u8) -> i32 {
+    a + b as i32
+}
+
+#[once]
+pub fn once_value() -> Result<String, i32> {
+    Ok("once".to_owned())
+}
+
+#[cached(debug_key = true, key = "a, b")]
+pub fn debug_key(_ctx: NoHash, a: f64, b: String) -> String {
+    format!("{}{}", a, b)
+}
+
+pub static GROUP: cold_moka::once_cell::sync::Lazy<cold_moka::moka::sync::Cache<i32, i32>> =
+    cold_moka::once_cell::sync::Lazy::new(|| cold_moka::moka::sync::Cache::new(10));
+
+#[cached(group = "GROUP")]
+pub fn grouped(inp: i32) -> i32 {
+    inp
+}
+
+#[cached(swap = true)]
+pub fn swapped(inp: i32) -> i32 {
+    inp
+}
+
+#[cached]
+pub fn boxed_error(inp: i32) -> Result<i32, Box<dyn std::error::Error + Send + Sync>> {
+    Ok(inp)
+}
+
+#[cached]
+#[cfg(any())]
+pub fn cfg_never(inp: i32) -> i32 {
+    inp
+}
+
+#[cached]
+pub fn tuple_return(inp: i32) -> (i32, Vec<u8>) {
+    (inp, Vec::new())
+}
+
+#[cached]
+pub fn static_ref_return(inp: i32) -> &'static str {
+    if inp > 0 {
+        "positive"
+    } else {
+        "other"
+    }
+}
+
+#[cached(stats = true)]
+pub fn with_stats(inp: i32) -> Option<i32> {
+    Some(inp)
+}
+
+#[cached(
+    max_weight = 100,
+    weigher = "|_, v: &Vec<u8>| v.len() as u32",
+    oversize = "dedicated"
+)]
+pub fn oversize_dedicated(inp: usize) -> Vec<u8> {
+    vec![0; inp]
+}
+
+#[cached(arc = true)]
+pub fn arc_value(inp: usize) -> Option<Vec<u8>> {
+    Some(vec![0; inp])
+}
+
+#[cached(failpoints = true)]
+pub fn failpoints(inp: i32) -> Result<i32, String> {
+    Ok(inp)
+}
+
+#[cached(invalidation = true)]
+pub fn invalidated(inp: i32) -> i32 {
+    inp
+}
+
+#[cached(key = "c, a, b")]
+pub fn key_order(a: u8, skipped: u64, b: String, c: bool) -> String {
+    format!("{}{}{}{}", a, skipped, b, c)
+}
+
+#[cached(ignore = "skipped")]
+pub fn ignored(inp: i32, skipped: u64) -> i32 {
+    inp + skipped as i32
+}
+
+#[cache_invalidate(target = "ignored", key = "inp")]
+pub fn ignored_update(inp: i32, mut value: u64) -> u64 {
+    value += inp as u64;
+    value
+}
+
+#[cache_update(target = "ignored", key = "inp")]
+pub fn ignored_save(inp: i32) -> Option<i32> {
+    Some(inp)
+}
+
+pub struct Ctx {
+    pub id: u64,
+}
+
+#[cached(key = "ctx.id", key_type = "u64")]
+pub fn field_key(ctx: Ctx, inp: i32) -> i32 {
+    inp + ctx.id as i32
+}
+
+#[cached(many = true, arc = true)]
+pub fn many(inp: i32) -> String {
+    inp.to_string()
+}
+
+#[cached(key_borrow = true)]
+pub fn key_borrow(inp: String, other: u8) -> String {
+    format!("{}{}", inp, other)
+}
+
+#[cached(bypass = true)]
+#[allow(unused_mut)]
+pub fn bypass(mut inp: i32) -> i32 {
+    inp
+}
+
+pub static BACKEND: cold_moka::once_cell::sync::Lazy<
+    cold_moka::testing::RecordingBackend<i32, i32>,
+> = cold_moka::once_cell::sync::Lazy::new(cold_moka::testing::RecordingBackend::new);
+
+#[cached(backend = "BACKEND")]
+pub fn backend(inp: i32) -> Result<i32, String> {
+    Ok(inp)
+}
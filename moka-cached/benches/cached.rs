@@ -0,0 +1,130 @@
+//! Overhead of `#[cached]` compared to the same cache written by hand.
+use std::hint::black_box;
+use std::sync::Arc;
+use std::time::Duration;
+
+use cold_moka::cached;
+use cold_moka::moka::{future, sync};
+use cold_moka::once_cell::sync::Lazy;
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+
+#[derive(Debug, Clone)]
+struct BenchError;
+
+#[cached]
+fn primitive(x: u64) -> u64 {
+    x + 1
+}
+
+fn primitive_manual(x: u64) -> u64 {
+    static CACHE: Lazy<sync::Cache<u64, u64>> =
+        Lazy::new(|| sync::Cache::builder().max_capacity(1000).build());
+    CACHE.get_with(x, || x + 1)
+}
+
+#[cached]
+fn string(s: String) -> usize {
+    s.len()
+}
+
+fn string_manual(s: String) -> usize {
+    static CACHE: Lazy<sync::Cache<String, usize>> =
+        Lazy::new(|| sync::Cache::builder().max_capacity(1000).build());
+    CACHE.get_with_by_ref(&s, || s.len())
+}
+
+#[cached(size = 1_000_000)]
+fn slow(x: u64) -> u64 {
+    std::thread::sleep(Duration::from_millis(1));
+    x + 1
+}
+
+fn slow_manual(x: u64) -> u64 {
+    static CACHE: Lazy<sync::Cache<u64, u64>> =
+        Lazy::new(|| sync::Cache::builder().max_capacity(1_000_000).build());
+    CACHE.get_with(x, || {
+        std::thread::sleep(Duration::from_millis(1));
+        x + 1
+    })
+}
+
+#[cached]
+fn fallible(x: u64) -> Result<u64, BenchError> {
+    Ok(x + 1)
+}
+
+fn fallible_manual(x: u64) -> Result<u64, BenchError> {
+    static CACHE: Lazy<sync::Cache<u64, u64>> =
+        Lazy::new(|| sync::Cache::builder().max_capacity(1000).build());
+    CACHE
+        .try_get_with(x, || Ok(x + 1))
+        .map_err(|e: Arc<BenchError>| (*e).clone())
+}
+
+#[cached]
+async fn asynchronous(x: u64) -> u64 {
+    x + 1
+}
+
+async fn asynchronous_manual(x: u64) -> u64 {
+    static CACHE: Lazy<future::Cache<u64, u64>> =
+        Lazy::new(|| future::Cache::builder().max_capacity(1000).build());
+    CACHE.get_with(x, async move { x + 1 }).await
+}
+
+fn hit(c: &mut Criterion) {
+    let mut group = c.benchmark_group("primitive hit");
+    group.bench_function("cached", |b| b.iter(|| primitive(black_box(1))));
+    group.bench_function("manual", |b| b.iter(|| primitive_manual(black_box(1))));
+    group.finish();
+
+    let mut group = c.benchmark_group("string hit");
+    let key = "some moderately long cache key".to_owned();
+    group.bench_function("cached", |b| {
+        b.iter_batched(|| key.clone(), string, BatchSize::SmallInput)
+    });
+    group.bench_function("manual", |b| {
+        b.iter_batched(|| key.clone(), string_manual, BatchSize::SmallInput)
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("result hit");
+    group.bench_function("cached", |b| b.iter(|| fallible(black_box(1))));
+    group.bench_function("manual", |b| b.iter(|| fallible_manual(black_box(1))));
+    group.finish();
+}
+
+fn miss(c: &mut Criterion) {
+    let mut group = c.benchmark_group("miss 1ms");
+    let mut key = 0;
+    group.bench_function("cached", |b| {
+        b.iter(|| {
+            key += 1;
+            slow(key)
+        })
+    });
+    let mut key = 0;
+    group.bench_function("manual", |b| {
+        b.iter(|| {
+            key += 1;
+            slow_manual(key)
+        })
+    });
+    group.finish();
+}
+
+fn async_hit(c: &mut Criterion) {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let mut group = c.benchmark_group("async hit");
+    group.bench_function("cached", |b| {
+        b.to_async(&runtime).iter(|| asynchronous(black_box(1)))
+    });
+    group.bench_function("manual", |b| {
+        b.to_async(&runtime)
+            .iter(|| asynchronous_manual(black_box(1)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, hit, miss, async_hit);
+criterion_main!(benches);
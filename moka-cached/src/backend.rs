@@ -0,0 +1,61 @@
+/// The operations the code around a cache performs on it, so a whole caching layer can be
+/// replaced with a fake like [`RecordingBackend`](crate::testing::RecordingBackend) in tests.
+///
+/// `#[cached(backend = "PATH")]` caches a function in a static implementing it. Its methods
+/// aren't async, so `moka::future::Cache` isn't one, an `async fn` uses it without awaiting.
+pub trait CacheBackend<K, V> {
+    /// Returns the value cached for `key`.
+    fn get(&self, key: &K) -> Option<V>;
+
+    /// Caches `value` for `key`, replacing the previous one.
+    fn insert(&self, key: K, value: V);
+
+    /// Drops the value cached for `key`.
+    fn invalidate(&self, key: &K);
+}
+
+/// The backend of a `#[cached(backend = "...")]` function, as the generated code reaches it.
+///
+/// The generated code can't call the methods of [`CacheBackend`] without importing it into the
+/// caller's scope, the inherent ones here forward to them.
+#[doc(hidden)]
+pub struct BackendRef<'a, K, V> {
+    backend: &'a (dyn CacheBackend<K, V> + Sync + 'a),
+}
+
+impl<'a, K, V> BackendRef<'a, K, V> {
+    pub fn new(backend: &'a (dyn CacheBackend<K, V> + Sync + 'a)) -> Self {
+        Self { backend }
+    }
+
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.backend.get(key)
+    }
+
+    pub fn insert(&self, key: K, value: V) {
+        self.backend.insert(key, value);
+    }
+
+    pub fn invalidate(&self, key: &K) {
+        self.backend.invalidate(key);
+    }
+}
+
+#[cfg(feature = "sync")]
+impl<K, V> CacheBackend<K, V> for moka::sync::Cache<K, V>
+where
+    K: std::hash::Hash + Eq + Send + Sync + 'static,
+    V: Clone + Send + Sync + 'static,
+{
+    fn get(&self, key: &K) -> Option<V> {
+        moka::sync::Cache::get(self, key)
+    }
+
+    fn insert(&self, key: K, value: V) {
+        moka::sync::Cache::insert(self, key, value);
+    }
+
+    fn invalidate(&self, key: &K) {
+        moka::sync::Cache::invalidate(self, key);
+    }
+}
@@ -0,0 +1,26 @@
+use std::hash::Hash;
+
+/// Implemented by the types which can be a part of the key of a `#[cached]` function.
+///
+/// The generated code asserts it for every argument making up the key, so a missing bound is
+/// reported on the argument instead of deep inside the cache.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be a part of the cache key",
+    label = "this argument is a part of the key",
+    note = "the key must be `Hash + Eq + Clone + Send + Sync + 'static`",
+    note = "leave the argument out of the key with `key = \"...\"` or `ignore = \"...\"`"
+)]
+pub trait CacheKey: Hash + Eq + Clone + Send + Sync + 'static {}
+
+impl<T: Hash + Eq + Clone + Send + Sync + 'static> CacheKey for T {}
+
+/// Implemented by the types which can be cached by a `#[cached]` function.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be cached",
+    label = "the cached value",
+    note = "the cached value must be `Clone + Send + Sync + 'static`",
+    note = "`arc = true` caches an `Arc` of the value, which only needs `Send + Sync`"
+)]
+pub trait CacheValue: Clone + Send + Sync + 'static {}
+
+impl<T: Clone + Send + Sync + 'static> CacheValue for T {}
@@ -0,0 +1,69 @@
+//! Explanations of the errors reported by `#[cached]`.
+//!
+//! Every error message starts with a stable code, e.g. `[CM0001]`, which can be looked up with
+//! [`explain`]. Codes are never reused, so tooling scraping build logs can rely on them.
+
+/// Codes of the macro errors with their long form explanations.
+pub const DIAGNOSTICS: &[(&str, &str)] = &[
+    (
+        "CM0001",
+        "The function takes a `self` receiver. `#[cache_invalidate]` and `#[cache_update]` \
+         can't be put on methods, move the mutation into a free function taking the needed \
+         fields as arguments.",
+    ),
+    (
+        "CM0002",
+        "Two arguments of the attribute can't be used together, or an argument can't be used \
+         on this kind of function, e.g. `pin` on a method, `boxed_future` on an `async fn`, \
+         `key_fn` together with `convert`, `key_expr` together with `key`, `key` together \
+         with `ignore` or `ttl` together with `backend`. Remove one of them.",
+    ),
+    (
+        "CM0003",
+        "An argument of the attribute needs another one to be set, e.g. `pin` requires `ttl`, \
+         `key_fn` and `key_expr` require `key_type` and `warmup` requires `record_size` or `stats`.",
+    ),
+    (
+        "CM0004",
+        "The value of an argument could not be parsed. `convert` must be a block, `key_fn`, \
+         `on_hit`, `on_miss` and `enabled` paths, `key_type` a type, `record_size`, `from_arc` \
+         and `cache_if` closures and `warmup` a duration like `\"30s\"`, all written as \
+         string literals. The `key` and `ignore` of `#[cached]` and the `key` of \
+         `#[cache_invalidate]` and `#[cache_update]` must name arguments of the function, each \
+         of them once.",
+    ),
+    (
+        "CM0005",
+        "The cached value type could not be derived from the return type. `Result` and \
+         `Option` returns need a generic argument holding the value, `boxed_future` functions \
+         must return `BoxFuture<T>`, `Pin<Box<dyn Future<Output = T>>>` or \
+         `impl Future<Output = T>`.",
+    ),
+    (
+        "CM0006",
+        "An argument uses a pattern the macro can't turn into a key component. Bind the \
+         argument to a plain identifier, or exclude it from the key with `key`.",
+    ),
+    (
+        "CM0007",
+        "An `impl Trait` argument is a part of the key, but the type of the key has to be \
+         named to store it. Leave the argument out of the key with `key`, or build the key \
+         from it with `key_fn` and `key_type`.",
+    ),
+    (
+        "CM0008",
+        "The function is of a kind the macro can't memoize. `#[once]` keeps a single value, \
+         so it only memoizes functions without arguments (or taking `()`) which aren't \
+         `async`, generic or methods, use `#[cached]` for the others. `#[cached]` keeps its \
+         cache in a single static, so it can't cache functions with const generic parameters, \
+         take a slice instead of an array of any length.",
+    ),
+];
+
+/// Returns the long form explanation of a diagnostic code, e.g. `explain("CM0001")`.
+pub fn explain(code: &str) -> Option<&'static str> {
+    DIAGNOSTICS
+        .iter()
+        .find(|(known, _)| *known == code)
+        .map(|(_, explanation)| *explanation)
+}
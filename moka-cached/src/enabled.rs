@@ -0,0 +1,11 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Reads the switch named by `#[cached(enabled = "...")]`.
+///
+/// The flag doesn't guard any other data, a call racing with the switch may still see the old
+/// state, so `Relaxed` is enough.
+#[doc(hidden)]
+#[inline]
+pub fn is_enabled(flag: &AtomicBool) -> bool {
+    flag.load(Ordering::Relaxed)
+}
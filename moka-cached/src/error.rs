@@ -0,0 +1,110 @@
+use std::cell::Cell;
+use std::error::Error;
+use std::fmt;
+use std::sync::Arc;
+
+type BoxError = Box<dyn Error + Send + Sync>;
+
+/// An error returned by a cached function which isn't `Clone`, shared between the callers
+/// waiting on the same computation.
+///
+/// Each caller gets the error moka shares wrapped again, as a `Box<dyn Error + Send + Sync>`,
+/// an `anyhow::Error` or an `eyre::Report`. Display and source are those of the original error,
+/// which [`SharedError::get_ref`] returns.
+#[derive(Debug, Clone)]
+pub struct SharedError(Shared);
+
+#[derive(Debug, Clone)]
+enum Shared {
+    Boxed(Arc<BoxError>),
+    #[cfg(feature = "anyhow")]
+    Anyhow(Arc<anyhow::Error>),
+    #[cfg(feature = "eyre")]
+    Eyre(Arc<eyre::Report>),
+}
+
+impl SharedError {
+    /// The error returned by the function.
+    pub fn get_ref(&self) -> &(dyn Error + Send + Sync + 'static) {
+        match &self.0 {
+            Shared::Boxed(error) => error.as_ref().as_ref(),
+            #[cfg(feature = "anyhow")]
+            Shared::Anyhow(error) => error.as_ref().as_ref(),
+            #[cfg(feature = "eyre")]
+            Shared::Eyre(error) => error.as_ref().as_ref(),
+        }
+    }
+}
+
+impl From<Arc<BoxError>> for SharedError {
+    fn from(error: Arc<BoxError>) -> Self {
+        Self(Shared::Boxed(error))
+    }
+}
+
+#[cfg(feature = "anyhow")]
+impl From<Arc<anyhow::Error>> for SharedError {
+    fn from(error: Arc<anyhow::Error>) -> Self {
+        Self(Shared::Anyhow(error))
+    }
+}
+
+#[cfg(feature = "eyre")]
+impl From<Arc<eyre::Report>> for SharedError {
+    fn from(error: Arc<eyre::Report>) -> Self {
+        Self(Shared::Eyre(error))
+    }
+}
+
+impl fmt::Display for SharedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.get_ref(), f)
+    }
+}
+
+impl Error for SharedError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.get_ref().source()
+    }
+}
+
+// The error of a failed computation as moka shares it, turned back into the error type of the
+// function. The conversion is picked at compile time by autoref specialization: the generated
+// code calls `(&&ArcError::new(e)).into_error()`, which resolves to `CloneArcError` when the
+// error is `Clone` and falls back to `FromArcError` for a `From<Arc<E>>` impl. When neither
+// applies, `into_error` isn't found and rustc lists both bounds.
+#[doc(hidden)]
+pub struct ArcError<E>(Cell<Option<Arc<E>>>);
+
+impl<E> ArcError<E> {
+    pub fn new(error: Arc<E>) -> Self {
+        Self(Cell::new(Some(error)))
+    }
+
+    fn take(&self) -> Arc<E> {
+        self.0.take().expect("the error is converted once")
+    }
+}
+
+#[doc(hidden)]
+pub trait CloneArcError<E> {
+    fn into_error(self) -> E;
+}
+
+// the last caller holding the `Arc` gets the error itself, the others a clone of it
+impl<E: Clone> CloneArcError<E> for &&ArcError<E> {
+    fn into_error(self) -> E {
+        Arc::try_unwrap(self.take()).unwrap_or_else(|error| (*error).clone())
+    }
+}
+
+#[doc(hidden)]
+pub trait FromArcError<E> {
+    fn into_error(self) -> E;
+}
+
+impl<E: From<Arc<E>>> FromArcError<E> for &ArcError<E> {
+    fn into_error(self) -> E {
+        E::from(self.take())
+    }
+}
@@ -0,0 +1,71 @@
+use std::time::{Duration, Instant};
+
+use moka::Expiry;
+
+/// [`Expiry`] of a `#[cached(cache_errors = true, negative_ttl = ...)]` function, giving the
+/// cached errors a time to live of their own.
+///
+/// `ttl` applies to the other values, which never expire when it's `None`.
+pub struct ErrorExpiry<V> {
+    ttl: Option<Duration>,
+    negative_ttl: Duration,
+    is_err: fn(&V) -> bool,
+}
+
+impl<V> ErrorExpiry<V> {
+    /// Creates an expiry policy applying `negative_ttl` to the values `is_err` returns `true`
+    /// for and `ttl` to the rest.
+    pub fn new(ttl: Option<Duration>, negative_ttl: Duration, is_err: fn(&V) -> bool) -> Self {
+        Self {
+            ttl,
+            negative_ttl,
+            is_err,
+        }
+    }
+}
+
+impl<K, V> Expiry<K, V> for ErrorExpiry<V> {
+    fn expire_after_create(&self, _key: &K, value: &V, _created_at: Instant) -> Option<Duration> {
+        if (self.is_err)(value) {
+            Some(self.negative_ttl)
+        } else {
+            self.ttl
+        }
+    }
+
+    fn expire_after_update(
+        &self,
+        key: &K,
+        value: &V,
+        updated_at: Instant,
+        _duration_until_expiry: Option<Duration>,
+    ) -> Option<Duration> {
+        self.expire_after_create(key, value, updated_at)
+    }
+}
+
+/// [`Expiry`] of a `#[cached(value_ttl = true)]` function, the values are `(T, Duration)` and
+/// expire after their duration.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ValueTtl;
+
+impl<K, T> Expiry<K, (T, Duration)> for ValueTtl {
+    fn expire_after_create(
+        &self,
+        _key: &K,
+        value: &(T, Duration),
+        _created_at: Instant,
+    ) -> Option<Duration> {
+        Some(value.1)
+    }
+
+    fn expire_after_update(
+        &self,
+        _key: &K,
+        value: &(T, Duration),
+        _updated_at: Instant,
+        _duration_until_expiry: Option<Duration>,
+    ) -> Option<Duration> {
+        Some(value.1)
+    }
+}
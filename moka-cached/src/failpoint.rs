@@ -0,0 +1,17 @@
+/// Evaluates the failpoint `name` injected by `#[cached(failpoints = true)]`.
+///
+/// The configured action runs right away, so `panic` and `sleep` happen inside the wrapper.
+/// Returns `true` for `return`, which makes the wrapper skip the cache where it can.
+#[cfg(feature = "chaos")]
+#[doc(hidden)]
+pub fn failpoint(name: &str) -> bool {
+    fail::eval(name, |_| ()).is_some()
+}
+
+// without `chaos` the checks are constant and compile away
+#[cfg(not(feature = "chaos"))]
+#[doc(hidden)]
+#[inline(always)]
+pub fn failpoint(_name: &str) -> bool {
+    false
+}
@@ -0,0 +1,13 @@
+use std::future::Future;
+
+/// Returns the future built by a cached function returning `impl Future`, or the body of a cached
+/// `async` method, as is.
+///
+/// The type of the former can't be named, and the latter runs in place as an `async` block, which
+/// doesn't know its output on its own. Naming the output here tells the `?`s inside which error
+/// they convert into.
+#[doc(hidden)]
+#[inline(always)]
+pub fn typed_future<T, F: Future<Output = T>>(future: F) -> F {
+    future
+}
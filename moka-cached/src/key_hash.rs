@@ -0,0 +1,98 @@
+use std::hash::Hasher;
+
+/// The hasher of `key_hash = true`: SipHash-1-3 with fixed keys.
+///
+/// It's what `DefaultHasher::new()` computes today, pinned so a key hashes the same in every
+/// build and every run whatever the standard library does later. The `Hash` implementations
+/// of the key inputs still decide what is written, e.g. `usize` is as wide as the platform.
+#[derive(Debug, Clone)]
+pub struct KeyHasher {
+    v0: u64,
+    v1: u64,
+    v2: u64,
+    v3: u64,
+    // bytes which don't fill a word yet, little endian
+    tail: u64,
+    ntail: usize,
+    length: usize,
+}
+
+impl Default for KeyHasher {
+    fn default() -> Self {
+        KeyHasher {
+            v0: 0x736f_6d65_7073_6575,
+            v1: 0x646f_7261_6e64_6f6d,
+            v2: 0x6c79_6765_6e65_7261,
+            v3: 0x7465_6462_7974_6573,
+            tail: 0,
+            ntail: 0,
+            length: 0,
+        }
+    }
+}
+
+impl KeyHasher {
+    fn round(&mut self) {
+        self.v0 = self.v0.wrapping_add(self.v1);
+        self.v1 = self.v1.rotate_left(13) ^ self.v0;
+        self.v0 = self.v0.rotate_left(32);
+        self.v2 = self.v2.wrapping_add(self.v3);
+        self.v3 = self.v3.rotate_left(16) ^ self.v2;
+        self.v0 = self.v0.wrapping_add(self.v3);
+        self.v3 = self.v3.rotate_left(21) ^ self.v0;
+        self.v2 = self.v2.wrapping_add(self.v1);
+        self.v1 = self.v1.rotate_left(17) ^ self.v2;
+        self.v2 = self.v2.rotate_left(32);
+    }
+
+    fn compress(&mut self, word: u64) {
+        self.v3 ^= word;
+        self.round();
+        self.v0 ^= word;
+    }
+}
+
+// up to 8 bytes as a little endian word
+fn word(bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .rev()
+        .fold(0, |word, byte| (word << 8) | u64::from(*byte))
+}
+
+impl Hasher for KeyHasher {
+    fn write(&mut self, mut bytes: &[u8]) {
+        self.length += bytes.len();
+        if self.ntail != 0 {
+            let fill = (8 - self.ntail).min(bytes.len());
+            self.tail |= word(&bytes[..fill]) << (8 * self.ntail);
+            self.ntail += fill;
+            bytes = &bytes[fill..];
+            if self.ntail < 8 {
+                return;
+            }
+            let tail = self.tail;
+            self.compress(tail);
+            self.tail = 0;
+            self.ntail = 0;
+        }
+        let mut words = bytes.chunks_exact(8);
+        for chunk in &mut words {
+            self.compress(word(chunk));
+        }
+        let rest = words.remainder();
+        self.tail = word(rest);
+        self.ntail = rest.len();
+    }
+
+    fn finish(&self) -> u64 {
+        let mut state = self.clone();
+        let last = ((self.length as u64 & 0xff) << 56) | self.tail;
+        state.compress(last);
+        state.v2 ^= 0xff;
+        for _ in 0..3 {
+            state.round();
+        }
+        state.v0 ^ state.v1 ^ state.v2 ^ state.v3
+    }
+}
@@ -1,6 +1,63 @@
+#[cfg(feature = "swap")]
+#[doc(hidden)]
+pub use arc_swap;
+#[cfg(feature = "chaos")]
+#[doc(hidden)]
+pub use fail;
+#[doc(hidden)]
+pub use futures_util;
 #[doc(hidden)]
 pub use moka;
 #[doc(hidden)]
 pub use once_cell;
+#[cfg(feature = "tokio")]
+#[doc(hidden)]
+pub use tokio;
+#[cfg(feature = "tracing")]
+#[doc(hidden)]
+pub use tracing;
+
+#[doc(hidden)]
+pub use backend::BackendRef;
+pub use backend::CacheBackend;
+pub use bounds::{CacheKey, CacheValue};
+#[doc(hidden)]
+pub use enabled::is_enabled;
+pub use error::SharedError;
+#[doc(hidden)]
+pub use error::{ArcError, CloneArcError, FromArcError};
+pub use expiry::{ErrorExpiry, ValueTtl};
+#[doc(hidden)]
+pub use failpoint::failpoint;
+pub use future::typed_future;
+pub use key_hash::KeyHasher;
+pub use moka_cached_proc::{cache_invalidate, cache_update, cached, once};
+#[cfg(feature = "swap")]
+pub use oversize::OversizeSlot;
+pub use pin::{PinnedExpiry, PinnedKeys};
+pub use stale::StaleValues;
+pub use stats::{CacheStats, HitMissCounters};
+pub use warm::Warmed;
+pub use warmup::Warmup;
+pub use wrap_return::Return;
 
-pub use moka_cached_proc::cached;
+mod backend;
+mod bounds;
+pub mod diagnostics;
+mod enabled;
+mod error;
+mod expiry;
+mod failpoint;
+mod future;
+mod key_hash;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+#[cfg(feature = "swap")]
+mod oversize;
+mod pin;
+mod stale;
+mod stats;
+pub mod testing;
+mod warm;
+mod warmup;
+mod wrap_return;
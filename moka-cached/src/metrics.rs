@@ -0,0 +1,24 @@
+//! Integration with the [`metrics`](https://docs.rs/metrics) crate, enabled by the `metrics` feature.
+//!
+//! Metrics are labeled with the name of the cached function as `fn`.
+//! Nothing is exported until a recorder is installed.
+
+/// Counter of the lookups answered from the cache.
+pub const HITS: &str = "cold_moka_cache_hits_total";
+
+/// Counter of the lookups which computed the value.
+pub const MISSES: &str = "cold_moka_cache_misses_total";
+
+/// Histogram of the sizes reported by `record_size` for every computed value.
+pub const VALUE_SIZE: &str = "cold_moka_value_size";
+
+#[doc(hidden)]
+pub fn record_lookup(function: &'static str, fresh: bool) {
+    let name = if fresh { MISSES } else { HITS };
+    ::metrics::counter!(name, "fn" => function).increment(1);
+}
+
+#[doc(hidden)]
+pub fn record_size<V>(function: &'static str, value: &V, size: impl FnOnce(&V) -> f64) {
+    ::metrics::histogram!(VALUE_SIZE, "fn" => function).record(size(value));
+}
@@ -0,0 +1,65 @@
+use arc_swap::ArcSwapOption;
+use std::sync::Arc;
+
+/// Holds the single oversized value of a `#[cached(oversize = "dedicated")]` function outside
+/// the cache, so it doesn't evict everything else. A new oversized value replaces the previous.
+#[derive(Debug)]
+pub struct OversizeSlot<K, V> {
+    entry: ArcSwapOption<(K, V)>,
+}
+
+impl<K, V> OversizeSlot<K, V> {
+    /// Creates an empty slot.
+    pub const fn new() -> Self {
+        Self {
+            entry: ArcSwapOption::const_empty(),
+        }
+    }
+
+    /// Replaces the held value.
+    pub fn store(&self, key: K, value: V) {
+        self.entry.store(Some(Arc::new((key, value))));
+    }
+
+    /// Drops the held value.
+    pub fn clear(&self) {
+        self.entry.store(None);
+    }
+}
+
+impl<K: Eq, V: Clone> OversizeSlot<K, V> {
+    /// Drops the held value if it was computed for `key`.
+    pub fn remove(&self, key: &K) {
+        let current = self.entry.load();
+        if current.as_ref().is_some_and(|entry| entry.0 == *key) {
+            // a value stored in the meantime is kept
+            self.entry.compare_and_swap(&current, None);
+        }
+    }
+
+    /// Drops the held value if `predicate` returns `true` for it.
+    pub fn remove_if(&self, predicate: impl Fn(&K, &V) -> bool) {
+        let current = self.entry.load();
+        if current
+            .as_ref()
+            .is_some_and(|entry| predicate(&entry.0, &entry.1))
+        {
+            self.entry.compare_and_swap(&current, None);
+        }
+    }
+
+    /// Returns the held value if it was computed for `key`.
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.entry
+            .load()
+            .as_ref()
+            .filter(|entry| entry.0 == *key)
+            .map(|entry| entry.1.clone())
+    }
+}
+
+impl<K, V> Default for OversizeSlot<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,99 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+use moka::Expiry;
+
+/// Keys of a `#[cached(pin = true)]` function which must never expire.
+///
+/// Filled by the generated `<fn>_pin` helper and consulted by [`PinnedExpiry`].
+#[derive(Debug)]
+pub struct PinnedKeys<K> {
+    keys: RwLock<HashSet<K>>,
+}
+
+impl<K> Default for PinnedKeys<K> {
+    fn default() -> Self {
+        Self {
+            keys: RwLock::new(HashSet::new()),
+        }
+    }
+}
+
+impl<K: Hash + Eq> PinnedKeys<K> {
+    /// Marks `key` as pinned.
+    pub fn pin(&self, key: K) {
+        self.keys
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(key);
+    }
+
+    /// Removes the pin from `key`, returns `true` if it was pinned.
+    pub fn unpin(&self, key: &K) -> bool {
+        self.keys
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(key)
+    }
+
+    /// Returns `true` if `key` is pinned.
+    pub fn is_pinned(&self, key: &K) -> bool {
+        self.keys
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .contains(key)
+    }
+}
+
+/// [`Expiry`] applying `ttl` to every entry except the pinned ones.
+///
+/// Pinning only affects expiration, pinned entries can still be evicted when the cache is full.
+#[derive(Debug)]
+pub struct PinnedExpiry<K: 'static> {
+    pinned: &'static PinnedKeys<K>,
+    ttl: Duration,
+}
+
+impl<K> PinnedExpiry<K> {
+    /// Creates an expiry policy consulting `pinned` before applying `ttl`.
+    pub fn new(pinned: &'static PinnedKeys<K>, ttl: Duration) -> Self {
+        Self { pinned, ttl }
+    }
+}
+
+impl<K: Hash + Eq, V> Expiry<K, V> for PinnedExpiry<K> {
+    fn expire_after_create(&self, key: &K, _value: &V, _created_at: Instant) -> Option<Duration> {
+        if self.pinned.is_pinned(key) {
+            None
+        } else {
+            Some(self.ttl)
+        }
+    }
+
+    fn expire_after_read(
+        &self,
+        key: &K,
+        _value: &V,
+        _read_at: Instant,
+        duration_until_expiry: Option<Duration>,
+        _last_modified_at: Instant,
+    ) -> Option<Duration> {
+        if self.pinned.is_pinned(key) {
+            None
+        } else {
+            duration_until_expiry
+        }
+    }
+
+    fn expire_after_update(
+        &self,
+        key: &K,
+        value: &V,
+        updated_at: Instant,
+        _duration_until_expiry: Option<Duration>,
+    ) -> Option<Duration> {
+        self.expire_after_create(key, value, updated_at)
+    }
+}
@@ -0,0 +1,92 @@
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use std::sync::Mutex;
+
+/// Last good values of a `#[cached(result_fallback = true)]` function, kept once they expire or
+/// are evicted from the cache and returned when computing them again fails.
+///
+/// Holds up to `capacity` values, a new one replaces the least recently used when it's full.
+#[derive(Debug)]
+pub struct StaleValues<K, V> {
+    values: Mutex<Lru<K, V>>,
+    capacity: usize,
+}
+
+// values with the tick of their last use, and the keys in the order of these ticks
+#[derive(Debug)]
+struct Lru<K, V> {
+    values: HashMap<K, (V, u64)>,
+    order: BTreeMap<u64, K>,
+    tick: u64,
+}
+
+impl<K, V> StaleValues<K, V> {
+    /// Creates an empty store for up to `capacity` values.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            values: Mutex::new(Lru {
+                values: HashMap::new(),
+                order: BTreeMap::new(),
+                tick: 0,
+            }),
+            capacity,
+        }
+    }
+
+    /// Drops every value.
+    pub fn clear(&self) {
+        let mut lru = self.values.lock().unwrap_or_else(|e| e.into_inner());
+        lru.values.clear();
+        lru.order.clear();
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> StaleValues<K, V> {
+    /// Keeps the value which left the cache.
+    pub fn keep(&self, key: K, value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut lru = self.values.lock().unwrap_or_else(|e| e.into_inner());
+        let tick = lru.next_tick();
+        match lru.values.insert(key.clone(), (value, tick)) {
+            Some((_, used)) => {
+                lru.order.remove(&used);
+            }
+            None if lru.values.len() > self.capacity => {
+                if let Some((_, oldest)) = lru.order.pop_first() {
+                    lru.values.remove(&oldest);
+                }
+            }
+            None => {}
+        }
+        lru.order.insert(tick, key);
+    }
+
+    /// Drops the value of an invalidated key.
+    pub fn forget(&self, key: &K) {
+        let mut lru = self.values.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some((_, used)) = lru.values.remove(key) {
+            lru.order.remove(&used);
+        }
+    }
+
+    /// Returns the last good value of `key`.
+    pub fn get(&self, key: &K) -> Option<V> {
+        let mut lru = self.values.lock().unwrap_or_else(|e| e.into_inner());
+        let tick = lru.next_tick();
+        let lru = &mut *lru;
+        let (value, used) = lru.values.get_mut(key)?;
+        let key = lru.order.remove(used)?;
+        *used = tick;
+        lru.order.insert(tick, key);
+        Some(value.clone())
+    }
+}
+
+impl<K, V> Lru<K, V> {
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+}
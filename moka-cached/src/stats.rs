@@ -0,0 +1,155 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Hit and miss counts of a `#[cached(stats = true)]` function, returned by the generated
+/// `<fn>_cache_stats`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    /// Calls answered without computing the value, including the ones which waited for
+    /// a concurrent call computing it.
+    pub hits: u64,
+    /// Calls which computed the value.
+    pub misses: u64,
+    /// Approximate number of cached entries, moka updates it lazily.
+    pub entry_count: u64,
+}
+
+impl CacheStats {
+    /// Share of the calls which were hits, `0.0` before the first call.
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            calls => self.hits as f64 / calls as f64,
+        }
+    }
+}
+
+/// Counters kept next to the cache of a `#[cached(stats = true)]` function.
+///
+/// The generated code only goes through these methods, the orderings are chosen here: a miss
+/// is counted after the call it belongs to, and publishes it. Reading the misses first makes
+/// every call behind them visible, so the stats never see more misses than calls.
+///
+/// The generated statics use the std atomics, the loom model test swaps in loom's.
+#[doc(hidden)]
+#[derive(Debug, Default)]
+pub struct HitMissCounters<A = AtomicU64> {
+    calls: A,
+    misses: A,
+}
+
+impl HitMissCounters {
+    pub const fn new() -> Self {
+        Self {
+            calls: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+}
+
+// loom's atomics can't be created in a const context, so they get their own constructor
+#[cfg(feature = "loom")]
+impl HitMissCounters<loom::sync::atomic::AtomicU64> {
+    pub fn new_loom() -> Self {
+        Self {
+            calls: loom::sync::atomic::AtomicU64::new(0),
+            misses: loom::sync::atomic::AtomicU64::new(0),
+        }
+    }
+}
+
+impl<A: counter::Counter> HitMissCounters<A> {
+    pub fn call(&self) {
+        self.calls.add(Ordering::Relaxed);
+    }
+
+    // called from the computation of the value, so concurrent callers coalesced into
+    // a single computation count as a single miss
+    pub fn miss(&self) {
+        self.misses.add(Ordering::Release);
+    }
+
+    pub fn stats(&self, entry_count: u64) -> CacheStats {
+        let (calls, misses) = self.counts();
+        // a call made while the warmup ended may have its miss counted but not itself
+        let hits = calls.saturating_sub(misses);
+        CacheStats {
+            hits,
+            misses,
+            entry_count,
+        }
+    }
+
+    // the calls and the misses, read in the order which keeps the misses below the calls
+    fn counts(&self) -> (u64, u64) {
+        let misses = self.misses.get(Ordering::Acquire);
+        (self.calls.get(Ordering::Relaxed), misses)
+    }
+}
+
+mod counter {
+    use std::sync::atomic::Ordering;
+
+    /// The two operations the counters need from an atomic.
+    pub trait Counter {
+        fn add(&self, ordering: Ordering);
+        fn get(&self, ordering: Ordering) -> u64;
+    }
+
+    impl Counter for std::sync::atomic::AtomicU64 {
+        fn add(&self, ordering: Ordering) {
+            self.fetch_add(1, ordering);
+        }
+
+        fn get(&self, ordering: Ordering) -> u64 {
+            self.load(ordering)
+        }
+    }
+
+    #[cfg(feature = "loom")]
+    impl Counter for loom::sync::atomic::AtomicU64 {
+        fn add(&self, ordering: Ordering) {
+            self.fetch_add(1, ordering);
+        }
+
+        fn get(&self, ordering: Ordering) -> u64 {
+            self.load(ordering)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "loom"))]
+mod test {
+    use loom::sync::Arc;
+    use loom::thread;
+
+    use super::HitMissCounters;
+
+    #[test]
+    fn misses_never_exceed_calls() {
+        loom::model(|| {
+            let counters = Arc::new(HitMissCounters::new_loom());
+            let handles: Vec<_> = (0..2)
+                .map(|_| {
+                    let counters = counters.clone();
+                    thread::spawn(move || {
+                        counters.call();
+                        counters.miss();
+                    })
+                })
+                .collect();
+            // a hit of this thread, racing with the misses of the others
+            counters.call();
+            // the hits are the calls without a miss, a miss seen before its call would make
+            // them wrap around, which `stats` only hides
+            let (calls, misses) = counters.counts();
+            assert!(misses <= calls, "{} misses for {} calls", misses, calls);
+            assert!((1..=3).contains(&calls) && misses <= 2);
+            for handle in handles {
+                handle.join().unwrap();
+            }
+            assert_eq!(counters.counts(), (3, 2));
+            let stats = counters.stats(0);
+            assert_eq!((stats.hits, stats.misses), (1, 2));
+        });
+    }
+}
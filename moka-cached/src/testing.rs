@@ -0,0 +1,83 @@
+//! Fakes for testing the code built on top of the caches.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Mutex;
+
+use crate::CacheBackend;
+
+/// An operation performed on a [`RecordingBackend`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Op<K> {
+    /// A lookup of the key, `hit` tells whether a value was found.
+    Get { key: K, hit: bool },
+    /// An insertion of a value for the key.
+    Insert(K),
+    /// An invalidation of the key.
+    Invalidate(K),
+}
+
+/// A [`CacheBackend`] over a `HashMap`, logging every operation so tests can assert the exact
+/// sequence of cache interactions.
+#[derive(Debug)]
+pub struct RecordingBackend<K, V> {
+    entries: Mutex<HashMap<K, V>>,
+    ops: Mutex<Vec<Op<K>>>,
+}
+
+impl<K, V> Default for RecordingBackend<K, V> {
+    fn default() -> Self {
+        Self {
+            entries: Mutex::new(HashMap::new()),
+            ops: Mutex::new(Vec::new()),
+        }
+    }
+}
+
+impl<K: Clone, V> RecordingBackend<K, V> {
+    /// Creates an empty backend.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the operations performed so far, oldest first.
+    pub fn ops(&self) -> Vec<Op<K>> {
+        self.ops.lock().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    fn record(&self, op: Op<K>) {
+        self.ops.lock().unwrap_or_else(|e| e.into_inner()).push(op);
+    }
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> CacheBackend<K, V> for RecordingBackend<K, V> {
+    fn get(&self, key: &K) -> Option<V> {
+        let value = self
+            .entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(key)
+            .cloned();
+        self.record(Op::Get {
+            key: key.clone(),
+            hit: value.is_some(),
+        });
+        value
+    }
+
+    fn insert(&self, key: K, value: V) {
+        self.record(Op::Insert(key.clone()));
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(key, value);
+    }
+
+    fn invalidate(&self, key: &K) {
+        self.record(Op::Invalidate(key.clone()));
+        self.entries
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(key);
+    }
+}
@@ -0,0 +1,19 @@
+/// Outcome of the `<fn>_warm` helper generated by `#[cached]`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Warmed {
+    /// Inputs whose value was computed, errors and `None`s included.
+    pub computed: usize,
+    /// Inputs whose value was already cached.
+    pub present: usize,
+}
+
+impl Warmed {
+    #[doc(hidden)]
+    pub fn record(&mut self, present: bool) {
+        if present {
+            self.present += 1;
+        } else {
+            self.computed += 1;
+        }
+    }
+}
@@ -0,0 +1,33 @@
+use std::time::{Duration, Instant};
+
+use once_cell::sync::OnceCell;
+
+/// Window after the creation of a cache during which its metrics are not recorded,
+/// so the misses of a cold cache don't skew the dashboards.
+#[derive(Debug)]
+pub struct Warmup {
+    started: OnceCell<Instant>,
+    duration: Duration,
+}
+
+impl Warmup {
+    /// Creates a window of `duration`, it starts with [`Warmup::start`].
+    pub const fn new(duration: Duration) -> Self {
+        Self {
+            started: OnceCell::new(),
+            duration,
+        }
+    }
+
+    /// Starts the window, called when the cache is created. Later calls have no effect.
+    pub fn start(&self) {
+        self.started.get_or_init(Instant::now);
+    }
+
+    /// Returns `true` once the window has passed.
+    pub fn is_over(&self) -> bool {
+        self.started
+            .get()
+            .is_some_and(|started| started.elapsed() >= self.duration)
+    }
+}
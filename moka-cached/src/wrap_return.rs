@@ -0,0 +1,43 @@
+use std::ops::{Deref, DerefMut};
+
+/// Value returned by a `#[cached(wrap_return = true)]` function, telling whether it came from
+/// the cache.
+///
+/// The function builds it with [`Return::new`], the macro caches the `value` alone and sets
+/// `was_cached` on every call.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Return<T> {
+    /// The returned value.
+    pub value: T,
+    /// `true` if the value was found in the cache, `false` if it was computed by this call.
+    pub was_cached: bool,
+}
+
+impl<T> Return<T> {
+    /// Wraps a freshly computed value.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            was_cached: false,
+        }
+    }
+
+    /// Unwraps the value.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Deref for Return<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+impl<T> DerefMut for Return<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+}
@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use cold_moka::cached;
+
+#[cached(arc = true)]
+fn blob(len: usize) -> Vec<u8> {
+    vec![0; len]
+}
+
+#[test]
+fn hits_share_the_value() {
+    let first: Arc<Vec<u8>> = blob(16);
+    let second = blob(16);
+    assert!(Arc::ptr_eq(&first, &second));
+    assert_eq!(first.len(), 16);
+}
+
+#[cached(arc = true)]
+fn fallible_blob(len: usize) -> Result<Vec<u8>, String> {
+    if len == 0 {
+        Err("empty".to_owned())
+    } else {
+        Ok(vec![0; len])
+    }
+}
+
+#[cached(arc = true)]
+fn optional_blob(len: usize) -> Option<String> {
+    (len > 0).then(|| "x".repeat(len))
+}
+
+#[test]
+fn result_and_option_wrap_the_value() {
+    let first: Result<Arc<Vec<u8>>, String> = fallible_blob(4);
+    assert!(Arc::ptr_eq(&first.unwrap(), &fallible_blob(4).unwrap()));
+    assert_eq!(fallible_blob(0), Err("empty".to_owned()));
+
+    let first: Option<Arc<String>> = optional_blob(3);
+    assert!(Arc::ptr_eq(&first.unwrap(), &optional_blob(3).unwrap()));
+    assert_eq!(optional_blob(0), None);
+}
+
+#[cached(arc = true)]
+async fn async_blob(len: usize) -> Result<Vec<u8>, String> {
+    Ok(vec![1; len])
+}
+
+#[tokio::test]
+async fn async_hits_share_the_value() {
+    let first = async_blob(8).await.unwrap();
+    let second = async_blob(8).await.unwrap();
+    assert!(Arc::ptr_eq(&first, &second));
+    assert_eq!(async_blob_if_cached(8).await, Some(first));
+}
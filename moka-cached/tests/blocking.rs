@@ -0,0 +1,37 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread::{self, ThreadId};
+
+use cold_moka::cached;
+
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(blocking = true)]
+async fn computed_on(x: u64) -> (u64, ThreadId) {
+    CALLS.fetch_add(1, Ordering::SeqCst);
+    (x, thread::current().id())
+}
+
+#[tokio::test]
+async fn runs_on_a_blocking_thread() {
+    let (x, thread) = computed_on(1).await;
+    assert_eq!(x, 1);
+    assert_ne!(thread, thread::current().id());
+
+    computed_on(1).await;
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+}
+
+#[cached(blocking = true)]
+async fn fails(x: u64) -> Result<u64, String> {
+    if x == 0 {
+        panic!("zero");
+    }
+    Err(format!("{} failed", x))
+}
+
+#[tokio::test]
+async fn errors_and_panics_reach_the_caller() {
+    assert_eq!(fails(1).await, Err("1 failed".to_owned()));
+    let panicked = tokio::spawn(fails(0)).await.unwrap_err();
+    assert!(panicked.is_panic());
+}
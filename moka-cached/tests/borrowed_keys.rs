@@ -0,0 +1,75 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use cold_moka::cached;
+
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(dump = true)]
+fn name_len(name: &str) -> usize {
+    CALLS.fetch_add(1, Ordering::SeqCst);
+    name.len()
+}
+
+#[test]
+fn str_keys_are_owned() {
+    let name = String::from("abc");
+    assert_eq!(name_len(&name), 3);
+    assert_eq!(name_len("abc"), 3);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+
+    let entries: Vec<(String, usize)> = name_len_cache_dump(None);
+    assert_eq!(entries, vec![("abc".to_owned(), 3)]);
+}
+
+type MixedKey = (String, u64, Vec<u8>, u64);
+
+#[allow(clippy::ptr_arg)]
+#[cached(dump = true)]
+fn mixed(label: &String, factor: &u64, bytes: &[u8], offset: u64) -> u64 {
+    label.len() as u64 * factor + bytes.len() as u64 + offset
+}
+
+#[test]
+fn mixed_keys_are_owned() {
+    assert_eq!(mixed(&"ab".to_owned(), &3, b"xyz", 1), 10);
+    assert!(mixed_cache_contains(&"ab".to_owned(), &3, b"xyz", 1));
+
+    let entries: Vec<(MixedKey, u64)> = mixed_cache_dump(None);
+    assert_eq!(
+        entries,
+        vec![(("ab".to_owned(), 3, b"xyz".to_vec(), 1), 10)]
+    );
+}
+
+#[cached(key = "id")]
+fn static_label(label: &'static str, id: u64) -> String {
+    format!("{} {}", label, id)
+}
+
+#[cached]
+fn static_key(label: &'static str) -> usize {
+    label.len()
+}
+
+#[test]
+fn static_references_are_kept() {
+    assert_eq!(static_key("abc"), 3);
+    assert_eq!(static_label("user", 1), "user 1");
+    assert!(static_key_cache_contains("abc"));
+}
+
+static ASYNC_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached]
+async fn async_mixed(name: &str, id: &u64) -> Result<String, String> {
+    ASYNC_CALLS.fetch_add(1, Ordering::SeqCst);
+    Ok(format!("{}{}", name, id))
+}
+
+#[tokio::test]
+async fn async_borrowed_keys() {
+    assert_eq!(async_mixed("a", &1).await, Ok("a1".to_owned()));
+    assert_eq!(async_mixed("a", &1).await, Ok("a1".to_owned()));
+    assert_eq!(ASYNC_CALLS.load(Ordering::SeqCst), 1);
+    assert_eq!(async_mixed_if_cached("a", &1).await, Some("a1".to_owned()));
+}
@@ -0,0 +1,121 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use cold_moka::cached;
+
+type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+trait Repo {
+    fn fetch(&self, id: u64) -> BoxFuture<'static, String>;
+
+    fn find(&self, id: u64) -> Pin<Box<dyn Future<Output = Option<String>> + Send>>;
+}
+
+#[derive(Default)]
+struct Db {
+    fetches: Arc<AtomicUsize>,
+    finds: Arc<AtomicUsize>,
+}
+
+impl Repo for Db {
+    #[cached(boxed_future = true)]
+    fn fetch(&self, id: u64) -> BoxFuture<'static, String> {
+        let fetches = self.fetches.clone();
+        Box::pin(async move {
+            fetches.fetch_add(1, Ordering::SeqCst);
+            format!("row {}", id)
+        })
+    }
+
+    #[cached(boxed_future = true)]
+    fn find(&self, id: u64) -> Pin<Box<dyn Future<Output = Option<String>> + Send>> {
+        let finds = self.finds.clone();
+        Box::pin(async move {
+            finds.fetch_add(1, Ordering::SeqCst);
+            (id < 3).then(|| format!("row {}", id))
+        })
+    }
+}
+
+#[tokio::test]
+async fn trait_object_methods() {
+    let db = Db::default();
+    let fetches = db.fetches.clone();
+    let finds = db.finds.clone();
+    let repo: Box<dyn Repo> = Box::new(db);
+
+    assert_eq!(repo.fetch(1).await, "row 1");
+    assert_eq!(repo.fetch(1).await, "row 1");
+    assert_eq!(fetches.load(Ordering::SeqCst), 1);
+
+    assert_eq!(repo.find(2).await, Some("row 2".to_owned()));
+    assert_eq!(repo.find(2).await, Some("row 2".to_owned()));
+    assert_eq!(finds.load(Ordering::SeqCst), 1);
+    // `None` is not cached
+    assert_eq!(repo.find(3).await, None);
+    assert_eq!(repo.find(3).await, None);
+    assert_eq!(finds.load(Ordering::SeqCst), 3);
+}
+
+static FREE_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(boxed_future = true)]
+fn free(id: u64) -> BoxFuture<'static, Result<u64, String>> {
+    Box::pin(async move {
+        FREE_CALLS.fetch_add(1, Ordering::SeqCst);
+        Ok(id)
+    })
+}
+
+#[tokio::test]
+async fn free_function() {
+    assert_eq!(free(1).await, Ok(1));
+    assert_eq!(free(1).await, Ok(1));
+    assert_eq!(FREE_CALLS.load(Ordering::SeqCst), 1);
+
+    free_cache_clear();
+    assert_eq!(free(1).await, Ok(1));
+    assert_eq!(FREE_CALLS.load(Ordering::SeqCst), 2);
+}
+
+static COMBINED_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+// built from combinators rather than `async`, the value it resolves to is cached
+#[cached]
+fn combined(id: u64) -> impl Future<Output = Result<u64, String>> {
+    COMBINED_CALLS.fetch_add(1, Ordering::SeqCst);
+    std::future::ready(if id > 0 {
+        Ok(id * 2)
+    } else {
+        Err("zero".to_owned())
+    })
+}
+
+#[tokio::test]
+async fn impl_future_is_detected() {
+    assert_eq!(combined(1).await, Ok(2));
+    assert_eq!(combined(1).await, Ok(2));
+    assert_eq!(COMBINED_CALLS.load(Ordering::SeqCst), 2);
+    assert_eq!(combined_if_cached(1).await, Some(2));
+    assert_eq!(combined(0).await, Err("zero".to_owned()));
+    assert_eq!(combined_if_cached(0).await, None);
+}
+
+static DETECTED_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached]
+fn detected(id: u64) -> BoxFuture<'static, u64> {
+    Box::pin(async move {
+        DETECTED_CALLS.fetch_add(1, Ordering::SeqCst);
+        id
+    })
+}
+
+#[tokio::test]
+async fn box_future_is_detected() {
+    assert_eq!(detected(1).await, 1);
+    assert_eq!(detected(1).await, 1);
+    assert_eq!(DETECTED_CALLS.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,36 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use cold_moka::cached;
+
+static VERSION: AtomicU64 = AtomicU64::new(0);
+
+#[cached(bypass = true)]
+fn balance(account: u64) -> u64 {
+    account * 100 + VERSION.fetch_add(1, Ordering::SeqCst)
+}
+
+#[test]
+fn uncached_skips_the_cache() {
+    assert_eq!(balance(1), 100);
+    assert_eq!(balance_uncached(1), 101);
+    assert_eq!(balance_uncached(2), 202);
+    assert_eq!(balance(1), 100);
+    assert!(!balance_cache_contains(2));
+}
+
+static ASYNC_VERSION: AtomicU64 = AtomicU64::new(0);
+
+// the allow applies to the generated function too, it runs the same body
+#[cached(bypass = true, bypass_name = "fresh_quote", key = "symbol")]
+#[allow(unused_variables)]
+pub(crate) async fn quote(symbol: String, trace_id: u64) -> Result<u64, String> {
+    Ok(ASYNC_VERSION.fetch_add(1, Ordering::SeqCst))
+}
+
+#[tokio::test]
+async fn async_uncached_skips_the_cache() {
+    assert_eq!(quote("ABC".to_owned(), 1).await, Ok(0));
+    assert_eq!(fresh_quote("ABC".to_owned(), 2).await, Ok(1));
+    assert_eq!(quote("ABC".to_owned(), 3).await, Ok(0));
+    assert_eq!(quote_if_cached("ABC".to_owned()).await, Some(0));
+}
@@ -0,0 +1,62 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use cold_moka::cached;
+
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(cache_if = "|v: &Vec<u64>| !v.is_empty()")]
+fn orders(user: u64) -> Vec<u64> {
+    CALLS.fetch_add(1, Ordering::SeqCst);
+    (0..user).collect()
+}
+
+#[test]
+fn rejected_values_are_not_cached() {
+    assert!(orders(0).is_empty());
+    assert!(orders(0).is_empty());
+    assert_eq!(orders_if_cached(0), None);
+    assert_eq!(orders(2), vec![0, 1]);
+    assert_eq!(orders(2), vec![0, 1]);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 3);
+}
+
+#[cached(cache_if = "|v: &String| v != \"pending\"")]
+fn status(id: u64) -> Result<String, String> {
+    match id {
+        0 => Err("unknown".to_owned()),
+        1 => Ok("pending".to_owned()),
+        _ => Ok("done".to_owned()),
+    }
+}
+
+#[test]
+fn checks_the_ok_value() {
+    assert_eq!(status(1), Ok("pending".to_owned()));
+    assert_eq!(status_if_cached(1), None);
+    assert_eq!(status(2), Ok("done".to_owned()));
+    assert_eq!(status_if_cached(2), Some("done".to_owned()));
+    assert!(status(0).is_err());
+}
+
+#[cached(cache_if = "|v: &u64| v % 2 == 0")]
+async fn lookup(x: u64) -> Option<u64> {
+    (x > 0).then_some(x)
+}
+
+#[cached(sync_cache = true, cache_if = "|v: &u64| *v > 0")]
+async fn positive(x: u64) -> u64 {
+    x
+}
+
+#[tokio::test]
+async fn async_rejected_values_are_not_cached() {
+    assert_eq!(lookup(1).await, Some(1));
+    assert_eq!(lookup_if_cached(1).await, None);
+    assert_eq!(lookup(2).await, Some(2));
+    assert_eq!(lookup_if_cached(2).await, Some(2));
+
+    assert_eq!(positive(0).await, 0);
+    assert_eq!(positive_if_cached(0).await, None);
+    assert_eq!(positive(1).await, 1);
+    assert_eq!(positive_if_cached(1).await, Some(1));
+}
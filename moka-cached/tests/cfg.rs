@@ -0,0 +1,27 @@
+use cold_moka::cached;
+
+// `#[cfg]`s of the function are evaluated before the macro runs, so nothing is generated
+// for a function which isn't compiled, whichever side of `#[cached]` they are on
+#[cached]
+#[cfg(not(test))]
+fn release_only(x: u32) -> u32 {
+    x
+}
+
+#[cached(ttl = 60, pin = true)]
+#[cfg(any())]
+fn never(x: u32) -> u32 {
+    x
+}
+
+#[cfg(test)]
+#[cached]
+fn test_only(x: u32) -> u32 {
+    x
+}
+
+#[test]
+fn cfg_is_forwarded() {
+    assert_eq!(test_only(1), 1);
+    assert_eq!(test_only_if_cached(1), Some(1));
+}
@@ -0,0 +1,60 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use cold_moka::cached;
+
+static COMPUTED: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(failpoints = true)]
+fn counted(x: u64) -> u64 {
+    COMPUTED.fetch_add(1, Ordering::SeqCst);
+    x * 2
+}
+
+#[test]
+fn bypass_warm_cache() {
+    assert_eq!(counted(1), 2);
+    assert_eq!(counted(1), 2);
+    assert_eq!(COMPUTED.load(Ordering::SeqCst), 1);
+
+    fail::cfg("cold_moka::counted::before_get", "return").unwrap();
+    assert_eq!(counted(1), 2);
+    assert_eq!(COMPUTED.load(Ordering::SeqCst), 2);
+
+    fail::remove("cold_moka::counted::before_get");
+    assert_eq!(counted(1), 2);
+    assert_eq!(COMPUTED.load(Ordering::SeqCst), 2);
+}
+
+#[cached(failpoints = true)]
+fn fragile(x: u64) -> Result<u64, String> {
+    Ok(x + 1)
+}
+
+#[test]
+fn panic_before_insert() {
+    fail::cfg("cold_moka::fragile::before_insert", "panic").unwrap();
+    assert!(std::panic::catch_unwind(|| fragile(1)).is_err());
+    fail::remove("cold_moka::fragile::before_insert");
+
+    assert!(!fragile_cache_contains(1));
+    assert_eq!(fragile(1), Ok(2));
+    assert!(fragile_cache_contains(1));
+}
+
+#[cached(failpoints = true)]
+async fn slow(x: u64) -> u64 {
+    x * 3
+}
+
+#[tokio::test]
+async fn delay_after_compute() {
+    fail::cfg("cold_moka::slow::after_compute", "sleep(50)").unwrap();
+    let started = std::time::Instant::now();
+    assert_eq!(slow(1).await, 3);
+    assert!(started.elapsed() >= std::time::Duration::from_millis(50));
+    fail::remove("cold_moka::slow::after_compute");
+
+    let started = std::time::Instant::now();
+    assert_eq!(slow(1).await, 3);
+    assert!(started.elapsed() < std::time::Duration::from_millis(50));
+}
@@ -0,0 +1,303 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use cold_moka::cached;
+
+static CLEAR_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached]
+fn clear(x: u32) -> u32 {
+    CLEAR_CALLS.fetch_add(1, Ordering::SeqCst);
+    x
+}
+
+#[test]
+fn cache_clear() {
+    clear(1);
+    clear(2);
+    clear(1);
+    assert_eq!(CLEAR_CALLS.load(Ordering::SeqCst), 2);
+
+    clear_cache_clear();
+    clear(1);
+    clear(2);
+    assert_eq!(CLEAR_CALLS.load(Ordering::SeqCst), 4);
+}
+
+static ASYNC_CLEAR_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached]
+async fn async_clear(x: u32) -> u32 {
+    ASYNC_CLEAR_CALLS.fetch_add(1, Ordering::SeqCst);
+    x
+}
+
+#[tokio::test]
+async fn async_cache_clear() {
+    async_clear(1).await;
+    async_clear(1).await;
+    assert_eq!(ASYNC_CLEAR_CALLS.load(Ordering::SeqCst), 1);
+
+    async_clear_cache_clear();
+    async_clear(1).await;
+    assert_eq!(ASYNC_CLEAR_CALLS.load(Ordering::SeqCst), 2);
+}
+
+pub struct Context;
+
+static RENDER_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(key = "template_id")]
+fn render(_ctx: Context, template_id: u64) -> String {
+    RENDER_CALLS.fetch_add(1, Ordering::SeqCst);
+    format!("rendered {}", template_id)
+}
+
+#[test]
+fn cache_insert() {
+    render_cache_insert(1, "primed".to_owned());
+    assert_eq!(render(Context, 1), "primed");
+    assert_eq!(RENDER_CALLS.load(Ordering::SeqCst), 0);
+
+    assert_eq!(render(Context, 2), "rendered 2");
+    assert_eq!(RENDER_CALLS.load(Ordering::SeqCst), 1);
+}
+
+static ASYNC_RENDER_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(key = "name", convert = "{ name.to_lowercase() }")]
+async fn async_render(_ctx: Context, name: String) -> Result<String, String> {
+    ASYNC_RENDER_CALLS.fetch_add(1, Ordering::SeqCst);
+    Ok(format!("rendered {}", name))
+}
+
+#[tokio::test]
+async fn async_cache_insert() {
+    async_render_cache_insert("Index".to_owned(), "primed".to_owned()).await;
+    assert_eq!(
+        async_render(Context, "INDEX".to_owned()).await,
+        Ok("primed".to_owned())
+    );
+    assert_eq!(ASYNC_RENDER_CALLS.load(Ordering::SeqCst), 0);
+}
+
+static IF_CACHED_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(key = "id")]
+fn if_cached(_ctx: Context, id: u64) -> Option<u64> {
+    IF_CACHED_CALLS.fetch_add(1, Ordering::SeqCst);
+    Some(id)
+}
+
+#[test]
+fn if_cached_hit_and_miss() {
+    assert_eq!(if_cached_if_cached(1), None);
+    assert_eq!(if_cached(Context, 1), Some(1));
+    assert_eq!(if_cached_if_cached(1), Some(1));
+    assert_eq!(if_cached_if_cached(2), None);
+    assert_eq!(IF_CACHED_CALLS.load(Ordering::SeqCst), 1);
+}
+
+static ASYNC_IF_CACHED_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(convert = "{ name.to_lowercase() }")]
+async fn async_if_cached(name: String) -> usize {
+    ASYNC_IF_CACHED_CALLS.fetch_add(1, Ordering::SeqCst);
+    name.len()
+}
+
+#[tokio::test]
+async fn async_if_cached_hit_and_miss() {
+    assert_eq!(async_if_cached_if_cached("Abc".to_owned()).await, None);
+    assert_eq!(async_if_cached("abc".to_owned()).await, 3);
+    assert_eq!(async_if_cached_if_cached("ABC".to_owned()).await, Some(3));
+    assert_eq!(ASYNC_IF_CACHED_CALLS.load(Ordering::SeqCst), 1);
+}
+
+#[cached(key = "id")]
+fn contains(_ctx: Context, id: u64) -> u64 {
+    id
+}
+
+#[test]
+fn cache_contains() {
+    assert!(!contains_cache_contains(1));
+    contains(Context, 1);
+    assert!(contains_cache_contains(1));
+    assert!(!contains_cache_contains(2));
+}
+
+#[cached(convert = "{ name.to_lowercase() }")]
+async fn async_contains(name: String) -> usize {
+    name.len()
+}
+
+#[tokio::test]
+async fn async_cache_contains() {
+    assert!(!async_contains_cache_contains("Abc".to_owned()));
+    async_contains("abc".to_owned()).await;
+    assert!(async_contains_cache_contains("ABC".to_owned()));
+}
+
+#[cached(max_weight = 1000, weigher = "|_, v: &String| v.len() as u32")]
+fn sized(len: usize) -> String {
+    "x".repeat(len)
+}
+
+#[test]
+fn entry_count_and_weighted_size() {
+    for len in 1..=10 {
+        sized(len);
+    }
+    assert_eq!(sized_cache_entry_count(), 10);
+    assert_eq!(sized_cache_weighted_size(), 55);
+}
+
+static AGREE_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(key = "tenant, name", convert = "{ (tenant, name.to_lowercase()) }")]
+fn agree(_ctx: Context, tenant: u32, name: String) -> String {
+    AGREE_CALLS.fetch_add(1, Ordering::SeqCst);
+    format!("{}/{}", tenant, name)
+}
+
+#[test]
+fn companions_agree_on_the_key() {
+    agree_cache_insert(1, "Alice".to_owned(), "primed".to_owned());
+    assert!(agree_cache_contains(1, "ALICE".to_owned()));
+    assert_eq!(agree(Context, 1, "alice".to_owned()), "primed");
+    assert_eq!(
+        agree_if_cached(1, "aLiCe".to_owned()),
+        Some("primed".to_owned())
+    );
+    assert_eq!(AGREE_CALLS.load(Ordering::SeqCst), 0);
+
+    agree_cache_invalidate(1, "ALICE".to_owned());
+    assert!(!agree_cache_contains(1, "alice".to_owned()));
+    assert_eq!(agree(Context, 1, "Alice".to_owned()), "1/Alice");
+    assert!(agree_cache_contains(1, "alice".to_owned()));
+    assert!(!agree_cache_contains(2, "alice".to_owned()));
+    assert_eq!(AGREE_CALLS.load(Ordering::SeqCst), 1);
+}
+
+#[cached(convert = "{ name.to_lowercase() }")]
+async fn async_invalidate(name: String) -> usize {
+    name.len()
+}
+
+#[tokio::test]
+async fn async_cache_invalidate() {
+    async_invalidate("abc".to_owned()).await;
+    async_invalidate_cache_invalidate("ABC".to_owned()).await;
+    assert!(!async_invalidate_cache_contains("abc".to_owned()));
+}
+
+#[cached(key = "name", convert = "{ name.to_lowercase() }", dump = true)]
+fn dump(_ctx: Context, name: String) -> usize {
+    name.len()
+}
+
+#[test]
+fn cache_dump() {
+    dump(Context, "Abc".to_owned());
+    dump(Context, "DE".to_owned());
+    dump(Context, "abc".to_owned());
+
+    let mut entries = dump_cache_dump(None);
+    entries.sort();
+    assert_eq!(entries, vec![("abc".to_owned(), 3), ("de".to_owned(), 2)]);
+    assert_eq!(dump_cache_dump(Some(1)).len(), 1);
+}
+
+#[cached(dump = true)]
+async fn async_dump(x: u32) -> Option<u32> {
+    Some(x + 1)
+}
+
+#[tokio::test]
+async fn async_cache_dump() {
+    async_dump(1).await;
+    assert_eq!(async_dump_cache_dump(Some(10)), vec![(1, 2)]);
+}
+
+static WARM_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached]
+fn warm(user: u64, game: u64) -> Result<u64, String> {
+    WARM_CALLS.fetch_add(1, Ordering::SeqCst);
+    Ok(user * game)
+}
+
+#[test]
+fn warm_then_hit() {
+    warm(1, 1).unwrap();
+    let warmed = warm_warm([(1, 1), (1, 2), (2, 2)]);
+    assert_eq!(warmed.computed, 2);
+    assert_eq!(warmed.present, 1);
+    assert_eq!(WARM_CALLS.load(Ordering::SeqCst), 3);
+
+    assert_eq!(warm(1, 2), Ok(2));
+    assert_eq!(warm(2, 2), Ok(4));
+    assert_eq!(WARM_CALLS.load(Ordering::SeqCst), 3);
+}
+
+static ASYNC_WARM_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached]
+async fn async_warm(id: u64) -> u64 {
+    ASYNC_WARM_CALLS.fetch_add(1, Ordering::SeqCst);
+    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    id * 10
+}
+
+#[tokio::test]
+async fn async_warm_then_hit() {
+    let warmed = async_warm_warm(0..100, 16).await;
+    assert_eq!(warmed.computed, 100);
+    assert_eq!(async_warm_warm(0..100, 16).await.present, 100);
+    for id in 0..100 {
+        assert_eq!(async_warm(id).await, id * 10);
+    }
+    assert_eq!(ASYNC_WARM_CALLS.load(Ordering::SeqCst), 100);
+}
+
+#[cached(size = 2)]
+async fn bounded(id: u64) -> u64 {
+    id
+}
+
+#[tokio::test]
+async fn sync_runs_maintenance() {
+    for id in 0..5 {
+        bounded(id).await;
+    }
+    bounded_cache_sync().await;
+    assert_eq!(bounded_cache_entry_count(), 2);
+}
+
+static MANY_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(many = true)]
+async fn many_square(x: u64) -> Result<u64, String> {
+    MANY_CALLS.fetch_add(1, Ordering::SeqCst);
+    tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+    if x == 0 {
+        Err("zero".to_owned())
+    } else {
+        Ok(x * x)
+    }
+}
+
+#[tokio::test]
+async fn many_keeps_order_and_fans_out() {
+    many_square(3).await.unwrap();
+    let started = std::time::Instant::now();
+    let squares = many_square_many([5, 0, 3, 4, 5], 8).await;
+    // the misses are computed concurrently
+    assert!(started.elapsed() < std::time::Duration::from_millis(150));
+    assert_eq!(
+        squares,
+        vec![Ok(25), Err("zero".to_owned()), Ok(9), Ok(16), Ok(25)]
+    );
+    // 3 was cached, the two 5s were coalesced
+    assert_eq!(MANY_CALLS.load(Ordering::SeqCst), 4);
+}
@@ -0,0 +1,51 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use cold_moka::cached;
+
+static NOTIFIED: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(ttl = 1)]
+async fn notify_ops(alert_key: String) {
+    let _ = alert_key;
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    NOTIFIED.fetch_add(1, Ordering::SeqCst);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn runs_once_per_window() {
+    let calls = (0..16).map(|_| tokio::spawn(notify_ops("disk".to_owned())));
+    for call in calls {
+        call.await.unwrap();
+    }
+    assert_eq!(NOTIFIED.load(Ordering::SeqCst), 1);
+
+    notify_ops("disk".to_owned()).await;
+    assert_eq!(NOTIFIED.load(Ordering::SeqCst), 1);
+
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+    notify_ops("disk".to_owned()).await;
+    assert_eq!(NOTIFIED.load(Ordering::SeqCst), 2);
+}
+
+static LOGGED: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(ttl = 1)]
+fn log_once(message: &'static str) {
+    let _ = message;
+    LOGGED.fetch_add(1, Ordering::SeqCst);
+}
+
+#[test]
+fn sync_runs_once_per_window() {
+    std::thread::scope(|scope| {
+        for _ in 0..8 {
+            scope.spawn(|| log_once("full"));
+        }
+    });
+    assert_eq!(LOGGED.load(Ordering::SeqCst), 1);
+
+    std::thread::sleep(Duration::from_millis(1100));
+    log_once("full");
+    assert_eq!(LOGGED.load(Ordering::SeqCst), 2);
+}
@@ -0,0 +1,15 @@
+use cold_moka::diagnostics::{explain, DIAGNOSTICS};
+
+#[test]
+fn explain_known_codes() {
+    assert!(explain("CM0001").unwrap().contains("self"));
+    assert_eq!(explain("CM9999"), None);
+}
+
+#[test]
+fn codes_are_sequential() {
+    for (idx, (code, explanation)) in DIAGNOSTICS.iter().enumerate() {
+        assert_eq!(*code, format!("CM{:04}", idx + 1));
+        assert!(!explanation.is_empty());
+    }
+}
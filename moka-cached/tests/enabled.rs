@@ -0,0 +1,65 @@
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use cold_moka::cached;
+
+static ENABLED: AtomicBool = AtomicBool::new(true);
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(enabled = "ENABLED")]
+fn square(x: u64) -> u64 {
+    CALLS.fetch_add(1, Ordering::SeqCst);
+    x * x
+}
+
+#[test]
+fn disabled_cache_is_skipped() {
+    square(2);
+    square(2);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+
+    ENABLED.store(false, Ordering::SeqCst);
+    assert_eq!(square(2), 4);
+    assert_eq!(square(3), 9);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 3);
+    assert!(!square_cache_contains(3));
+
+    ENABLED.store(true, Ordering::SeqCst);
+    square(2);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 3);
+}
+
+static ASYNC_ENABLED: AtomicBool = AtomicBool::new(false);
+
+#[cached(enabled = "ASYNC_ENABLED")]
+async fn parse(s: String) -> Result<u64, String> {
+    s.parse().map_err(|_| format!("{} is not a number", s))
+}
+
+#[tokio::test]
+async fn async_disabled_cache_is_skipped() {
+    assert_eq!(parse("1".to_owned()).await, Ok(1));
+    assert_eq!(parse_if_cached("1".to_owned()).await, None);
+
+    ASYNC_ENABLED.store(true, Ordering::SeqCst);
+    assert_eq!(parse("1".to_owned()).await, Ok(1));
+    assert_eq!(parse_if_cached("1".to_owned()).await, Some(1));
+}
+
+static STATS_ENABLED: AtomicBool = AtomicBool::new(true);
+
+#[cached(enabled = "STATS_ENABLED", stats = true)]
+fn cube(x: u64) -> u64 {
+    x * x * x
+}
+
+#[test]
+fn disabled_calls_are_not_counted() {
+    cube(2);
+    cube(2);
+    STATS_ENABLED.store(false, Ordering::SeqCst);
+    cube(2);
+    cube(3);
+    STATS_ENABLED.store(true, Ordering::SeqCst);
+    let stats = cube_cache_stats();
+    assert_eq!((stats.hits, stats.misses), (1, 1));
+}
@@ -0,0 +1,68 @@
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::Duration;
+
+use cold_moka::{cached, StaleValues};
+
+static FAILING: AtomicBool = AtomicBool::new(false);
+static VERSION: AtomicU64 = AtomicU64::new(0);
+
+#[cached(ttl = 1, result_fallback = true)]
+fn config(name: &'static str) -> Result<String, String> {
+    if FAILING.load(Ordering::SeqCst) {
+        return Err(format!("{} is unreachable", name));
+    }
+    Ok(format!(
+        "{} v{}",
+        name,
+        VERSION.fetch_add(1, Ordering::SeqCst)
+    ))
+}
+
+#[test]
+fn serves_the_last_good_value() {
+    assert_eq!(config("db"), Ok("db v0".to_owned()));
+    FAILING.store(true, Ordering::SeqCst);
+    std::thread::sleep(Duration::from_millis(1100));
+    assert_eq!(config("db"), Ok("db v0".to_owned()));
+    assert_eq!(config("cdn"), Err("cdn is unreachable".to_owned()));
+
+    config_cache_invalidate("db");
+    assert_eq!(config("db"), Err("db is unreachable".to_owned()));
+
+    FAILING.store(false, Ordering::SeqCst);
+    assert_eq!(config("db"), Ok("db v1".to_owned()));
+}
+
+static ASYNC_FAILING: AtomicBool = AtomicBool::new(false);
+
+#[cached(ttl = 1, result_fallback = true)]
+async fn async_config(version: u64) -> Result<u64, String> {
+    if ASYNC_FAILING.load(Ordering::SeqCst) {
+        return Err("unreachable".to_owned());
+    }
+    Ok(version)
+}
+
+#[tokio::test]
+async fn async_serves_the_last_good_value() {
+    assert_eq!(async_config(1).await, Ok(1));
+    ASYNC_FAILING.store(true, Ordering::SeqCst);
+    tokio::time::sleep(Duration::from_millis(1100)).await;
+    assert_eq!(async_config(1).await, Ok(1));
+    assert_eq!(async_config(2).await, Err("unreachable".to_owned()));
+
+    async_config_cache_clear();
+    assert_eq!(async_config(1).await, Err("unreachable".to_owned()));
+}
+
+#[test]
+fn full_store_replaces_the_least_recently_used_value() {
+    let stale = StaleValues::new(2);
+    stale.keep("a", 1);
+    stale.keep("b", 2);
+    assert_eq!(stale.get(&"a"), Some(1));
+    stale.keep("c", 3);
+    assert_eq!(stale.get(&"b"), None);
+    assert_eq!(stale.get(&"a"), Some(1));
+    assert_eq!(stale.get(&"c"), Some(3));
+}
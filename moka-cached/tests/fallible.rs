@@ -0,0 +1,440 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use cold_moka::cached;
+
+static STRING_ERROR_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached]
+fn string_error(x: u32) -> Result<u32, String> {
+    STRING_ERROR_CALLS.fetch_add(1, Ordering::SeqCst);
+    if x == 0 {
+        Err("zero".to_owned())
+    } else {
+        Ok(x)
+    }
+}
+
+#[test]
+fn errors_are_returned_and_not_cached() {
+    assert_eq!(string_error(0), Err("zero".to_owned()));
+    assert_eq!(string_error(0), Err("zero".to_owned()));
+    assert_eq!(STRING_ERROR_CALLS.load(Ordering::SeqCst), 2);
+
+    assert_eq!(string_error(1), Ok(1));
+    assert_eq!(string_error(1), Ok(1));
+    assert_eq!(STRING_ERROR_CALLS.load(Ordering::SeqCst), 3);
+}
+
+type ApiResult<T> = Result<T, String>;
+
+static ALIAS_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(result = true)]
+fn alias(x: u32) -> ApiResult<u32> {
+    ALIAS_CALLS.fetch_add(1, Ordering::SeqCst);
+    if x == 0 {
+        Err("zero".to_owned())
+    } else {
+        Ok(x)
+    }
+}
+
+#[test]
+fn result_alias() {
+    assert_eq!(alias(0), Err("zero".to_owned()));
+    assert_eq!(alias(0), Err("zero".to_owned()));
+    assert_eq!(ALIAS_CALLS.load(Ordering::SeqCst), 2);
+
+    assert_eq!(alias(1), Ok(1));
+    assert_eq!(alias(1), Ok(1));
+    assert_eq!(ALIAS_CALLS.load(Ordering::SeqCst), 3);
+    // only the `Ok` value is stored
+    assert_eq!(alias_if_cached(1), Some(1));
+}
+
+type Lookup<T> = Option<T>;
+
+#[cached(option = true)]
+fn option_alias(x: u32) -> Lookup<u32> {
+    (x != 0).then_some(x)
+}
+
+#[test]
+fn option_alias_caches_some() {
+    assert_eq!(option_alias(0), None);
+    assert_eq!(option_alias_if_cached(0), None);
+    assert_eq!(option_alias(1), Some(1));
+    assert_eq!(option_alias_if_cached(1), Some(1));
+}
+
+static WHOLE_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(result = false)]
+fn whole_result(x: u32) -> Result<u32, String> {
+    WHOLE_CALLS.fetch_add(1, Ordering::SeqCst);
+    if x == 0 {
+        Err("zero".to_owned())
+    } else {
+        Ok(x)
+    }
+}
+
+#[test]
+fn result_cached_as_a_value() {
+    assert_eq!(whole_result(0), Err("zero".to_owned()));
+    assert_eq!(whole_result(0), Err("zero".to_owned()));
+    assert_eq!(WHOLE_CALLS.load(Ordering::SeqCst), 1);
+    assert_eq!(whole_result_if_cached(0), Some(Err("zero".to_owned())));
+}
+
+#[derive(Debug)]
+struct NotFound(u32);
+
+impl std::fmt::Display for NotFound {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} not found", self.0)
+    }
+}
+
+impl std::error::Error for NotFound {}
+
+#[cached]
+fn boxed_error(x: u32) -> Result<u32, Box<dyn std::error::Error + Send + Sync>> {
+    if x == 0 {
+        Err(Box::new(NotFound(x)))
+    } else {
+        Ok(x)
+    }
+}
+
+#[test]
+fn boxed_errors() {
+    assert_eq!(boxed_error(1).unwrap(), 1);
+
+    let error = boxed_error(0).unwrap_err();
+    assert_eq!(error.to_string(), "0 not found");
+    let shared = error.downcast_ref::<cold_moka::SharedError>().unwrap();
+    assert!(shared.get_ref().is::<NotFound>());
+}
+
+#[cached]
+async fn async_boxed_error(x: u32) -> Result<u32, Box<dyn std::error::Error + Send + Sync>> {
+    if x == 0 {
+        Err("zero".into())
+    } else {
+        Ok(x)
+    }
+}
+
+#[tokio::test]
+async fn async_boxed_errors() {
+    assert_eq!(async_boxed_error(1).await.unwrap(), 1);
+    assert_eq!(async_boxed_error(0).await.unwrap_err().to_string(), "zero");
+}
+
+#[cached]
+fn anyhow_error(x: u32) -> anyhow::Result<u32> {
+    if x == 0 {
+        Err(NotFound(x).into())
+    } else {
+        Ok(x)
+    }
+}
+
+#[test]
+fn anyhow_errors() {
+    assert_eq!(anyhow_error(1).unwrap(), 1);
+
+    let error = anyhow_error(0).unwrap_err();
+    assert_eq!(error.to_string(), "0 not found");
+    let shared = error.downcast_ref::<cold_moka::SharedError>().unwrap();
+    assert!(shared.get_ref().is::<NotFound>());
+}
+
+#[cached]
+async fn async_anyhow_error(x: u32) -> Result<u32, anyhow::Error> {
+    if x == 0 {
+        Err(anyhow::anyhow!("zero"))
+    } else {
+        Ok(x)
+    }
+}
+
+#[tokio::test]
+async fn async_anyhow_errors() {
+    assert_eq!(async_anyhow_error(1).await.unwrap(), 1);
+    assert_eq!(async_anyhow_error(0).await.unwrap_err().to_string(), "zero");
+}
+
+#[cached]
+fn eyre_error(x: u32) -> eyre::Result<u32> {
+    if x == 0 {
+        Err(NotFound(x).into())
+    } else {
+        Ok(x)
+    }
+}
+
+#[test]
+fn eyre_errors() {
+    assert_eq!(eyre_error(1).unwrap(), 1);
+
+    let error = eyre_error(0).unwrap_err();
+    assert_eq!(error.to_string(), "0 not found");
+    let shared = error.downcast_ref::<cold_moka::SharedError>().unwrap();
+    assert!(shared.get_ref().is::<NotFound>());
+}
+
+#[cached]
+async fn async_eyre_error(x: u32) -> Result<u32, eyre::Report> {
+    if x == 0 {
+        Err(eyre::eyre!("zero"))
+    } else {
+        Ok(x)
+    }
+}
+
+#[tokio::test]
+async fn async_eyre_errors() {
+    assert_eq!(async_eyre_error(1).await.unwrap(), 1);
+    assert_eq!(async_eyre_error(0).await.unwrap_err().to_string(), "zero");
+}
+
+// neither `Clone` nor shareable as it is
+#[derive(Debug, PartialEq)]
+struct Fatal(String);
+
+#[cached(
+    error = "arc",
+    from_arc = "|e: std::sync::Arc<Fatal>| Fatal(format!(\"shared {}\", e.0))"
+)]
+fn fatal(x: u32) -> Result<u32, Fatal> {
+    if x == 0 {
+        Err(Fatal("zero".to_owned()))
+    } else {
+        Ok(x)
+    }
+}
+
+#[test]
+fn errors_converted_from_arc() {
+    assert_eq!(fatal(1), Ok(1));
+    assert_eq!(fatal(0), Err(Fatal("shared zero".to_owned())));
+}
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+#[cached(error = "shared")]
+fn aliased_boxed_error(x: u32) -> Result<u32, BoxError> {
+    if x == 0 {
+        Err(Box::new(NotFound(x)))
+    } else {
+        Ok(x)
+    }
+}
+
+#[test]
+fn shared_aliased_errors() {
+    let error = aliased_boxed_error(0).unwrap_err();
+    let shared = error.downcast_ref::<cold_moka::SharedError>().unwrap();
+    assert!(shared.get_ref().is::<NotFound>());
+}
+
+#[derive(Debug, PartialEq)]
+struct Gone(u32);
+
+impl From<std::sync::Arc<Gone>> for Gone {
+    fn from(error: std::sync::Arc<Gone>) -> Self {
+        Gone(error.0 + 100)
+    }
+}
+
+#[cached]
+fn gone(x: u32) -> Result<u32, Gone> {
+    Err(Gone(x))
+}
+
+#[test]
+fn errors_converted_with_from_arc_impl() {
+    assert_eq!(gone(1), Err(Gone(101)));
+}
+
+static CACHED_ERROR_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(cache_errors = true)]
+fn cached_error(x: u32) -> Result<u32, String> {
+    CACHED_ERROR_CALLS.fetch_add(1, Ordering::SeqCst);
+    if x == 0 {
+        Err("zero".to_owned())
+    } else {
+        Ok(x)
+    }
+}
+
+#[test]
+fn errors_cached_on_request() {
+    assert_eq!(cached_error(0), Err("zero".to_owned()));
+    assert_eq!(cached_error(0), Err("zero".to_owned()));
+    assert_eq!(cached_error(1), Ok(1));
+    assert_eq!(cached_error(1), Ok(1));
+    assert_eq!(CACHED_ERROR_CALLS.load(Ordering::SeqCst), 2);
+}
+
+static ASYNC_CACHED_ERROR_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(cache_errors = true, negative_ttl = 1)]
+async fn async_cached_error(x: u32) -> Result<u32, String> {
+    ASYNC_CACHED_ERROR_CALLS.fetch_add(1, Ordering::SeqCst);
+    if x == 0 {
+        Err("zero".to_owned())
+    } else {
+        Ok(x)
+    }
+}
+
+#[tokio::test]
+async fn async_errors_expire_after_negative_ttl() {
+    assert_eq!(async_cached_error(0).await, Err("zero".to_owned()));
+    assert_eq!(async_cached_error(1).await, Ok(1));
+    assert_eq!(async_cached_error(0).await, Err("zero".to_owned()));
+    assert_eq!(ASYNC_CACHED_ERROR_CALLS.load(Ordering::SeqCst), 2);
+
+    tokio::time::sleep(std::time::Duration::from_millis(1100)).await;
+    assert_eq!(async_cached_error(0).await, Err("zero".to_owned()));
+    assert_eq!(async_cached_error(1).await, Ok(1));
+    // only the error expired
+    assert_eq!(ASYNC_CACHED_ERROR_CALLS.load(Ordering::SeqCst), 3);
+}
+
+static CACHED_NONE_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(cache_none = true)]
+fn cached_none(x: u32) -> Option<u32> {
+    CACHED_NONE_CALLS.fetch_add(1, Ordering::SeqCst);
+    (x != 0).then_some(x)
+}
+
+#[test]
+fn none_cached_on_request() {
+    assert_eq!(cached_none(0), None);
+    assert_eq!(cached_none(0), None);
+    assert_eq!(cached_none(1), Some(1));
+    assert_eq!(cached_none(1), Some(1));
+    assert_eq!(CACHED_NONE_CALLS.load(Ordering::SeqCst), 2);
+    assert_eq!(cached_none_if_cached(0), Some(None));
+}
+
+static ASYNC_CACHED_NONE_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(cache_none = true)]
+async fn async_cached_none(x: u32) -> Option<u32> {
+    ASYNC_CACHED_NONE_CALLS.fetch_add(1, Ordering::SeqCst);
+    (x != 0).then_some(x)
+}
+
+#[tokio::test]
+async fn async_none_cached_on_request() {
+    assert_eq!(async_cached_none(0).await, None);
+    assert_eq!(async_cached_none(0).await, None);
+    assert_eq!(ASYNC_CACHED_NONE_CALLS.load(Ordering::SeqCst), 1);
+    assert_eq!(async_cached_none_if_cached(0).await, Some(None));
+}
+
+static MAYBE_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached]
+fn maybe(x: i32) -> Result<Option<u32>, String> {
+    MAYBE_CALLS.fetch_add(1, Ordering::SeqCst);
+    match x {
+        x if x < 0 => Err("negative".to_owned()),
+        0 => Ok(None),
+        x => Ok(Some(x as u32)),
+    }
+}
+
+#[test]
+fn result_option_caches_some() {
+    assert_eq!(maybe(0), Ok(None));
+    assert_eq!(maybe(0), Ok(None));
+    assert_eq!(maybe(-1), Err("negative".to_owned()));
+    assert_eq!(maybe(-1), Err("negative".to_owned()));
+    assert_eq!(maybe(1), Ok(Some(1)));
+    assert_eq!(maybe(1), Ok(Some(1)));
+    assert_eq!(MAYBE_CALLS.load(Ordering::SeqCst), 5);
+    assert_eq!(maybe_if_cached(0), None);
+    assert_eq!(maybe_if_cached(1), Some(1));
+}
+
+static MAYBE_NONE_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(cache_none = true)]
+fn maybe_none(x: i32) -> Result<Option<u32>, String> {
+    MAYBE_NONE_CALLS.fetch_add(1, Ordering::SeqCst);
+    match x {
+        x if x < 0 => Err("negative".to_owned()),
+        0 => Ok(None),
+        x => Ok(Some(x as u32)),
+    }
+}
+
+#[test]
+fn result_option_caches_none_on_request() {
+    assert_eq!(maybe_none(0), Ok(None));
+    assert_eq!(maybe_none(0), Ok(None));
+    assert_eq!(maybe_none(-1), Err("negative".to_owned()));
+    assert_eq!(maybe_none(-1), Err("negative".to_owned()));
+    assert_eq!(MAYBE_NONE_CALLS.load(Ordering::SeqCst), 3);
+    assert_eq!(maybe_none_if_cached(0), Some(None));
+}
+
+static ASYNC_MAYBE_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached]
+async fn async_maybe(x: i32) -> Result<Option<u32>, String> {
+    ASYNC_MAYBE_CALLS.fetch_add(1, Ordering::SeqCst);
+    match x {
+        x if x < 0 => Err("negative".to_owned()),
+        0 => Ok(None),
+        x => Ok(Some(x as u32)),
+    }
+}
+
+#[tokio::test]
+async fn async_result_option_caches_some() {
+    assert_eq!(async_maybe(0).await, Ok(None));
+    assert_eq!(async_maybe(0).await, Ok(None));
+    assert_eq!(async_maybe(-1).await, Err("negative".to_owned()));
+    assert_eq!(async_maybe(1).await, Ok(Some(1)));
+    assert_eq!(async_maybe(1).await, Ok(Some(1)));
+    assert_eq!(ASYNC_MAYBE_CALLS.load(Ordering::SeqCst), 4);
+    assert_eq!(async_maybe_if_cached(1).await, Some(1));
+}
+
+type PortLookup = Result<u16, String>;
+
+#[cached(result = true, value_type = "u16")]
+fn port(service: &'static str) -> PortLookup {
+    match service {
+        "http" => Ok(80),
+        _ => Err(format!("unknown service {}", service)),
+    }
+}
+
+type MaybeName = Option<String>;
+
+#[cached(option = true, value_type = "String")]
+async fn name(id: u64) -> MaybeName {
+    (id != 0).then(|| format!("user {}", id))
+}
+
+#[tokio::test]
+async fn value_type_names_the_cached_value() {
+    assert_eq!(port("http"), Ok(80));
+    assert_eq!(port_if_cached("http"), Some(80));
+    assert!(port("gopher").is_err());
+    assert_eq!(port_if_cached("gopher"), None);
+
+    assert_eq!(name(1).await, Some("user 1".to_owned()));
+    assert_eq!(name_if_cached(1).await, Some("user 1".to_owned()));
+    assert_eq!(name(0).await, None);
+}
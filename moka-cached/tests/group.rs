@@ -0,0 +1,86 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use cold_moka::cached;
+use cold_moka::moka::{future, sync};
+use cold_moka::once_cell::sync::Lazy;
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+enum UserKey {
+    Id(u64),
+    Email(String),
+}
+
+static USERS: Lazy<sync::Cache<UserKey, String>> = Lazy::new(|| sync::Cache::new(100));
+
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(group = "USERS", convert = "UserKey::Id(id)", key_type = "UserKey")]
+fn user_by_id(id: u64) -> String {
+    CALLS.fetch_add(1, Ordering::SeqCst);
+    format!("user {}", id)
+}
+
+#[cached(
+    group = "USERS",
+    convert = "UserKey::Email(email)",
+    key_type = "UserKey"
+)]
+fn user_by_email(email: String) -> String {
+    CALLS.fetch_add(1, Ordering::SeqCst);
+    format!("user {}", email)
+}
+
+#[test]
+fn members_share_the_cache() {
+    assert_eq!(user_by_id(1), "user 1");
+    assert_eq!(
+        user_by_email("a@example.com".to_owned()),
+        "user a@example.com"
+    );
+    assert_eq!(user_by_id(1), "user 1");
+    assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+
+    assert!(USERS.contains_key(&UserKey::Id(1)));
+    assert!(user_by_email_cache_contains("a@example.com".to_owned()));
+
+    // a member writing an entry is seen by the others
+    USERS.insert(
+        UserKey::Email("b@example.com".to_owned()),
+        "cached".to_owned(),
+    );
+    assert_eq!(user_by_email("b@example.com".to_owned()), "cached");
+
+    user_by_id_cache_clear();
+    assert!(!user_by_email_cache_contains("a@example.com".to_owned()));
+}
+
+static ASYNC_USERS: Lazy<future::Cache<UserKey, String>> = Lazy::new(|| future::Cache::new(100));
+
+#[cached(
+    group = "ASYNC_USERS",
+    convert = "UserKey::Id(id)",
+    key_type = "UserKey"
+)]
+async fn async_user_by_id(id: u64) -> String {
+    format!("user {}", id)
+}
+
+#[cached(
+    group = "ASYNC_USERS",
+    convert = "UserKey::Email(email)",
+    key_type = "UserKey"
+)]
+async fn async_user_by_email(email: String) -> String {
+    format!("user {}", email)
+}
+
+#[tokio::test]
+async fn async_members_share_the_cache() {
+    assert_eq!(async_user_by_id(1).await, "user 1");
+    assert_eq!(
+        async_user_by_email("a@example.com".to_owned()).await,
+        "user a@example.com"
+    );
+    assert!(ASYNC_USERS.contains_key(&UserKey::Id(1)));
+    assert!(ASYNC_USERS.contains_key(&UserKey::Email("a@example.com".to_owned())));
+}
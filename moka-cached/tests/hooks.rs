@@ -0,0 +1,49 @@
+use std::sync::Mutex;
+
+use cold_moka::cached;
+
+static LOOKUPS: Mutex<Vec<(&str, u64)>> = Mutex::new(Vec::new());
+
+fn hit(key: &u64) {
+    LOOKUPS.lock().unwrap().push(("hit", *key));
+}
+
+fn miss(key: &u64) {
+    LOOKUPS.lock().unwrap().push(("miss", *key));
+}
+
+#[cached(on_hit = "hit", on_miss = "miss")]
+fn square(x: u64) -> u64 {
+    x * x
+}
+
+#[test]
+fn called_with_the_key() {
+    square(2);
+    square(2);
+    square(3);
+    assert_eq!(
+        *LOOKUPS.lock().unwrap(),
+        vec![("miss", 2), ("hit", 2), ("miss", 3)]
+    );
+}
+
+static ASYNC_HITS: Mutex<Vec<(u64, u64)>> = Mutex::new(Vec::new());
+
+fn async_hit(key: &(u64, u64)) {
+    ASYNC_HITS.lock().unwrap().push(*key);
+}
+
+#[cached(on_hit = "async_hit")]
+async fn add(a: u64, b: u64) -> Result<u64, String> {
+    a.checked_add(b).ok_or_else(|| "overflow".to_owned())
+}
+
+#[tokio::test]
+async fn async_called_on_hits_only() {
+    add(1, 2).await.unwrap();
+    add(1, 2).await.unwrap();
+    add(u64::MAX, 1).await.unwrap_err();
+    add(u64::MAX, 1).await.unwrap_err();
+    assert_eq!(*ASYNC_HITS.lock().unwrap(), vec![(1, 2)]);
+}
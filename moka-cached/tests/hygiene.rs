@@ -0,0 +1,76 @@
+use cold_moka::cached;
+
+#[cached]
+fn named_key(key: u32, result: String) -> String {
+    format!("{}{}", key, result)
+}
+
+#[cached(key = "key")]
+fn fallible_key(key: u32, cache: u8) -> Result<u32, String> {
+    Ok(key + cache as u32)
+}
+
+#[test]
+fn inputs_named_like_locals() {
+    assert_eq!(named_key(1, "a".to_owned()), "1a");
+    assert!(named_key_cache_contains(1, "a".to_owned()));
+    assert_eq!(fallible_key(1, 2), Ok(3));
+}
+
+#[cached(wrap_return = true)]
+fn wrapped(key: u32, was_cached: u32) -> cold_moka::Return<u32> {
+    cold_moka::Return::new(key + was_cached)
+}
+
+#[cached(key_borrow = true, key = "key")]
+fn borrowed(key: String, key_ref: u32) -> String {
+    format!("{}{}", key, key_ref)
+}
+
+#[cached(cache_if = "|v: &u32| *v > 0")]
+fn kept(key: u32, value: u32, fresh: bool, entry: u8) -> Option<u32> {
+    Some(key + value + fresh as u32 + entry as u32)
+}
+
+#[cached(on_hit = "drop_key", on_miss = "drop_key")]
+async fn observed(key: u32, fresh: bool, entry: u8, result: u8) -> u32 {
+    key + fresh as u32 + entry as u32 + result as u32
+}
+
+fn drop_key(_: &(u32, bool, u8, u8)) {}
+
+#[test]
+fn companions_with_inputs_named_like_locals() {
+    assert_eq!(
+        named_key_warm([(1, "a".to_owned()), (2, "b".to_owned())]).computed,
+        2
+    );
+    assert_eq!(wrapped(1, 2).value, 3);
+    assert_eq!(borrowed("a".to_owned(), 1), "a1");
+    assert_eq!(kept(1, 2, true, 0), Some(4));
+}
+
+#[tokio::test]
+async fn async_inputs_named_like_locals() {
+    assert_eq!(observed(1, true, 0, 0).await, 2);
+    assert_eq!(
+        observed_warm([(1, true, 0, 0), (2, true, 0, 0)], 2)
+            .await
+            .computed,
+        1
+    );
+}
+
+fn doubled_inner(x: u32) -> u32 {
+    x * 2
+}
+
+#[cached]
+fn doubled(x: u32) -> u32 {
+    doubled_inner(x) + 1
+}
+
+#[test]
+fn functions_named_like_the_body_are_called() {
+    assert_eq!(doubled(2), 5);
+}
@@ -0,0 +1,101 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use cold_moka::{cache_invalidate, cache_update, cached};
+
+static LOADS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached]
+fn get_user(tenant: String, id: u64) -> String {
+    LOADS.fetch_add(1, Ordering::SeqCst);
+    format!("{}/{}", tenant, id)
+}
+
+#[cache_invalidate(target = "get_user", key = "tenant, id")]
+fn update_user(id: u64, tenant: String, name: String) -> Result<(), String> {
+    if name.is_empty() {
+        return Err(format!("empty name for {}/{}", tenant, id));
+    }
+    Ok(())
+}
+
+#[test]
+fn invalidates_the_target_entry() {
+    get_user("acme".to_owned(), 1);
+    get_user("acme".to_owned(), 2);
+    assert_eq!(LOADS.load(Ordering::SeqCst), 2);
+
+    assert_eq!(update_user(1, "acme".to_owned(), "new".to_owned()), Ok(()));
+    assert!(!get_user_cache_contains("acme".to_owned(), 1));
+    assert!(get_user_cache_contains("acme".to_owned(), 2));
+
+    // failures invalidate too
+    assert!(update_user(2, "acme".to_owned(), String::new()).is_err());
+    assert!(!get_user_cache_contains("acme".to_owned(), 2));
+}
+
+mod repo {
+    use cold_moka::cached;
+
+    #[cached]
+    pub async fn balance(account: u64) -> u64 {
+        account * 100
+    }
+}
+
+#[cache_invalidate(target = "repo::balance")]
+async fn deposit(account: u64) -> u64 {
+    account
+}
+
+#[tokio::test]
+async fn async_target_in_another_module() {
+    repo::balance(7).await;
+    assert!(repo::balance_cache_contains(7));
+    deposit(7).await;
+    assert!(!repo::balance_cache_contains(7));
+}
+
+static PROFILE_LOADS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached]
+fn profile(id: u64) -> String {
+    PROFILE_LOADS.fetch_add(1, Ordering::SeqCst);
+    format!("profile {}", id)
+}
+
+#[cache_update(target = "profile", key = "id")]
+fn save_profile(id: u64, name: String) -> Result<String, String> {
+    if name.is_empty() {
+        return Err(format!("empty name for {}", id));
+    }
+    Ok(format!("profile {} {}", id, name))
+}
+
+#[test]
+fn update_writes_through() {
+    assert_eq!(
+        save_profile(1, "ann".to_owned()),
+        Ok("profile 1 ann".to_owned())
+    );
+    assert_eq!(profile(1), "profile 1 ann");
+    assert_eq!(PROFILE_LOADS.load(Ordering::SeqCst), 0);
+
+    // errors leave the cache alone
+    assert!(save_profile(1, String::new()).is_err());
+    assert_eq!(profile(1), "profile 1 ann");
+    assert!(save_profile(2, String::new()).is_err());
+    assert!(!profile_cache_contains(2));
+}
+
+#[cache_update(target = "repo::balance")]
+async fn set_balance(account: u64) -> Option<u64> {
+    (account != 0).then_some(account * 1000)
+}
+
+#[tokio::test]
+async fn async_update() {
+    assert_eq!(set_balance(3).await, Some(3000));
+    assert_eq!(repo::balance(3).await, 3000);
+    assert_eq!(set_balance(0).await, None);
+    assert!(!repo::balance_cache_contains(0));
+}
@@ -0,0 +1,43 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use cold_moka::cached;
+
+static COMPUTED: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(invalidation = true)]
+fn report(tenant: u64, id: u64) -> String {
+    COMPUTED.fetch_add(1, Ordering::SeqCst);
+    format!("report {} of {}", id, tenant)
+}
+
+#[test]
+fn only_matching_entries_recompute() {
+    for tenant in 1..=3 {
+        for id in 1..=2 {
+            report(tenant, id);
+        }
+    }
+    assert_eq!(COMPUTED.load(Ordering::SeqCst), 6);
+
+    report_cache_invalidate_if(|(tenant, _), _| *tenant == 2).unwrap();
+    for tenant in 1..=3 {
+        for id in 1..=2 {
+            report(tenant, id);
+        }
+    }
+    assert_eq!(COMPUTED.load(Ordering::SeqCst), 8);
+}
+
+#[cached(invalidation = true)]
+async fn scores(team: u64) -> Vec<u64> {
+    vec![team; 3]
+}
+
+#[tokio::test]
+async fn predicate_sees_values() {
+    scores(1).await;
+    scores(2).await;
+    scores_cache_invalidate_if(|_, scores| scores.contains(&2)).unwrap();
+    assert!(scores_cache_contains(1));
+    assert!(!scores_cache_contains(2));
+}
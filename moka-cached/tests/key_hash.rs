@@ -0,0 +1,42 @@
+use std::hash::{Hash, Hasher};
+
+use cold_moka::{cached, KeyHasher};
+
+fn hash(value: impl Hash) -> u64 {
+    let mut hasher = KeyHasher::default();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn hashes_are_pinned() {
+    assert_eq!(KeyHasher::default().finish(), 0xd1fb_a762_150c_532c);
+    assert_eq!(hash("https://example.com/a"), 0xe402_a390_12ae_33cd);
+
+    let mut hasher = KeyHasher::default();
+    hasher.write(b"0123456789abcdef0123");
+    assert_eq!(hasher.finish(), 0x560e_d536_0a9a_319a);
+}
+
+#[test]
+fn split_writes_hash_alike() {
+    let bytes = b"0123456789abcdef0123";
+    for split in 0..bytes.len() {
+        let mut hasher = KeyHasher::default();
+        hasher.write(&bytes[..split]);
+        hasher.write(&bytes[split..]);
+        assert_eq!(hasher.finish(), 0x560e_d536_0a9a_319a, "split at {}", split);
+    }
+}
+
+#[cached(key_hash = true, dump = true)]
+fn url_len(url: String) -> usize {
+    url.len()
+}
+
+#[test]
+fn key_is_the_hash_of_the_inputs() {
+    url_len("https://example.com/a".to_owned());
+    let entries: Vec<(u64, usize)> = url_len_cache_dump(None);
+    assert_eq!(entries, vec![(hash("https://example.com/a"), 21)]);
+}
@@ -0,0 +1,412 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use cold_moka::cached;
+
+pub struct Context;
+
+#[derive(Clone, Hash, PartialEq, Eq)]
+pub struct CompositeId(String);
+
+impl CompositeId {
+    fn from_parts(tenant: &str, kind: &u8, id: &u64) -> Self {
+        CompositeId(format!("{}/{}/{}", tenant.to_lowercase(), kind, id))
+    }
+}
+
+static KEY_FN_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(
+    key = "tenant, kind, id",
+    key_fn = "CompositeId::from_parts",
+    key_type = "CompositeId"
+)]
+fn composite(_ctx: Context, tenant: String, kind: u8, id: u64) -> String {
+    KEY_FN_CALLS.fetch_add(1, Ordering::SeqCst);
+    format!("{}:{}:{}", tenant, kind, id)
+}
+
+#[test]
+fn key_fn() {
+    assert_eq!(composite(Context, "Acme".to_owned(), 1, 7), "Acme:1:7");
+    // same composite id, served from the cache
+    assert_eq!(composite(Context, "ACME".to_owned(), 1, 7), "Acme:1:7");
+    assert_eq!(KEY_FN_CALLS.load(Ordering::SeqCst), 1);
+
+    assert_eq!(composite(Context, "acme".to_owned(), 2, 7), "acme:2:7");
+    assert_eq!(KEY_FN_CALLS.load(Ordering::SeqCst), 2);
+}
+
+static ASYNC_KEY_FN_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(
+    key = "tenant, kind, id",
+    key_fn = "CompositeId::from_parts",
+    key_type = "CompositeId"
+)]
+async fn async_composite(_ctx: Context, tenant: String, kind: u8, id: u64) -> String {
+    ASYNC_KEY_FN_CALLS.fetch_add(1, Ordering::SeqCst);
+    format!("{}:{}:{}", tenant, kind, id)
+}
+
+#[tokio::test]
+async fn async_key_fn() {
+    assert_eq!(
+        async_composite(Context, "Acme".to_owned(), 1, 7).await,
+        "Acme:1:7"
+    );
+    assert_eq!(
+        async_composite(Context, "ACME".to_owned(), 1, 7).await,
+        "Acme:1:7"
+    );
+    assert_eq!(ASYNC_KEY_FN_CALLS.load(Ordering::SeqCst), 1);
+    assert!(async_composite_cache_contains("acme".to_owned(), 1, 7));
+}
+
+#[cached(key = "id")]
+fn excluded_impl_trait(id: u64, log: impl Fn(&str)) -> String {
+    log("computing");
+    format!("item {}", id)
+}
+
+#[test]
+fn impl_trait_left_out_of_the_key() {
+    let computed = std::cell::Cell::new(0);
+    let log = |_: &str| computed.set(computed.get() + 1);
+    assert_eq!(excluded_impl_trait(1, log), "item 1");
+    assert_eq!(excluded_impl_trait(1, log), "item 1");
+    assert_eq!(computed.get(), 1);
+}
+
+fn lowercase(name: &impl AsRef<str>) -> String {
+    name.as_ref().to_lowercase()
+}
+
+#[cached(key_fn = "lowercase", key_type = "String")]
+fn impl_trait_key_fn(name: impl AsRef<str>) -> usize {
+    name.as_ref().len()
+}
+
+#[test]
+fn impl_trait_through_key_fn() {
+    assert_eq!(impl_trait_key_fn("Abc"), 3);
+    assert!(impl_trait_key_fn_cache_contains(String::from("ABC")));
+}
+
+#[cached(key = "c, a, b", dump = true)]
+fn reordered(a: u8, _skipped: u64, b: String, c: bool) -> String {
+    format!("{}{}{}", a, b, c)
+}
+
+#[test]
+fn key_follows_attribute_order() {
+    reordered(1, 0, "b".to_owned(), true);
+    let entries: Vec<((bool, u8, String), String)> = reordered_cache_dump(None);
+    assert_eq!(
+        entries,
+        vec![((true, 1, "b".to_owned()), "1btrue".to_owned())]
+    );
+    assert!(reordered_cache_contains(true, 1, "b".to_owned()));
+}
+
+#[cached(dump = true)]
+fn in_parameter_order(b: u8, a: bool) -> String {
+    format!("{}{}", b, a)
+}
+
+#[test]
+fn key_without_names_follows_parameter_order() {
+    in_parameter_order(1, true);
+    let entries: Vec<((u8, bool), String)> = in_parameter_order_cache_dump(None);
+    assert_eq!(entries, vec![((1, true), "1true".to_owned())]);
+}
+
+#[cached(ignore = "_ctx, verbose")]
+fn ignored(_ctx: Context, id: u64, verbose: bool, name: String) -> String {
+    format!("{} {} {}", id, verbose, name)
+}
+
+#[test]
+fn ignore_leaves_out_arguments() {
+    assert_eq!(ignored(Context, 1, false, "a".to_owned()), "1 false a");
+    // the ignored arguments are still passed to the function
+    assert_eq!(ignored(Context, 1, true, "a".to_owned()), "1 false a");
+    assert_eq!(ignored(Context, 2, true, "a".to_owned()), "2 true a");
+    assert!(ignored_cache_contains(1, "a".to_owned()));
+}
+
+#[cached(ignore = "_db, _pool")]
+fn ignored_contexts(_db: Context, id: u64, _pool: Context, name: String) -> String {
+    format!("{} {}", id, name)
+}
+
+#[cached(ignore = "_db, _pool")]
+async fn async_ignored_contexts(_db: Context, id: u64, _pool: Context, name: String) -> String {
+    format!("{} {}", id, name)
+}
+
+#[tokio::test]
+async fn ignore_leaves_out_contexts() {
+    assert_eq!(ignored_contexts(Context, 1, Context, "a".to_owned()), "1 a");
+    assert!(ignored_contexts_cache_contains(1, "a".to_owned()));
+
+    assert_eq!(
+        async_ignored_contexts(Context, 1, Context, "a".to_owned()).await,
+        "1 a"
+    );
+    assert!(async_ignored_contexts_cache_contains(1, "a".to_owned()));
+}
+
+#[cached(key_hash = true, key = "text, lang", dump = true)]
+fn hashed(text: String, _verbose: bool, lang: &'static str) -> usize {
+    text.len() + lang.len()
+}
+
+#[test]
+fn key_hash_stores_u64() {
+    assert_eq!(hashed("hello".to_owned(), false, "en"), 7);
+    assert_eq!(hashed("hello".to_owned(), true, "en"), 7);
+    assert_eq!(hashed("hello".to_owned(), false, "de"), 7);
+    let entries: Vec<(u64, usize)> = hashed_cache_dump(None);
+    assert_eq!(entries.len(), 2);
+    assert!(hashed_cache_contains("hello".to_owned(), "de"));
+    assert!(!hashed_cache_contains("hello".to_owned(), "fr"));
+}
+
+pub struct Session {
+    tenant: Tenant,
+    trace: u64,
+}
+
+pub struct Tenant {
+    id: u64,
+}
+
+#[cached(key = "session.tenant.id, page", key_type = "(u64, u32)", dump = true)]
+fn listing(session: Session, page: u32) -> String {
+    format!("{}/{} {}", session.tenant.id, page, session.trace)
+}
+
+#[test]
+fn key_on_fields() {
+    let session = |id, trace| Session {
+        tenant: Tenant { id },
+        trace,
+    };
+    assert_eq!(listing(session(1, 10), 2), "1/2 10");
+    assert_eq!(listing(session(1, 20), 2), "1/2 10");
+    assert_eq!(listing(session(2, 20), 2), "2/2 20");
+    let mut keys: Vec<(u64, u32)> = listing_cache_dump(None)
+        .into_iter()
+        .map(|(key, _)| key)
+        .collect();
+    keys.sort_unstable();
+    assert_eq!(keys, vec![(1, 2), (2, 2)]);
+}
+
+#[cached(key = "session.tenant.id", key_hash = true)]
+fn hashed_listing(session: Session) -> u64 {
+    session.tenant.id + session.trace
+}
+
+#[test]
+fn hashed_fields() {
+    let session = |id, trace| Session {
+        tenant: Tenant { id },
+        trace,
+    };
+    assert_eq!(hashed_listing(session(1, 10)), 11);
+    assert_eq!(hashed_listing(session(1, 20)), 11);
+}
+
+static BORROW_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(key_borrow = true, key = "path, line")]
+fn borrowed(path: String, _ctx: Context, line: u32) -> Result<String, String> {
+    BORROW_CALLS.fetch_add(1, Ordering::SeqCst);
+    Ok(format!("{}:{}", path, line))
+}
+
+#[cached(key_borrow = true)]
+async fn borrowed_async(path: String) -> usize {
+    BORROW_CALLS.fetch_add(1, Ordering::SeqCst);
+    path.len()
+}
+
+#[tokio::test]
+async fn key_borrow() {
+    let start = BORROW_CALLS.load(Ordering::SeqCst);
+    assert_eq!(
+        borrowed("a.rs".to_owned(), Context, 1),
+        Ok("a.rs:1".to_owned())
+    );
+    assert_eq!(
+        borrowed("a.rs".to_owned(), Context, 1),
+        Ok("a.rs:1".to_owned())
+    );
+    assert!(borrowed_cache_contains("a.rs".to_owned(), 1));
+
+    assert_eq!(borrowed_async("b.rs".to_owned()).await, 4);
+    assert_eq!(borrowed_async("b.rs".to_owned()).await, 4);
+    assert_eq!(BORROW_CALLS.load(Ordering::SeqCst) - start, 2);
+}
+
+#[cached(key = "id")]
+fn labelled<T>(id: u32, label: T) -> String
+where
+    T: std::fmt::Display,
+{
+    format!("{}{}", label, id)
+}
+
+#[test]
+fn where_clause_kept() {
+    assert_eq!(labelled(1, "first "), "first 1");
+    assert_eq!(labelled(1, 'x'), "first 1");
+}
+
+static KEY_ONLY_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(key = "id")]
+fn single_key(_ctx: Context, id: u64) -> u64 {
+    KEY_ONLY_CALLS.fetch_add(1, Ordering::SeqCst);
+    id * 2
+}
+
+#[cached(key = "tenant, id")]
+fn multiple_keys(tenant: String, _ctx: Context, id: u64) -> String {
+    KEY_ONLY_CALLS.fetch_add(1, Ordering::SeqCst);
+    format!("{}/{}", tenant, id)
+}
+
+#[test]
+fn key_without_convert() {
+    assert_eq!(single_key(Context, 1), 2);
+    assert_eq!(single_key(Context, 1), 2);
+    assert!(single_key_cache_contains(1));
+    assert_eq!(multiple_keys("acme".to_owned(), Context, 1), "acme/1");
+    assert_eq!(multiple_keys("acme".to_owned(), Context, 1), "acme/1");
+    assert!(multiple_keys_cache_contains("acme".to_owned(), 1));
+    assert_eq!(KEY_ONLY_CALLS.load(Ordering::SeqCst), 2);
+}
+
+#[cached(key = "x, name")]
+fn destructured((x, _y): (u8, u8), _ctx: Context, name: &'static str) -> String {
+    format!("{}{}", name, x)
+}
+
+#[cached]
+fn destructured_whole((a, b): (u8, String), Wrapper(c): Wrapper<bool>) -> String {
+    format!("{}{}{}", a, b, c)
+}
+
+pub struct Wrapper<T>(T);
+
+#[test]
+fn destructured_key_inputs() {
+    assert_eq!(destructured((1, 2), Context, "n"), "n1");
+    // `_y` isn't a part of the key
+    assert_eq!(destructured((1, 5), Context, "n"), "n1");
+    assert!(destructured_cache_contains(1, "n"));
+
+    assert_eq!(
+        destructured_whole((1, "b".to_owned()), Wrapper(true)),
+        "1btrue"
+    );
+    assert!(destructured_whole_cache_contains(1, "b".to_owned(), true));
+}
+
+#[cached(key = "a, b", convert = "(a.abs(), b)")]
+fn convert_expression(a: i32, b: u8, _verbose: bool) -> i32 {
+    a + b as i32
+}
+
+#[cached(
+    convert = r#"format!("{}:{}", a.abs(), b)"#,
+    key_type = "String",
+    dump = true
+)]
+fn convert_to_key_type(a: i32, b: u8) -> i32 {
+    a + b as i32
+}
+
+#[test]
+fn convert_takes_an_expression() {
+    assert_eq!(convert_expression(-1, 2, false), 1);
+    assert!(convert_expression_cache_contains(1, 2));
+
+    assert_eq!(convert_to_key_type(-1, 2), 1);
+    assert_eq!(convert_to_key_type(1, 2), 1);
+    let entries: Vec<(String, i32)> = convert_to_key_type_cache_dump(None);
+    assert_eq!(entries, vec![("1:2".to_owned(), 1)]);
+}
+
+#[derive(Clone)]
+struct Request {
+    tenant_id: u64,
+    path: String,
+}
+
+#[cached(key_expr = "req.tenant_id", key_type = "u64")]
+fn tenant_path(req: Request) -> String {
+    format!("{}{}", req.tenant_id, req.path)
+}
+
+#[test]
+fn key_from_one_expression() {
+    let request = |path: &str| Request {
+        tenant_id: 7,
+        path: path.to_owned(),
+    };
+    assert_eq!(tenant_path(request("/a")), "7/a");
+    assert_eq!(tenant_path(request("/b")), "7/a");
+    assert!(tenant_path_cache_contains(request("/c")));
+}
+
+async fn digest(name: &str) -> u64 {
+    name.bytes().map(u64::from).sum()
+}
+
+#[cached(key = "name", convert = "digest(&name).await", key_type = "u64")]
+async fn awaited_key(name: String, verbose: bool) -> String {
+    format!("{} {}", name, verbose)
+}
+
+#[tokio::test]
+async fn convert_awaits_in_async_functions() {
+    assert_eq!(awaited_key("ab".to_owned(), true).await, "ab true");
+    assert_eq!(awaited_key("ba".to_owned(), false).await, "ab true");
+    assert!(awaited_key_cache_contains("ba".to_owned()).await);
+    awaited_key_cache_invalidate("ab".to_owned()).await;
+    assert!(!awaited_key_cache_contains("ab".to_owned()).await);
+}
+
+#[derive(Debug)]
+struct Foreign {
+    id: u64,
+    tags: Vec<&'static str>,
+}
+
+static DEBUG_KEY_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(debug_key = true, key = "foreign, page", dump = true)]
+fn debug_keyed(foreign: Foreign, _ctx: Context, page: u8) -> usize {
+    DEBUG_KEY_CALLS.fetch_add(1, Ordering::SeqCst);
+    foreign.tags.len() + foreign.id as usize + page as usize
+}
+
+#[test]
+fn debug_key_shares_entries_of_equal_values() {
+    let foreign = || Foreign {
+        id: 1,
+        tags: vec!["a", "b"],
+    };
+    assert_eq!(debug_keyed(foreign(), Context, 0), 3);
+    assert_eq!(debug_keyed(foreign(), Context, 0), 3);
+    assert_eq!(DEBUG_KEY_CALLS.load(Ordering::SeqCst), 1);
+
+    let entries: Vec<(String, usize)> = debug_keyed_cache_dump(None);
+    assert_eq!(
+        entries,
+        vec![(r#"(Foreign { id: 1, tags: ["a", "b"] }, 0)"#.to_owned(), 3)]
+    );
+}
@@ -0,0 +1,162 @@
+use std::rc::Rc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use cold_moka::cached;
+
+struct Users {
+    prefix: &'static str,
+    lookups: AtomicUsize,
+}
+
+impl Users {
+    fn new(prefix: &'static str) -> Self {
+        Self {
+            prefix,
+            lookups: AtomicUsize::new(0),
+        }
+    }
+
+    #[cached]
+    fn name(&self, id: u64) -> String {
+        self.lookups.fetch_add(1, Ordering::SeqCst);
+        format!("{}{}", self.prefix, id)
+    }
+
+    #[cached]
+    fn parse(&self, raw: String) -> Result<u64, String> {
+        self.lookups.fetch_add(1, Ordering::SeqCst);
+        let id: u64 = raw.parse().map_err(|_| format!("bad id {}", raw))?;
+        Ok(id)
+    }
+
+    #[cached]
+    async fn fetch(&self, id: u64) -> Result<String, String> {
+        self.lookups.fetch_add(1, Ordering::SeqCst);
+        if id == 0 {
+            return Err("zero".to_owned());
+        }
+        let id = u32::try_from(id).map_err(|e| e.to_string())?;
+        Ok(format!("{}{}", self.prefix, id))
+    }
+}
+
+struct Service {
+    handled: AtomicUsize,
+}
+
+impl Service {
+    #[cached]
+    async fn handle(self: Arc<Self>, req: u64) -> String {
+        self.handled.fetch_add(1, Ordering::SeqCst);
+        format!("handled {}", req)
+    }
+
+    #[cached]
+    fn handle_local(self: Rc<Self>, req: u64) -> String {
+        self.handled.fetch_add(1, Ordering::SeqCst);
+        format!("handled {}", req)
+    }
+}
+
+struct Parser;
+
+static PARSED: AtomicUsize = AtomicUsize::new(0);
+
+impl Parser {
+    #[cached(associated = true)]
+    fn parse(raw: String) -> Result<u64, String> {
+        PARSED.fetch_add(1, Ordering::SeqCst);
+        raw.parse().map_err(|_| format!("bad id {}", raw))
+    }
+
+    #[cached(associated)]
+    async fn parse_async(raw: String) -> u64 {
+        Self::parse(raw).unwrap_or_default()
+    }
+}
+
+struct Counter(u64);
+
+impl Counter {
+    #[cached]
+    fn bump(&mut self, by: u64) -> u64 {
+        self.0 += by;
+        self.0
+    }
+}
+
+#[test]
+fn receiver_is_not_part_of_the_key() {
+    let users = Users::new("user ");
+    let others = Users::new("other ");
+    assert_eq!(users.name(1), "user 1");
+    assert_eq!(users.name(1), "user 1");
+    // the cache is shared between the instances
+    assert_eq!(others.name(1), "user 1");
+    assert_eq!(others.name(2), "other 2");
+    assert_eq!(users.lookups.load(Ordering::SeqCst), 1);
+    assert_eq!(others.lookups.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn fallible_method() {
+    let users = Users::new("");
+    assert_eq!(users.parse("x".to_owned()), Err("bad id x".to_owned()));
+    assert_eq!(users.parse("7".to_owned()), Ok(7));
+    assert_eq!(users.parse("7".to_owned()), Ok(7));
+    assert_eq!(users.lookups.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn mut_receiver() {
+    let mut counter = Counter(0);
+    assert_eq!(counter.bump(2), 2);
+    assert_eq!(counter.bump(2), 2);
+    assert_eq!(counter.bump(3), 5);
+    assert_eq!(counter.0, 5);
+}
+
+#[tokio::test]
+async fn async_method() {
+    let users = Users::new("user ");
+    assert_eq!(users.fetch(0).await, Err("zero".to_owned()));
+    assert_eq!(users.fetch(1).await, Ok("user 1".to_owned()));
+    assert_eq!(users.fetch(1).await, Ok("user 1".to_owned()));
+    assert_eq!(users.lookups.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn arc_receiver() {
+    let service = Arc::new(Service {
+        handled: AtomicUsize::new(0),
+    });
+    assert_eq!(service.clone().handle(1).await, "handled 1");
+    assert_eq!(service.clone().handle(1).await, "handled 1");
+    assert_eq!(service.clone().handle(2).await, "handled 2");
+    assert_eq!(service.handled.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn rc_receiver() {
+    let service = Rc::new(Service {
+        handled: AtomicUsize::new(0),
+    });
+    assert_eq!(service.clone().handle_local(1), "handled 1");
+    assert_eq!(service.clone().handle_local(1), "handled 1");
+    assert_eq!(service.handled.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn associated_function() {
+    assert_eq!(Parser::parse("7".to_owned()), Ok(7));
+    assert_eq!(Parser::parse("7".to_owned()), Ok(7));
+    assert_eq!(Parser::parse("x".to_owned()), Err("bad id x".to_owned()));
+    assert_eq!(PARSED.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn async_associated_function() {
+    assert_eq!(Parser::parse_async("8".to_owned()).await, 8);
+    assert_eq!(Parser::parse_async("8".to_owned()).await, 8);
+}
@@ -0,0 +1,200 @@
+use std::sync::{Arc, Mutex};
+
+use cold_moka::cached;
+use metrics::{
+    Counter, CounterFn, Gauge, Histogram, HistogramFn, Key, KeyName, Metadata, Recorder,
+    SharedString, Unit,
+};
+
+#[derive(Default)]
+struct Samples(Mutex<Vec<(String, f64)>>);
+
+struct FnSamples {
+    function: String,
+    samples: Arc<Samples>,
+}
+
+impl HistogramFn for FnSamples {
+    fn record(&self, value: f64) {
+        self.samples
+            .0
+            .lock()
+            .unwrap()
+            .push((self.function.clone(), value));
+    }
+}
+
+fn function_label(key: &Key) -> String {
+    key.labels()
+        .find(|label| label.key() == "fn")
+        .unwrap()
+        .value()
+        .to_owned()
+}
+
+#[derive(Default)]
+struct Lookups(Mutex<Vec<(String, String)>>);
+
+struct FnLookups {
+    name: String,
+    function: String,
+    lookups: Arc<Lookups>,
+}
+
+impl CounterFn for FnLookups {
+    fn increment(&self, value: u64) {
+        let mut lookups = self.lookups.0.lock().unwrap();
+        for _ in 0..value {
+            lookups.push((self.name.clone(), self.function.clone()));
+        }
+    }
+
+    fn absolute(&self, _: u64) {}
+}
+
+#[derive(Default)]
+struct SizeRecorder {
+    samples: Arc<Samples>,
+    lookups: Arc<Lookups>,
+}
+
+impl SizeRecorder {
+    fn samples(&self) -> Vec<(String, f64)> {
+        self.samples.0.lock().unwrap().clone()
+    }
+
+    fn lookups(&self, function: &str) -> Vec<String> {
+        let lookups = self.lookups.0.lock().unwrap();
+        lookups
+            .iter()
+            .filter(|(_, name)| name == function)
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+}
+
+impl Recorder for SizeRecorder {
+    fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+    fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+    fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+    fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+        Counter::from_arc(Arc::new(FnLookups {
+            name: key.name().to_owned(),
+            function: function_label(key),
+            lookups: self.lookups.clone(),
+        }))
+    }
+
+    fn register_gauge(&self, _: &Key, _: &Metadata<'_>) -> Gauge {
+        Gauge::noop()
+    }
+
+    fn register_histogram(&self, key: &Key, _: &Metadata<'_>) -> Histogram {
+        assert_eq!(key.name(), cold_moka::metrics::VALUE_SIZE);
+        Histogram::from_arc(Arc::new(FnSamples {
+            function: function_label(key),
+            samples: self.samples.clone(),
+        }))
+    }
+}
+
+#[cached(record_size = "|v| v.len() as f64")]
+fn payload(len: usize) -> String {
+    "x".repeat(len)
+}
+
+#[cached(record_size = "|v: &Vec<u8>| v.len() as f64")]
+fn fallible_payload(len: usize) -> Result<Vec<u8>, String> {
+    if len == 0 {
+        Err("empty".to_owned())
+    } else {
+        Ok(vec![0; len])
+    }
+}
+
+#[test]
+fn sample_per_computation() {
+    let recorder = SizeRecorder::default();
+    metrics::with_local_recorder(&recorder, || {
+        payload(3);
+        payload(3);
+        payload(5);
+
+        fallible_payload(2).unwrap();
+        fallible_payload(2).unwrap();
+        fallible_payload(0).unwrap_err();
+    });
+
+    assert_eq!(
+        recorder.samples(),
+        vec![
+            ("payload".to_owned(), 3.0),
+            ("payload".to_owned(), 5.0),
+            ("fallible_payload".to_owned(), 2.0),
+        ]
+    );
+}
+
+#[cached(record_size = "|v| v.len() as f64", warmup = "300ms")]
+fn warm_payload(len: usize) -> String {
+    "x".repeat(len)
+}
+
+#[test]
+fn no_samples_during_warmup() {
+    let recorder = SizeRecorder::default();
+    metrics::with_local_recorder(&recorder, || {
+        warm_payload(3);
+        std::thread::sleep(std::time::Duration::from_millis(350));
+        warm_payload(3);
+        warm_payload(5);
+    });
+
+    assert_eq!(recorder.samples(), vec![("warm_payload".to_owned(), 5.0)]);
+}
+
+#[cached]
+fn counted(x: u64) -> u64 {
+    x
+}
+
+#[test]
+fn hits_and_misses_counted() {
+    use cold_moka::metrics::{HITS, MISSES};
+
+    let recorder = SizeRecorder::default();
+    metrics::with_local_recorder(&recorder, || {
+        counted(1);
+        counted(1);
+        counted(2);
+        counted(1);
+    });
+
+    assert_eq!(
+        recorder.lookups("counted"),
+        vec![MISSES, HITS, MISSES, HITS]
+    );
+}
+
+#[cached(stats = true, warmup = "300ms")]
+fn warm_counted(x: u64) -> u64 {
+    x
+}
+
+#[test]
+fn no_lookups_counted_during_warmup() {
+    use cold_moka::metrics::{HITS, MISSES};
+
+    let recorder = SizeRecorder::default();
+    metrics::with_local_recorder(&recorder, || {
+        warm_counted(1);
+        std::thread::sleep(std::time::Duration::from_millis(350));
+        warm_counted(1);
+        warm_counted(2);
+    });
+
+    assert_eq!(recorder.lookups("warm_counted"), vec![HITS, MISSES]);
+}
@@ -0,0 +1,80 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use cold_moka::once;
+
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[once]
+fn greeting() -> String {
+    CALLS.fetch_add(1, Ordering::SeqCst);
+    "hello".to_owned()
+}
+
+#[test]
+fn runs_once() {
+    assert_eq!(greeting(), "hello");
+    assert_eq!(greeting(), "hello");
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+}
+
+static RESULT_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[once]
+fn flaky() -> Result<usize, String> {
+    match RESULT_CALLS.fetch_add(1, Ordering::SeqCst) {
+        0 => Err("not yet".to_owned()),
+        calls => Ok(calls),
+    }
+}
+
+#[test]
+fn errors_are_not_kept() {
+    assert_eq!(flaky(), Err("not yet".to_owned()));
+    assert_eq!(flaky(), Ok(1));
+    assert_eq!(flaky(), Ok(1));
+    assert_eq!(RESULT_CALLS.load(Ordering::SeqCst), 2);
+}
+
+static OPTION_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[once]
+fn maybe() -> Option<usize> {
+    let calls = OPTION_CALLS.fetch_add(1, Ordering::SeqCst);
+    (calls > 0).then_some(calls)
+}
+
+#[test]
+fn nones_are_not_kept() {
+    assert_eq!(maybe(), None);
+    assert_eq!(maybe(), Some(1));
+    assert_eq!(maybe(), Some(1));
+}
+
+static NESTED_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[once]
+fn nested() -> Result<Option<usize>, String> {
+    match NESTED_CALLS.fetch_add(1, Ordering::SeqCst) {
+        0 => Err("not yet".to_owned()),
+        1 => Ok(None),
+        calls => Ok(Some(calls)),
+    }
+}
+
+#[test]
+fn only_ok_somes_are_kept() {
+    assert_eq!(nested(), Err("not yet".to_owned()));
+    assert_eq!(nested(), Ok(None));
+    assert_eq!(nested(), Ok(Some(2)));
+    assert_eq!(nested(), Ok(Some(2)));
+}
+
+#[once]
+fn unit_input(_: ()) -> u8 {
+    7
+}
+
+#[test]
+fn unit_inputs_are_accepted() {
+    assert_eq!(unit_input(()), 7);
+}
@@ -0,0 +1,92 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use cold_moka::cached;
+
+static BYPASS_CALLS: AtomicUsize = AtomicUsize::new(0);
+static OVERSIZED: AtomicUsize = AtomicUsize::new(0);
+
+fn oversized(weight: u32) {
+    assert!(weight > 50);
+    OVERSIZED.fetch_add(1, Ordering::SeqCst);
+}
+
+#[cached(
+    max_weight = 100,
+    weigher = "|_, v: &Vec<u8>| v.len() as u32",
+    oversize = "bypass",
+    on_oversize = "oversized"
+)]
+fn bypass(len: usize) -> Vec<u8> {
+    BYPASS_CALLS.fetch_add(1, Ordering::SeqCst);
+    vec![0; len]
+}
+
+#[test]
+fn oversized_values_are_not_cached() {
+    for _ in 0..3 {
+        for len in [10, 11, 12] {
+            assert_eq!(bypass(len).len(), len);
+        }
+        assert_eq!(bypass(80).len(), 80);
+    }
+    // small entries are computed once, the huge one every time
+    assert_eq!(BYPASS_CALLS.load(Ordering::SeqCst), 3 + 3);
+    assert_eq!(OVERSIZED.load(Ordering::SeqCst), 3);
+    assert!(bypass_cache_contains(10));
+    assert!(!bypass_cache_contains(80));
+}
+
+static DEDICATED_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(
+    max_weight = 100,
+    weigher = "|_, v: &Vec<u8>| v.len() as u32",
+    oversize = "dedicated",
+    oversize_fraction = 0.25
+)]
+fn dedicated(len: usize) -> Option<Vec<u8>> {
+    DEDICATED_CALLS.fetch_add(1, Ordering::SeqCst);
+    Some(vec![0; len])
+}
+
+#[test]
+fn oversized_values_get_a_slot() {
+    for _ in 0..3 {
+        for len in [10, 11, 12] {
+            assert_eq!(dedicated(len).unwrap().len(), len);
+        }
+        assert_eq!(dedicated(30).unwrap().len(), 30);
+    }
+    assert_eq!(DEDICATED_CALLS.load(Ordering::SeqCst), 4);
+    assert!(!dedicated_cache_contains(30));
+
+    // the slot holds the last oversized value only
+    dedicated(40);
+    dedicated(30);
+    assert_eq!(DEDICATED_CALLS.load(Ordering::SeqCst), 6);
+
+    dedicated_cache_clear();
+    dedicated(30);
+    assert_eq!(DEDICATED_CALLS.load(Ordering::SeqCst), 7);
+}
+
+static ASYNC_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(
+    max_weight = 100,
+    weigher = "|_, v: &String| v.len() as u32",
+    oversize = "bypass"
+)]
+async fn async_bypass(len: usize) -> Result<String, String> {
+    ASYNC_CALLS.fetch_add(1, Ordering::SeqCst);
+    Ok("x".repeat(len))
+}
+
+#[tokio::test]
+async fn async_oversized_values_are_not_cached() {
+    async_bypass(5).await.unwrap();
+    async_bypass(5).await.unwrap();
+    async_bypass(70).await.unwrap();
+    async_bypass(70).await.unwrap();
+    assert_eq!(ASYNC_CALLS.load(Ordering::SeqCst), 3);
+}
@@ -0,0 +1,65 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use cold_moka::cached;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Query {
+    table: String,
+    limit: usize,
+}
+
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached]
+fn describe(name: String, bytes: Vec<u8>, query: Query) -> String {
+    CALLS.fetch_add(1, Ordering::SeqCst);
+    format!("{} {} {} {}", name, bytes.len(), query.table, query.limit)
+}
+
+#[test]
+fn owned_arguments_build_the_key_and_reach_the_function() {
+    let query = Query {
+        table: "users".to_owned(),
+        limit: 10,
+    };
+    assert_eq!(
+        describe("a".to_owned(), vec![1, 2], query.clone()),
+        "a 2 users 10"
+    );
+    assert_eq!(
+        describe("a".to_owned(), vec![1, 2], query.clone()),
+        "a 2 users 10"
+    );
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+    assert!(describe_cache_contains("a".to_owned(), vec![1, 2], query));
+}
+
+static ASYNC_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached]
+async fn async_describe(name: String, bytes: Vec<u8>, query: Query) -> String {
+    ASYNC_CALLS.fetch_add(1, Ordering::SeqCst);
+    format!("{} {} {} {}", name, bytes.len(), query.table, query.limit)
+}
+
+#[tokio::test]
+async fn async_owned_arguments_build_the_key_and_reach_the_function() {
+    let query = Query {
+        table: "users".to_owned(),
+        limit: 10,
+    };
+    assert_eq!(
+        async_describe("a".to_owned(), vec![1, 2], query.clone()).await,
+        "a 2 users 10"
+    );
+    assert_eq!(
+        async_describe("a".to_owned(), vec![1, 2], query.clone()).await,
+        "a 2 users 10"
+    );
+    assert_eq!(ASYNC_CALLS.load(Ordering::SeqCst), 1);
+    assert!(async_describe_cache_contains(
+        "a".to_owned(),
+        vec![1, 2],
+        query
+    ));
+}
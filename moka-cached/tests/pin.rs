@@ -0,0 +1,28 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use cold_moka::cached;
+
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(ttl = 1, pin = true)]
+fn double(x: u32) -> u32 {
+    CALLS.fetch_add(1, Ordering::SeqCst);
+    x * 2
+}
+
+#[test]
+fn pinned_entry_survives_ttl() {
+    assert_eq!(double_pin(1), 2);
+    assert_eq!(double(2), 4);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+
+    std::thread::sleep(Duration::from_millis(1500));
+
+    // pinned entry is still cached
+    assert_eq!(double(1), 2);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 2);
+    // the other one expired
+    assert_eq!(double(2), 4);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 3);
+}
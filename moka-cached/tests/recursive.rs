@@ -0,0 +1,39 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use cold_moka::cached;
+
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached]
+fn fib(n: u64) -> u64 {
+    CALLS.fetch_add(1, Ordering::SeqCst);
+    if n < 2 {
+        n
+    } else {
+        fib(n - 1) + fib(n - 2)
+    }
+}
+
+#[test]
+fn recursive_calls_are_memoized() {
+    assert_eq!(fib(30), 832040);
+    assert_eq!(CALLS.load(Ordering::SeqCst), 31);
+}
+
+static ASYNC_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached]
+async fn async_fib(n: u64) -> u64 {
+    ASYNC_CALLS.fetch_add(1, Ordering::SeqCst);
+    if n < 2 {
+        n
+    } else {
+        Box::pin(async_fib(n - 1)).await + Box::pin(async_fib(n - 2)).await
+    }
+}
+
+#[tokio::test]
+async fn async_recursive_calls_are_memoized() {
+    assert_eq!(async_fib(30).await, 832040);
+    assert_eq!(ASYNC_CALLS.load(Ordering::SeqCst), 31);
+}
@@ -0,0 +1,87 @@
+use std::time::Duration;
+
+use cold_moka::{cached, CacheStats};
+
+fn counts(stats: CacheStats) -> (u64, u64) {
+    (stats.hits, stats.misses)
+}
+
+#[cached(stats = true)]
+fn bare(x: u32) -> u32 {
+    x
+}
+
+#[test]
+fn bare_hits_and_misses() {
+    assert_eq!(bare_cache_stats(), CacheStats::default());
+    bare(1);
+    bare(1);
+    bare(2);
+    bare(1);
+    assert_eq!(counts(bare_cache_stats()), (2, 2));
+    assert_eq!(bare_cache_stats().hit_rate(), 0.5);
+}
+
+#[cached(stats = true)]
+fn fallible(x: u32) -> Result<u32, String> {
+    if x == 0 {
+        Err("zero".to_owned())
+    } else {
+        Ok(x)
+    }
+}
+
+#[test]
+fn errors_are_misses() {
+    fallible(0).unwrap_err();
+    fallible(0).unwrap_err();
+    fallible(1).unwrap();
+    fallible(1).unwrap();
+    assert_eq!(counts(fallible_cache_stats()), (1, 3));
+}
+
+#[cached(stats = true)]
+async fn optional(x: u32) -> Option<u32> {
+    (x > 0).then_some(x)
+}
+
+#[tokio::test]
+async fn async_hits_and_misses() {
+    optional(0).await;
+    optional(1).await;
+    optional(1).await;
+    optional(1).await;
+    assert_eq!(counts(optional_cache_stats()), (2, 2));
+}
+
+#[cached(stats = true)]
+async fn slow(x: u32) -> u32 {
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    x
+}
+
+#[tokio::test]
+async fn coalesced_callers_count_one_miss() {
+    let calls: Vec<_> = (0..8).map(|_| tokio::spawn(slow(1))).collect();
+    for call in calls {
+        assert_eq!(call.await.unwrap(), 1);
+    }
+    assert_eq!(counts(slow_cache_stats()), (7, 1));
+}
+
+#[cached(stats = true, warmup = "300ms")]
+fn warm(x: u32) -> u32 {
+    x
+}
+
+#[test]
+fn nothing_counted_during_warmup() {
+    warm(1);
+    warm(1);
+    assert_eq!(counts(warm_cache_stats()), (0, 0));
+
+    std::thread::sleep(Duration::from_millis(350));
+    warm(1);
+    warm(2);
+    assert_eq!(counts(warm_cache_stats()), (1, 1));
+}
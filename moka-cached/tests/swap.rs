@@ -0,0 +1,57 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+
+use cold_moka::cached;
+
+const ROUTES: u32 = 64;
+
+#[cached(swap = true)]
+fn route(id: u32) -> Option<u32> {
+    let _ = id;
+    None
+}
+
+fn routes(generation: u32) -> impl Iterator<Item = (u32, u32)> {
+    (0..ROUTES).map(move |id| (id, generation))
+}
+
+#[test]
+fn readers_never_see_a_partial_table() {
+    route_cache_swap(routes(0));
+    let done = AtomicBool::new(false);
+
+    thread::scope(|scope| {
+        scope.spawn(|| {
+            while !done.load(Ordering::Relaxed) {
+                for id in 0..ROUTES {
+                    // a reload never leaves a window where the route is missing
+                    assert!(route_if_cached(id).is_some(), "route {} is missing", id);
+                }
+            }
+        });
+
+        for generation in 1..=200 {
+            route_cache_swap(routes(generation));
+        }
+        done.store(true, Ordering::Relaxed);
+    });
+
+    for id in 0..ROUTES {
+        assert_eq!(route(id), Some(200));
+    }
+}
+
+#[cached(swap = true)]
+async fn async_route(id: u32) -> u32 {
+    id
+}
+
+#[tokio::test]
+async fn async_cache_swap() {
+    assert_eq!(async_route(1).await, 1);
+
+    async_route_cache_swap([(1, 10), (2, 20)]).await;
+    assert_eq!(async_route(1).await, 10);
+    assert_eq!(async_route(2).await, 20);
+    assert_eq!(async_route(3).await, 3);
+}
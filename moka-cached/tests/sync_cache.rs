@@ -0,0 +1,42 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use cold_moka::cached;
+
+static SQUARE_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(sync_cache = true)]
+async fn square(x: u64) -> u64 {
+    SQUARE_CALLS.fetch_add(1, Ordering::SeqCst);
+    x * x
+}
+
+#[tokio::test]
+async fn hits_and_companions() {
+    assert_eq!(square(3).await, 9);
+    assert_eq!(square(3).await, 9);
+    assert_eq!(SQUARE_CALLS.load(Ordering::SeqCst), 1);
+
+    assert_eq!(square_if_cached(3).await, Some(9));
+    square_cache_insert(4, 0).await;
+    assert_eq!(square(4).await, 0);
+    square_cache_invalidate(4).await;
+    assert_eq!(square(4).await, 16);
+    assert_eq!(square_cache_entry_count(), 2);
+}
+
+static PARSE_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(sync_cache = true)]
+async fn parse(raw: &'static str) -> Result<u64, String> {
+    PARSE_CALLS.fetch_add(1, Ordering::SeqCst);
+    raw.parse().map_err(|_| format!("bad {}", raw))
+}
+
+#[tokio::test]
+async fn errors_are_not_cached() {
+    assert_eq!(parse("x").await, Err("bad x".to_owned()));
+    assert_eq!(parse("x").await, Err("bad x".to_owned()));
+    assert_eq!(parse("1").await, Ok(1));
+    assert_eq!(parse("1").await, Ok(1));
+    assert_eq!(PARSE_CALLS.load(Ordering::SeqCst), 3);
+}
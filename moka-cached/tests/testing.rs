@@ -0,0 +1,73 @@
+use cold_moka::cached;
+use cold_moka::once_cell::sync::Lazy;
+use cold_moka::testing::{Op, RecordingBackend};
+
+static PROFILES: Lazy<RecordingBackend<u64, String>> = Lazy::new(RecordingBackend::new);
+
+#[cached(backend = "PROFILES")]
+fn profile(id: u64) -> String {
+    format!("profile {}", id)
+}
+
+#[test]
+fn records_operations_in_order() {
+    profile(1);
+    profile(1);
+    profile_cache_invalidate(1);
+    profile(1);
+
+    assert_eq!(
+        PROFILES.ops(),
+        vec![
+            Op::Get { key: 1, hit: false },
+            Op::Insert(1),
+            Op::Get { key: 1, hit: true },
+            Op::Invalidate(1),
+            Op::Get { key: 1, hit: false },
+            Op::Insert(1),
+        ]
+    );
+}
+
+static SESSIONS: Lazy<RecordingBackend<u64, String>> = Lazy::new(RecordingBackend::new);
+
+#[cached(backend = "SESSIONS")]
+async fn session(id: u64) -> Result<String, String> {
+    match id {
+        0 => Err("anonymous".to_owned()),
+        id => Ok(format!("session {}", id)),
+    }
+}
+
+#[tokio::test]
+async fn async_errors_are_not_inserted() {
+    assert!(session(0).await.is_err());
+    assert_eq!(session(2).await, Ok("session 2".to_owned()));
+    session_cache_insert(3, "restored".to_owned()).await;
+    assert_eq!(session_if_cached(3).await, Some("restored".to_owned()));
+
+    assert_eq!(
+        SESSIONS.ops(),
+        vec![
+            Op::Get { key: 0, hit: false },
+            Op::Get { key: 2, hit: false },
+            Op::Insert(2),
+            Op::Insert(3),
+            Op::Get { key: 3, hit: true },
+        ]
+    );
+}
+
+static SHARED: Lazy<cold_moka::moka::sync::Cache<u64, String>> =
+    Lazy::new(|| cold_moka::moka::sync::Cache::new(10));
+
+#[cached(backend = "SHARED")]
+fn shared(id: u64) -> String {
+    format!("shared {}", id)
+}
+
+#[test]
+fn moka_cache_is_a_backend() {
+    assert_eq!(shared(1), "shared 1");
+    assert_eq!(SHARED.get(&1), Some("shared 1".to_owned()));
+}
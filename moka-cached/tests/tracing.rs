@@ -0,0 +1,84 @@
+use std::sync::{Arc, Mutex};
+
+use cold_moka::cached;
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+#[derive(Clone, Default)]
+struct EventNames(Arc<Mutex<Vec<&'static str>>>);
+
+impl EventNames {
+    fn take(&self) -> Vec<&'static str> {
+        std::mem::take(&mut *self.0.lock().unwrap())
+    }
+}
+
+impl Subscriber for EventNames {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, _: &Attributes<'_>) -> Id {
+        Id::from_u64(1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        if event.metadata().target() == "cold_moka" {
+            self.0.lock().unwrap().push(event.metadata().name());
+        }
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[cached(trace = true)]
+fn traced(x: u32) -> u32 {
+    x
+}
+
+#[cached(trace = true)]
+fn traced_fallible(x: u32) -> Result<u32, String> {
+    if x == 0 {
+        Err("zero".to_owned())
+    } else {
+        Ok(x)
+    }
+}
+
+#[test]
+fn hits_and_misses_are_traced() {
+    let names = EventNames::default();
+    tracing::subscriber::with_default(names.clone(), || {
+        traced(1);
+        traced(1);
+        traced(2);
+    });
+    assert_eq!(
+        names.take(),
+        vec![
+            "__COLD_MOKA_TRACED miss",
+            "__COLD_MOKA_TRACED hit",
+            "__COLD_MOKA_TRACED miss"
+        ]
+    );
+
+    tracing::subscriber::with_default(names.clone(), || {
+        traced_fallible(0).unwrap_err();
+        traced_fallible(1).unwrap();
+        traced_fallible(1).unwrap();
+    });
+    assert_eq!(
+        names.take(),
+        vec![
+            "__COLD_MOKA_TRACED_FALLIBLE miss",
+            "__COLD_MOKA_TRACED_FALLIBLE miss",
+            "__COLD_MOKA_TRACED_FALLIBLE hit"
+        ]
+    );
+}
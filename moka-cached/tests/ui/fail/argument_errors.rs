@@ -0,0 +1,28 @@
+use cold_moka::cached;
+
+#[cached(key = "id", ignore = "verbose")]
+fn user_name(id: u64, verbose: bool) -> String {
+    format!("{} {}", id, verbose)
+}
+
+#[cached(key_hash, debug_key)]
+fn user_email(id: u64) -> String {
+    id.to_string()
+}
+
+#[cached(pin = true)]
+fn user_role(id: u64) -> String {
+    id.to_string()
+}
+
+#[cached(max_weight = 100, weigher = "|_: &u64, _: &String| 1", oversize = "split")]
+fn user_bio(id: u64) -> String {
+    id.to_string()
+}
+
+#[cached(cache_if = "|value: &String| value.is_empty(")]
+fn user_avatar(id: u64) -> String {
+    id.to_string()
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use cold_moka::cached;
+use cold_moka::once_cell::sync::Lazy;
+use cold_moka::testing::RecordingBackend;
+
+static USERS: Lazy<RecordingBackend<u64, String>> = Lazy::new(RecordingBackend::new);
+
+#[cached(backend = "USERS")]
+fn user_name(id: u64) -> usize {
+    id as usize
+}
+
+#[cached(backend = "USERS", ttl = 60)]
+fn user_email(id: u64) -> String {
+    id.to_string()
+}
+
+fn main() {}
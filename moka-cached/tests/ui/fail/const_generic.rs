@@ -0,0 +1,8 @@
+use cold_moka::cached;
+
+#[cached]
+fn checksum<const N: usize>(bytes: [u8; N]) -> u64 {
+    bytes.iter().map(|&byte| u64::from(byte)).sum()
+}
+
+fn main() {}
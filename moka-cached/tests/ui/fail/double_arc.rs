@@ -0,0 +1,8 @@
+use cold_moka::cached;
+
+#[cached(arc = true)]
+fn config(name: String) -> Result<std::sync::Arc<String>, String> {
+    Ok(std::sync::Arc::new(name))
+}
+
+fn main() {}
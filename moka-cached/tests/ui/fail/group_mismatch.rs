@@ -0,0 +1,17 @@
+use cold_moka::cached;
+use cold_moka::moka::sync::Cache;
+use cold_moka::once_cell::sync::Lazy;
+
+static USERS: Lazy<Cache<u64, String>> = Lazy::new(|| Cache::new(100));
+
+#[cached(group = "USERS")]
+fn user_name(id: u64) -> usize {
+    id as usize
+}
+
+#[cached(group = "USERS", ttl = 60)]
+fn user_email(id: u64) -> String {
+    id.to_string()
+}
+
+fn main() {}
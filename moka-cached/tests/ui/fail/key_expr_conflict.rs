@@ -0,0 +1,13 @@
+use cold_moka::cached;
+
+#[cached(key = "user_id", key_expr = "user_id", key_type = "u64")]
+fn user_name(user_id: u64, verbose: bool) -> String {
+    format!("{} {}", user_id, verbose)
+}
+
+#[cached(key_expr = "user_id")]
+fn user_email(user_id: u64, verbose: bool) -> String {
+    format!("{} {}", user_id, verbose)
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use cold_moka::cached;
+
+fn user_key(id: &u64) -> String {
+    id.to_string()
+}
+
+#[cached(key_fn = "user_key")]
+fn user_name(id: u64) -> String {
+    id.to_string()
+}
+
+#[cached(key_fn = "user_key(", key_type = "String")]
+fn user_email(id: u64) -> String {
+    id.to_string()
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use cold_moka::{cache_invalidate, cached};
+
+#[cached]
+fn get_user(id: u64) -> String {
+    format!("user {}", id)
+}
+
+struct Users;
+
+impl Users {
+    #[cache_invalidate(target = "get_user", key = "id")]
+    fn delete(&self, id: u64) {
+        let _ = id;
+    }
+}
+
+fn main() {}
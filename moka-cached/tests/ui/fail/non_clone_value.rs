@@ -0,0 +1,10 @@
+use cold_moka::cached;
+
+struct Report(String);
+
+#[cached]
+fn report(id: u64) -> Report {
+    Report(id.to_string())
+}
+
+fn main() {}
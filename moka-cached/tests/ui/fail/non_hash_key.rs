@@ -0,0 +1,12 @@
+use cold_moka::cached;
+
+#[derive(Clone, PartialEq, Eq)]
+struct Context;
+
+#[cached]
+fn report(ctx: Context, id: u64) -> String {
+    let _ = ctx;
+    id.to_string()
+}
+
+fn main() {}
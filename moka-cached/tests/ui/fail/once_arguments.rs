@@ -0,0 +1,13 @@
+use cold_moka::once;
+
+#[once]
+fn user_name(id: u64) -> String {
+    id.to_string()
+}
+
+#[once]
+async fn config() -> String {
+    String::new()
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use cold_moka::cached;
+
+async fn digest(name: &str) -> u64 {
+    name.len() as u64
+}
+
+#[cached(convert = "digest(&name).await", key_type = "u64")]
+fn user_name(name: String) -> String {
+    name
+}
+
+fn main() {}
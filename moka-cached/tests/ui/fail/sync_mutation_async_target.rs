@@ -0,0 +1,18 @@
+use cold_moka::{cache_invalidate, cache_update, cached};
+
+#[cached]
+async fn get_user(id: u64) -> String {
+    format!("user {}", id)
+}
+
+#[cache_invalidate(target = "get_user", key = "id")]
+fn delete_user(id: u64) {
+    let _ = id;
+}
+
+#[cache_update(target = "get_user", key = "id")]
+fn rename_user(id: u64, name: &str) -> String {
+    format!("{} {}", name, id)
+}
+
+fn main() {}
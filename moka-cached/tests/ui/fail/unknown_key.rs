@@ -0,0 +1,23 @@
+use cold_moka::{cache_invalidate, cached};
+
+#[cached(key = "user_idd")]
+fn user_name(user_id: u64, verbose: bool) -> String {
+    format!("{} {}", user_id, verbose)
+}
+
+#[cached(key = "user_id, user_id")]
+fn user_email(user_id: u64, verbose: bool) -> String {
+    format!("{} {}", user_id, verbose)
+}
+
+#[cached(ignore = "verbos")]
+fn user_phone(user_id: u64, verbose: bool) -> String {
+    format!("{} {}", user_id, verbose)
+}
+
+#[cache_invalidate(target = "user_name", key = "id, verbose")]
+fn rename_user(user_id: u64, verbose: bool) {
+    let _ = (user_id, verbose);
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use cold_moka::cached;
+
+pub struct Request {
+    tenant_id: u64,
+    path: String,
+}
+
+#[cached(key = "reqs.tenant_id, req.path", key_type = "(u64, String)")]
+fn route(req: Request) -> String {
+    format!("{}{}", req.tenant_id, req.path)
+}
+
+fn main() {}
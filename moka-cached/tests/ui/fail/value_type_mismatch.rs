@@ -0,0 +1,8 @@
+use cold_moka::cached;
+
+#[cached(value_type = "String")]
+fn double(x: u32) -> u32 {
+    x * 2
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use cold_moka::cached;
+
+#[derive(Debug, Clone)]
+struct ApiError;
+
+#[cached]
+fn fetch(id: u64) -> Result<u64, ApiError> {
+    Ok(id)
+}
+
+fn main() {
+    fetch(1).unwrap();
+}
@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use cold_moka::cached;
+
+#[derive(Debug)]
+struct ApiError(String);
+
+impl From<Arc<ApiError>> for ApiError {
+    fn from(error: Arc<ApiError>) -> Self {
+        ApiError(error.0.clone())
+    }
+}
+
+#[cached]
+fn fetch(id: u64) -> Result<u64, ApiError> {
+    Ok(id)
+}
+
+fn main() {
+    fetch(1).unwrap();
+}
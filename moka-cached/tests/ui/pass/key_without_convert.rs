@@ -0,0 +1,16 @@
+use cold_moka::cached;
+
+struct Context;
+
+#[cached(key = "id")]
+fn user(_ctx: &Context, id: u64) -> String {
+    format!("user {}", id)
+}
+
+fn main() {
+    assert_eq!(user(&Context, 1), "user 1");
+    assert!(user_cache_contains(1));
+
+    let warmed = user_warm([(&Context, 1), (&Context, 2)]);
+    assert_eq!((warmed.computed, warmed.present), (1, 1));
+}
@@ -0,0 +1,51 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+use cold_moka::cached;
+
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+#[cached(value_ttl = true)]
+fn max_age(path: &'static str) -> (String, Duration) {
+    CALLS.fetch_add(1, Ordering::SeqCst);
+    let ttl = match path {
+        "/static" => Duration::from_secs(3600),
+        _ => Duration::from_millis(200),
+    };
+    (format!("body of {}", path), ttl)
+}
+
+#[test]
+fn entries_expire_after_their_duration() {
+    max_age("/static");
+    max_age("/live");
+    std::thread::sleep(Duration::from_millis(300));
+    assert!(max_age_cache_contains("/static"));
+    assert!(!max_age_cache_contains("/live"));
+
+    let (body, ttl) = max_age("/live");
+    assert_eq!(body, "body of /live");
+    assert_eq!(ttl, Duration::from_millis(200));
+    assert_eq!(CALLS.load(Ordering::SeqCst), 3);
+}
+
+#[cached(value_ttl = true)]
+async fn fetch(max_age: u64) -> Result<(u64, Duration), String> {
+    if max_age == 0 {
+        return Err("no-store".to_owned());
+    }
+    Ok((max_age, Duration::from_millis(max_age)))
+}
+
+#[tokio::test]
+async fn async_entries_expire_after_their_duration() {
+    fetch(100).await.unwrap();
+    fetch(5000).await.unwrap();
+    assert!(fetch(0).await.is_err());
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert_eq!(fetch_if_cached(100).await, None);
+    assert_eq!(
+        fetch_if_cached(5000).await,
+        Some((5000, Duration::from_millis(5000)))
+    );
+}
@@ -0,0 +1,83 @@
+use cold_moka::{cached, Return};
+
+#[cached(wrap_return = true)]
+fn double(x: u64) -> Return<u64> {
+    Return::new(x * 2)
+}
+
+#[test]
+fn flags_the_hits() {
+    let first = double(1);
+    assert_eq!((first.value, first.was_cached), (2, false));
+    let second = double(1);
+    assert_eq!((second.value, second.was_cached), (2, true));
+    assert!(!double(2).was_cached);
+    assert_eq!(double_if_cached(1), Some(2));
+}
+
+#[cached(wrap_return = true)]
+fn parse(s: String) -> Result<Return<u64>, String> {
+    s.parse()
+        .map(Return::new)
+        .map_err(|_| format!("{} is not a number", s))
+}
+
+#[test]
+fn flags_the_ok_hits() {
+    assert!(!parse("1".to_owned()).unwrap().was_cached);
+    assert!(parse("1".to_owned()).unwrap().was_cached);
+    assert_eq!(*parse("1".to_owned()).unwrap(), 1);
+    assert_eq!(parse("x".to_owned()), Err("x is not a number".to_owned()));
+    assert_eq!(parse("x".to_owned()), Err("x is not a number".to_owned()));
+}
+
+#[cached(wrap_return = true, cache_none = true)]
+fn find(x: u64) -> Option<Return<u64>> {
+    (x != 0).then(|| Return::new(x))
+}
+
+#[test]
+fn flags_the_cached_none() {
+    assert_eq!(find(0), None);
+    assert_eq!(find_if_cached(0), Some(None));
+    assert!(!find(1).unwrap().was_cached);
+    assert!(find(1).unwrap().was_cached);
+}
+
+#[cached(wrap_return = true)]
+async fn async_double(x: u64) -> Return<u64> {
+    Return::new(x * 2)
+}
+
+#[cached(wrap_return = true)]
+async fn async_parse(s: String) -> Result<Return<u64>, String> {
+    s.parse()
+        .map(Return::new)
+        .map_err(|_| format!("{} is not a number", s))
+}
+
+#[tokio::test]
+async fn async_flags_the_hits() {
+    assert!(!async_double(1).await.was_cached);
+    assert!(async_double(1).await.was_cached);
+    assert_eq!(async_double(1).await.value, 2);
+
+    assert!(!async_parse("1".to_owned()).await.unwrap().was_cached);
+    assert!(async_parse("1".to_owned()).await.unwrap().was_cached);
+    assert!(async_parse("x".to_owned()).await.is_err());
+}
+
+struct Doubler;
+
+impl Doubler {
+    #[cached(wrap_return = true)]
+    fn double(&self, x: u64) -> Return<u64> {
+        Return::new(x * 2)
+    }
+}
+
+#[test]
+fn flags_the_method_hits() {
+    assert!(!Doubler.double(1).was_cached);
+    assert!(Doubler.double(1).was_cached);
+}